@@ -282,6 +282,308 @@ impl AlertManager {
         Ok(())
     }
 
+    /// Critical alert when the new active node landed no vote on-chain within
+    /// the Step 5 window after a switch
+    pub async fn send_vote_landing_timeout_alert(
+        &self,
+        validator_identity: &str,
+        new_active_node: &str,
+        timeout_seconds: u64,
+        detail: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🗳️ *NO VOTE LANDED AFTER SWITCH*\n\n\
+                *Validator:* `{}`\n\
+                *New Active:* {}\n\
+                *Window:* {}s\n\
+                *Detail:* {}\n\n\
+                ⚠️ *The validator may not be voting - check the new active node*",
+                validator_identity, new_active_node, timeout_seconds, detail
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Notify about a scheduled maintenance switch (starting, skipped, failed,
+    /// switch back planned). Failures and skips go to the main channel.
+    pub async fn send_scheduled_switch_notice(
+        &self,
+        validator_identity: &str,
+        title: &str,
+        details: &str,
+        high_priority: bool,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let telegram = if high_priority {
+            self.config.telegram.as_ref()
+        } else {
+            self.config
+                .telegram_low_priority
+                .as_ref()
+                .or(self.config.telegram.as_ref())
+        };
+
+        if let Some(telegram) = telegram {
+            let message = format!(
+                "🗓️ *{}*\n\n\
+                *Validator:* `{}`\n\
+                {}",
+                title, validator_identity, details
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sent once per cluster-wide stall, in place of delinquency alerts and
+    /// automatic takeovers for the validator
+    pub async fn send_cluster_stall_alert(
+        &self,
+        validator_identity: &str,
+        reason: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🌐 *CLUSTER-WIDE STALL*\n\n\
+                *Validator:* `{}`\n\
+                *Detected:* {}\n\n\
+                Delinquency alerts and auto-failover are suspended until the cluster makes progress again. \
+                No switch was performed.",
+                validator_identity, reason
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Critical alert when an emergency takeover is skipped because the
+    /// standby failed its fitness check
+    pub async fn send_standby_unfit_alert(
+        &self,
+        validator_identity: &str,
+        active_node: &str,
+        standby_node: &str,
+        issues: &[String],
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let issue_lines: Vec<String> = issues.iter().map(|i| format!("• {}", i)).collect();
+            let message = format!(
+                "🛑 *EMERGENCY TAKEOVER SKIPPED - STANDBY UNFIT*\n\n\
+                *Validator:* `{}`\n\
+                *Reason:* Not voting with confirmed connectivity\n\n\
+                *Active:* {} (left untouched)\n\
+                *Standby:* {} ❌\n\n\
+                *Fitness check:*\n{}\n\n\
+                ⚠️ *MANUAL INTERVENTION REQUIRED*",
+                validator_identity,
+                active_node,
+                standby_node,
+                issue_lines.join("\n")
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Page the operator when the auto-failover budget is used up and
+    /// auto-failover has locked itself out
+    pub async fn send_failover_lockout_alert(
+        &self,
+        validator_identity: &str,
+        takeovers_in_window: usize,
+        window_seconds: u64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🔒 *AUTO-FAILOVER LOCKED OUT*\n\n\
+                *Validator:* `{}`\n\
+                *Reason:* Still delinquent after {} automatic takeovers in {}\n\n\
+                Auto-failover is OFF for this validator until reset with `svs failover reset`.\n\n\
+                ⚠️ *MANUAL INTERVENTION REQUIRED*",
+                validator_identity,
+                takeovers_in_window,
+                crate::switch_schedule::format_duration(window_seconds)
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Critical alert when more than one node of a pair reports the funded
+    /// identity, with the outcome of any automatic demotion
+    pub async fn send_double_signing_alert(
+        &self,
+        validator_identity: &str,
+        funded_nodes: &[String],
+        demotions: &[(String, std::result::Result<(), String>)],
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let action = if demotions.is_empty() {
+                "none".to_string()
+            } else {
+                demotions
+                    .iter()
+                    .map(|(node, result)| match result {
+                        Ok(()) => format!("• {} → unfunded ✅", node),
+                        Err(e) => format!("• {} → unfunded ❌ {}", node, e),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            let message = format!(
+                "🚨 *DOUBLE-SIGNING RISK*\n\n\
+                *Validator:* `{}`\n\
+                *Nodes with the funded identity:* {}\n\n\
+                *Automatic demotion:*\n{}\n\n\
+                ⚠️ *MANUAL INTERVENTION REQUIRED*",
+                validator_identity,
+                funded_nodes.join(", "),
+                action
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_emergency_demotion_alert(
+        &self,
+        validator_identity: &str,
+        requested_by: &str,
+        demotions: &[(String, std::result::Result<(), String>)],
+        auto_failover_locked: bool,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let results = demotions
+                .iter()
+                .map(|(node, result)| match result {
+                    Ok(()) => format!("• {} → unfunded ✅", node),
+                    Err(e) => format!("• {} → unfunded ❌ {}", node, e),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let lockout = if auto_failover_locked {
+                "Auto-failover is locked out until `svs failover reset`"
+            } else {
+                "⚠️ Auto-failover could not be locked out"
+            };
+            let message = format!(
+                "🛑 *EMERGENCY DEMOTION*\n\n\
+                *Validator:* `{}`\n\
+                *Requested by:* {}\n\n\
+                {}\n\n\
+                {}",
+                validator_identity, requested_by, results, lockout
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Answer a Telegram command in the configured chat
+    pub async fn send_command_reply(&self, message: &str) -> Result<()> {
+        match &self.config.telegram {
+            Some(telegram) => self.send_telegram_message(telegram, message).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Texts of new messages from the configured chat, long-polling Telegram's
+    /// `getUpdates` for up to `timeout_seconds`. `offset` tracks the next update.
+    pub async fn fetch_telegram_commands(
+        &self,
+        offset: &mut i64,
+        timeout_seconds: u64,
+    ) -> Result<Vec<String>> {
+        let Some(telegram) = &self.config.telegram else {
+            return Ok(Vec::new());
+        };
+        let url = format!(
+            "https://api.telegram.org/bot{}/getUpdates",
+            telegram.bot_token
+        );
+        let payload = json!({
+            "offset": *offset,
+            "timeout": timeout_seconds,
+            "allowed_updates": ["message"]
+        });
+
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(timeout_seconds + 10))
+            .build()?;
+        let response = client.post(&url).json(&payload).send().await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = match response.text().await {
+                Ok(text) => text,
+                Err(e) => format!("(failed to read response body: {})", e),
+            };
+            anyhow::bail!("Telegram API error ({}): {}", status, error_text);
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        let mut texts = Vec::new();
+        for update in body["result"].as_array().into_iter().flatten() {
+            if let Some(update_id) = update["update_id"].as_i64() {
+                *offset = (*offset).max(update_id + 1);
+            }
+            let message = &update["message"];
+            let chat_id = match &message["chat"]["id"] {
+                serde_json::Value::Number(id) => id.to_string(),
+                _ => continue,
+            };
+            if chat_id != telegram.chat_id {
+                continue;
+            }
+            if let Some(text) = message["text"].as_str() {
+                texts.push(text.to_string());
+            }
+        }
+        Ok(texts)
+    }
+
     #[allow(dead_code)]
     pub async fn send_ssh_failure_alert(
         &self,
@@ -1,5 +1,5 @@
 #[cfg(test)]
-mod alert_integration_tests {
+mod tests {
     use crate::types::{AlertConfig, FailureTracker, NodeHealthStatus};
     use std::time::{Duration, Instant};
 
@@ -16,6 +16,11 @@ mod alert_integration_tests {
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
 
         // Simulate the actual check from status_ui_v2.rs
@@ -106,6 +111,11 @@ mod alert_integration_tests {
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
 
         let mut ssh_tracker = FailureTracker::new();
@@ -149,6 +159,11 @@ mod alert_integration_tests {
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
 
         // Validator state
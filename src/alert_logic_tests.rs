@@ -1,5 +1,5 @@
 #[cfg(test)]
-mod alert_logic_tests {
+mod tests {
     use crate::alert::ComprehensiveAlertTracker;
     use crate::types::{AlertConfig, FailureTracker, NodeHealthStatus, TelegramConfig};
     use std::time::{Duration, Instant};
@@ -18,6 +18,11 @@ mod alert_logic_tests {
             }),
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         }
     }
 
@@ -19,6 +19,11 @@ mod tests {
             }),
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         }
     }
 
@@ -14,6 +14,11 @@ mod tests {
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: true,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
 
         assert!(alert_config.enabled);
@@ -32,6 +37,11 @@ mod tests {
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
 
         assert!(!alert_config.auto_failover_enabled);
@@ -83,4 +93,71 @@ mod tests {
             "Should NOT trigger failover when RPC is failing"
         );
     }
+
+    fn candidate(
+        node_idx: usize,
+        healthy: bool,
+        slot_lag: Option<u64>,
+        priority: Option<u32>,
+    ) -> crate::emergency_failover::StandbyCandidate {
+        crate::emergency_failover::StandbyCandidate {
+            node_idx,
+            healthy,
+            slot_lag,
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_best_standby_prefers_healthy_then_lag() {
+        use crate::emergency_failover::rank_standby_candidates;
+
+        let ranked = rank_standby_candidates(&[
+            candidate(1, false, Some(0), Some(1)),
+            candidate(2, true, Some(500), Some(1)),
+            candidate(3, true, Some(3), Some(9)),
+        ]);
+        assert_eq!(ranked, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_best_standby_uses_priority_between_caught_up_nodes() {
+        use crate::emergency_failover::rank_standby_candidates;
+
+        // Both within the caught-up tolerance, so priority decides
+        let ranked = rank_standby_candidates(&[
+            candidate(1, true, Some(0), Some(5)),
+            candidate(2, true, Some(4), Some(1)),
+        ]);
+        assert_eq!(ranked, vec![2, 1]);
+
+        // Unset priority and unknown lag rank last; config order breaks ties
+        let ranked = rank_standby_candidates(&[
+            candidate(1, true, None, Some(1)),
+            candidate(2, true, Some(0), None),
+            candidate(3, true, Some(0), None),
+        ]);
+        assert_eq!(ranked, vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn test_standby_fitness_gate() {
+        use crate::emergency_failover::{standby_fitness_issues, MAX_TAKEOVER_SLOT_LAG};
+
+        let fit = candidate(1, true, Some(3), None);
+        assert!(standby_fitness_issues(&fit, true, &[]).is_empty());
+
+        let lagging = candidate(1, true, Some(MAX_TAKEOVER_SLOT_LAG + 1), None);
+        assert_eq!(standby_fitness_issues(&lagging, true, &[]).len(), 1);
+
+        // Unknown lag cannot be verified, so it is unfit as well
+        let unhealthy = candidate(1, false, None, None);
+        let issues = standby_fitness_issues(
+            &unhealthy,
+            false,
+            &["Ledger directory not writable".to_string()],
+        );
+        assert_eq!(issues.len(), 3);
+        assert!(issues[2].contains("Ledger directory not writable"));
+    }
 }
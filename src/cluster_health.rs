@@ -0,0 +1,97 @@
+use std::time::Instant;
+
+/// With at least this share of stake delinquent the cluster itself is in trouble,
+/// not our validator
+pub const CLUSTER_STALL_DELINQUENT_STAKE_FRACTION: f64 = 0.2;
+
+/// The finalized slot normally advances every ~400ms; this long without progress
+/// means the cluster has stopped rooting
+pub const CLUSTER_STALL_ROOT_SECONDS: u64 = 20;
+
+/// Cluster-wide health as seen from a pair's vote-account polling
+#[derive(Debug, Clone, Default)]
+pub struct ClusterHealth {
+    /// Highest finalized slot seen and when it was first seen
+    pub last_root: Option<(u64, Instant)>,
+    pub delinquent_stake_fraction: Option<f64>,
+    /// Last successful sample; stalls are measured between samples so an RPC
+    /// outage is not mistaken for a halted cluster
+    pub last_sample: Option<Instant>,
+    /// The current stall has already been reported
+    pub stall_notified: bool,
+}
+
+impl ClusterHealth {
+    pub fn record(&mut self, root_slot: u64, delinquent_stake_fraction: f64, now: Instant) {
+        match self.last_root {
+            Some((last_slot, _)) if root_slot <= last_slot => {}
+            _ => self.last_root = Some((root_slot, now)),
+        }
+        self.delinquent_stake_fraction = Some(delinquent_stake_fraction);
+        self.last_sample = Some(now);
+    }
+
+    /// Why the cluster looks stalled, or None when it is making progress
+    pub fn stall_reason(&self) -> Option<String> {
+        let mut reasons = Vec::new();
+        if let (Some((slot, since)), Some(last_sample)) = (self.last_root, self.last_sample) {
+            let stalled_for = last_sample.saturating_duration_since(since).as_secs();
+            if stalled_for >= CLUSTER_STALL_ROOT_SECONDS {
+                reasons.push(format!(
+                    "finalized slot stuck at {} for {}s",
+                    slot, stalled_for
+                ));
+            }
+        }
+        if let Some(fraction) = self.delinquent_stake_fraction {
+            if fraction >= CLUSTER_STALL_DELINQUENT_STAKE_FRACTION {
+                reasons.push(format!("{:.0}% of stake delinquent", fraction * 100.0));
+            }
+        }
+
+        if reasons.is_empty() {
+            None
+        } else {
+            Some(reasons.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod cluster_health_tests {
+    use super::ClusterHealth;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn healthy_cluster_is_not_stalled() {
+        let start = Instant::now();
+        let mut health = ClusterHealth::default();
+        health.record(1000, 0.02, start);
+        health.record(1050, 0.02, start + Duration::from_secs(20));
+        assert_eq!(health.stall_reason(), None);
+    }
+
+    #[test]
+    fn root_not_advancing_is_a_stall() {
+        let start = Instant::now();
+        let mut health = ClusterHealth::default();
+        health.record(1000, 0.02, start);
+        // A lagging endpoint reporting an older root does not reset progress
+        health.record(990, 0.02, start + Duration::from_secs(10));
+        health.record(1000, 0.02, start + Duration::from_secs(20));
+        let reason = health.stall_reason().unwrap();
+        assert!(reason.contains("stuck at 1000"));
+    }
+
+    #[test]
+    fn delinquent_stake_share_is_a_stall() {
+        let start = Instant::now();
+        let mut health = ClusterHealth::default();
+        health.record(1000, 0.35, start);
+        let reason = health.stall_reason().unwrap();
+        assert!(reason.contains("35% of stake delinquent"));
+
+        health.record(1010, 0.05, start + Duration::from_secs(5));
+        assert_eq!(health.stall_reason(), None);
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::collections::HashMap;
+
+use crate::alert::AlertManager;
+use crate::ssh::AsyncSshPool;
+use crate::{AppState, ValidatorStatus};
+
+/// Outcome of demoting every node of a pair
+#[derive(Debug)]
+pub struct EmergencyDemotion {
+    /// Node label and the error, if its demotion failed
+    pub nodes: Vec<(String, std::result::Result<(), String>)>,
+    /// Why auto-failover could not be locked out, if it could not
+    pub lockout_error: Option<String>,
+}
+
+impl EmergencyDemotion {
+    pub fn failed_nodes(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .count()
+    }
+}
+
+/// Move every node of the pair to its unfunded identity in parallel without
+/// promoting anyone. Auto-failover is locked out first so a running
+/// `svs status` does not promote a standby once the votes stop.
+pub async fn demote_all_nodes(
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+    validator_status: &ValidatorStatus,
+) -> EmergencyDemotion {
+    let lockout_error = crate::failover_budget::lock_out_auto_failover(
+        &validator_status.validator_pair.identity_pubkey,
+    )
+    .err()
+    .map(|e| e.to_string());
+
+    let demotions = validator_status
+        .nodes_with_status
+        .iter()
+        .map(|node| async move {
+            let result =
+                crate::commands::switch::demote_to_unfunded(ssh_pool, detected_ssh_keys, node)
+                    .await
+                    .map_err(|e| e.to_string());
+            (node.node.label.clone(), result)
+        });
+
+    EmergencyDemotion {
+        nodes: futures::future::join_all(demotions).await,
+        lockout_error,
+    }
+}
+
+/// Report a demotion through the configured alert channels
+pub async fn send_demotion_alert(
+    app_state: &AppState,
+    validator_status: &ValidatorStatus,
+    demotion: &EmergencyDemotion,
+    requested_by: &str,
+) -> Result<()> {
+    match app_state.config.alert_config.as_ref() {
+        Some(alert_config) if alert_config.enabled => {
+            AlertManager::new(alert_config.clone())
+                .send_emergency_demotion_alert(
+                    &validator_status.validator_pair.identity_pubkey,
+                    requested_by,
+                    &demotion.nodes,
+                    demotion.lockout_error.is_none(),
+                )
+                .await
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The validator argument of a `/demote` Telegram command: `Some(None)` when
+/// it is missing, `None` when the text is not a `/demote` command at all
+pub fn parse_demote_command(text: &str) -> Option<Option<&str>> {
+    let mut words = text.split_whitespace();
+    let command = words.next()?;
+    // Group chats address commands as /demote@botname
+    if command.split('@').next() != Some("/demote") {
+        return None;
+    }
+    Some(words.next())
+}
+
+/// `svs demote`: move every node of the selected validator to its unfunded
+/// identity so nothing votes with the funded identity
+pub async fn demote_command(app_state: &AppState, yes: bool) -> Result<()> {
+    let validator_status = &app_state.validator_statuses[app_state.selected_validator_index];
+    let identity = &validator_status.validator_pair.identity_pubkey;
+
+    println!("\n{}", "🛑 Emergency Demotion".bright_red().bold());
+    println!("{}", "━".repeat(50).dimmed());
+    println!("   Validator: {}", identity.bright_white());
+    for node in &validator_status.nodes_with_status {
+        println!(
+            "   {} ({}) → unfunded identity",
+            node.node.label, node.node.host
+        );
+    }
+    println!(
+        "   {}",
+        "No node is promoted; the validator stops voting until you switch again".yellow()
+    );
+    println!(
+        "   {}",
+        "Auto-failover is locked out until `svs failover reset`".yellow()
+    );
+
+    if !yes {
+        use inquire::Confirm;
+        crate::terminal::ensure_interactive("Demote every node of this validator now?")?;
+        let confirmed = Confirm::new("Demote every node of this validator now?")
+            .with_default(false)
+            .prompt()?;
+        if !confirmed {
+            println!("{}", "Demotion cancelled".dimmed());
+            return Ok(());
+        }
+    }
+
+    let demotion = demote_all_nodes(
+        &app_state.ssh_pool,
+        &app_state.detected_ssh_keys,
+        validator_status,
+    )
+    .await;
+
+    println!();
+    for (label, result) in &demotion.nodes {
+        match result {
+            Ok(()) => println!("   ✅ {} switched to its unfunded identity", label),
+            Err(e) => println!("   ❌ {}: {}", label, e.red()),
+        }
+    }
+    match &demotion.lockout_error {
+        None => println!("   🔒 Auto-failover locked out"),
+        Some(e) => println!("   ⚠️  Failed to lock out auto-failover: {}", e),
+    }
+
+    if let Err(e) = send_demotion_alert(app_state, validator_status, &demotion, "svs demote").await
+    {
+        println!("   ⚠️  Failed to send demotion alert: {}", e);
+    }
+
+    match demotion.failed_nodes() {
+        0 => Ok(()),
+        failed => Err(anyhow!(
+            "{} of {} nodes could not be demoted",
+            failed,
+            demotion.nodes.len()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_demote_command;
+
+    #[test]
+    fn parses_the_demote_command() {
+        assert_eq!(parse_demote_command("/demote 0"), Some(Some("0")));
+        assert_eq!(
+            parse_demote_command("/demote@svs_bot  7xKX "),
+            Some(Some("7xKX"))
+        );
+        assert_eq!(parse_demote_command("/demote"), Some(None));
+        assert_eq!(parse_demote_command("/demoted 0"), None);
+        assert_eq!(parse_demote_command("please /demote 0"), None);
+        assert_eq!(parse_demote_command(""), None);
+    }
+}
@@ -39,7 +39,6 @@ pub enum SwitchError {
 
 impl SwitchError {
     /// Convert to user-friendly error message with recovery suggestions
-    #[allow(dead_code)]
     pub fn to_user_message(&self) -> String {
         match self {
             SwitchError::SshConnectionFailed { host, details } => {
@@ -145,7 +144,6 @@ impl SwitchError {
     }
 
     /// Get exit code for this error type
-    #[allow(dead_code)]
     pub fn exit_code(&self) -> i32 {
         match self {
             SwitchError::SshConnectionFailed { .. } => 10,
@@ -160,6 +158,92 @@ impl SwitchError {
     }
 }
 
+impl std::fmt::Display for SwitchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwitchError::SshConnectionFailed { host, details } => {
+                write!(f, "SSH connection to {} failed: {}", host, details)
+            }
+            SwitchError::TowerFileNotFound { path } => {
+                write!(f, "Tower file not found: {}", path)
+            }
+            SwitchError::ExecutableNotFound {
+                name,
+                validator_type,
+            } => write!(f, "{} executable '{}' not found", validator_type, name),
+            SwitchError::PermissionDenied { operation, path } => {
+                write!(f, "Permission denied while {}: {}", operation, path)
+            }
+            SwitchError::NetworkTimeout {
+                operation,
+                elapsed_secs,
+            } => write!(f, "{} timed out after {}s", operation, elapsed_secs),
+            SwitchError::PartialSwitch {
+                active_status,
+                standby_status,
+            } => write!(
+                f,
+                "Partial switch - active node: {}; standby node: {}",
+                active_status, standby_status
+            ),
+            SwitchError::ConfigurationError { message } => {
+                write!(f, "Configuration error: {}", message)
+            }
+            SwitchError::ValidationFailed { issues } => write!(
+                f,
+                "Switch validation failed: {} critical issue(s): {}",
+                issues.len(),
+                issues.join("; ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SwitchError {}
+
+/// The `SwitchError` behind an error, if the switch attached one
+pub fn find_switch_error(error: &anyhow::Error) -> Option<&SwitchError> {
+    error.chain().find_map(|e| e.downcast_ref::<SwitchError>())
+}
+
+/// Process exit code for a failed command: the `SwitchError` code, or 1
+pub fn exit_code_for(error: &anyhow::Error) -> i32 {
+    find_switch_error(error)
+        .map(SwitchError::exit_code)
+        .unwrap_or(1)
+}
+
+/// Attach a `SwitchError` to a failed remote step when its message shows an SSH
+/// or permission problem; other errors are returned unchanged
+pub fn classify_step_error(error: anyhow::Error, operation: &str, host: &str) -> anyhow::Error {
+    if find_switch_error(&error).is_some() {
+        return error;
+    }
+
+    let message = error.to_string();
+    if message.contains("Failed to connect")
+        || message.contains("Connection refused")
+        || message.contains("Connection timed out")
+        || message.contains("Permission denied (publickey")
+    {
+        return SwitchError::SshConnectionFailed {
+            host: host.to_string(),
+            details: message,
+        }
+        .into();
+    }
+
+    if message.contains("Permission denied") {
+        return SwitchError::PermissionDenied {
+            operation: operation.to_string(),
+            path: extract_path_from_error(&message).unwrap_or_else(|| host.to_string()),
+        }
+        .into();
+    }
+
+    error
+}
+
 /// Wrap anyhow errors with better context
 #[allow(dead_code)]
 pub fn enhance_error_context(error: anyhow::Error) -> anyhow::Error {
@@ -224,7 +308,6 @@ fn extract_host_from_error(error: &str) -> Option<String> {
     None
 }
 
-#[allow(dead_code)]
 fn extract_path_from_error(error: &str) -> Option<String> {
     // Simple extraction - could be enhanced with regex
     if let Some(pos) = error.find('/') {
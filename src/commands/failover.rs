@@ -0,0 +1,94 @@
+use anyhow::Result;
+use chrono::{Local, Utc};
+use colored::*;
+
+use crate::failover_budget::{FailoverBudget, FailoverDecision};
+use crate::switch_schedule::format_duration;
+use crate::AppState;
+
+/// `svs failover status`: auto-failover budget and lockout state per validator
+pub fn failover_status_command(app_state: &AppState) -> Result<()> {
+    let Some(alert_config) = app_state.config.alert_config.as_ref() else {
+        println!(
+            "{}",
+            "Alerts are not configured - auto-failover is off".dimmed()
+        );
+        return Ok(());
+    };
+
+    let budget = FailoverBudget::load()?;
+    let now = Utc::now();
+
+    println!("{}", "🛡️  Auto-failover budget".bright_cyan().bold());
+    println!(
+        "   {} automatic takeovers per {}, {} dwell time after any switch{}",
+        alert_config.max_auto_failovers,
+        format_duration(alert_config.auto_failover_window_seconds),
+        format_duration(alert_config.auto_failover_min_dwell_seconds),
+        if alert_config.auto_failover_enabled {
+            String::new()
+        } else {
+            format!(" {}", "(auto-failover disabled)".yellow())
+        }
+    );
+
+    for validator_status in &app_state.validator_statuses {
+        let identity = &validator_status.validator_pair.identity_pubkey;
+        println!("\n   Validator: {}", identity.bright_white());
+
+        let history = budget.history(identity).cloned().unwrap_or_default();
+        let window = chrono::Duration::seconds(alert_config.auto_failover_window_seconds as i64);
+        let recent = history
+            .takeovers
+            .iter()
+            .filter(|&&at| now - at < window)
+            .count();
+        println!(
+            "      Takeovers in window: {}/{}",
+            recent, alert_config.max_auto_failovers
+        );
+        if let Some(last_switch) = history.last_switch_at {
+            println!(
+                "      Last switch:         {}",
+                last_switch
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S %Z")
+            );
+        }
+
+        let decision = budget.check(identity, alert_config, now);
+        let state = match &decision {
+            FailoverDecision::Allowed => "ready".green(),
+            FailoverDecision::LockedOut { .. } => decision.to_string().red().bold(),
+            _ => decision.to_string().yellow(),
+        };
+        println!("      State:               {}", state);
+    }
+    Ok(())
+}
+
+/// `svs failover reset`: re-enable auto-failover for the selected validator
+pub fn failover_reset_command(app_state: &AppState) -> Result<()> {
+    let identity = &app_state.validator_statuses[app_state.selected_validator_index]
+        .validator_pair
+        .identity_pubkey;
+
+    let mut budget = FailoverBudget::load()?;
+    if !budget.reset(identity) {
+        println!(
+            "{}",
+            format!("Nothing to reset for validator {}", identity).dimmed()
+        );
+        return Ok(());
+    }
+    budget.save()?;
+
+    println!(
+        "{} {}",
+        "✅ Auto-failover lockout and budget reset for"
+            .bright_green()
+            .bold(),
+        identity
+    );
+    Ok(())
+}
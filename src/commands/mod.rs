@@ -1,9 +1,19 @@
+pub mod demote;
 pub mod error_handler;
+pub mod failover;
+pub mod monitor;
+pub mod plan;
+pub mod schedule;
 pub mod status;
 pub mod status_ui_v2;
 pub mod switch;
 pub mod test_alert;
 
+pub use demote::demote_command;
+pub use failover::{failover_reset_command, failover_status_command};
+pub use monitor::monitor_command;
+pub use plan::{apply_command, plan_command};
+pub use schedule::{schedule_add_command, schedule_list_command, schedule_remove_command};
 pub use status::status_command;
 pub use switch::switch_command;
 pub use test_alert::test_alert_command;
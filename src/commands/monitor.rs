@@ -0,0 +1,105 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::RwLock;
+
+use crate::commands::status_ui_v2::{default_log_path, EnhancedStatusApp, LogLevel, LogMessage};
+use crate::AppState;
+
+/// `svs monitor`: run the status view's background tasks (vote polling,
+/// alerts, auto-failover, the double-signing guard, Telegram commands and
+/// scheduled switches) without a terminal UI, logging to a file. SIGTERM or
+/// Ctrl+C stops it once no emergency takeover is running.
+pub async fn monitor_command(app_state: &AppState, log_file: Option<PathBuf>) -> Result<()> {
+    let log_path = log_file.unwrap_or_else(default_log_path);
+    if let Some(log_dir) = log_path.parent() {
+        std::fs::create_dir_all(log_dir)?;
+    }
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut current_app_state = app_state.clone();
+
+    println!(
+        "🛰️  Monitoring {} validator(s) without a terminal UI, logging to {}",
+        current_app_state.validator_statuses.len(),
+        log_path.display()
+    );
+
+    loop {
+        let app =
+            EnhancedStatusApp::with_log_path(Arc::new(current_app_state.clone()), log_path.clone())
+                .await?;
+        app.spawn_background_tasks();
+        log(&app, "Monitor started", LogLevel::Info);
+
+        // The schedule task asks the status loop to quit when a switch is due
+        let stop_requested = tokio::select! {
+            _ = sigterm.recv() => true,
+            _ = tokio::signal::ctrl_c() => true,
+            _ = wait_for(&app.should_quit) => false,
+        };
+
+        if stop_requested {
+            if *app.emergency_takeover_in_progress.read().await {
+                println!("⏳ Waiting for the running emergency takeover before stopping...");
+                log(
+                    &app,
+                    "Stop requested during an emergency takeover; waiting for it to finish",
+                    LogLevel::Warning,
+                );
+                wait_while(&app.emergency_takeover_in_progress).await;
+            }
+            app.stop_background_tasks().await;
+            log(&app, "Monitor stopped", LogLevel::Info);
+            // Give the log writer a moment to append the last lines
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            println!("👋 Monitor stopped");
+            return Ok(());
+        }
+
+        app.stop_background_tasks().await;
+        let Some(scheduled) = app.scheduled_switch.write().await.take() else {
+            continue;
+        };
+        log(
+            &app,
+            &format!("Running scheduled switch {}", scheduled.describe()),
+            LogLevel::Info,
+        );
+        let (message, level) = match crate::commands::schedule::execute_scheduled_switch(
+            &mut current_app_state,
+            scheduled,
+        )
+        .await
+        {
+            Ok(true) => ("Scheduled switch completed".to_string(), LogLevel::Info),
+            Ok(false) => ("Scheduled switch skipped".to_string(), LogLevel::Warning),
+            Err(e) => (format!("Scheduled switch failed: {}", e), LogLevel::Error),
+        };
+        println!("{}", message);
+        log(&app, &message, level);
+    }
+}
+
+fn log(app: &EnhancedStatusApp, message: &str, level: LogLevel) {
+    let _ = app.log_sender.send(LogMessage {
+        host: "monitor".to_string(),
+        message: message.to_string(),
+        timestamp: Instant::now(),
+        level,
+    });
+}
+
+async fn wait_for(flag: &Arc<RwLock<bool>>) {
+    while !*flag.read().await {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+async fn wait_while(flag: &Arc<RwLock<bool>>) {
+    while *flag.read().await {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
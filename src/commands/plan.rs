@@ -0,0 +1,129 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::Path;
+
+use crate::commands::switch::{
+    firedancer_config_path, resolve_switch_nodes, switch_command_with_confirmation,
+    validate_switch_nodes,
+};
+use crate::switch_plan::SwitchPlan;
+use crate::types::ValidatorType;
+use crate::AppState;
+
+fn print_plan(plan: &SwitchPlan) {
+    println!("   Validator: {}", plan.identity_pubkey);
+    println!(
+        "   {} ({}) → {} ({})",
+        plan.source.label, plan.source.host, plan.target.label, plan.target.host
+    );
+    println!("   Created:   {}", plan.created_at);
+    println!("\n{}", "Commands:".dimmed());
+    for (idx, command) in plan.commands.iter().enumerate() {
+        println!("   {}. {}", idx + 1, command);
+    }
+}
+
+/// `svs switch --plan <file>`: resolve the switch for the selected validator and
+/// write it as a reviewable plan instead of executing it
+pub async fn plan_command(app_state: &mut AppState, path: &Path) -> Result<()> {
+    let target_label = app_state.switch_target_label.take();
+    let validator_status = &app_state.validator_statuses[app_state.selected_validator_index];
+    if validator_status.nodes_with_status.len() < 2 {
+        return Err(anyhow!(
+            "Single node configuration - switching not available"
+        ));
+    }
+
+    let (source, target) =
+        resolve_switch_nodes(app_state, validator_status, target_label.as_deref()).await?;
+    validate_switch_nodes(app_state, source, target, true, false).await?;
+
+    // Resolve anything startup left for the switch to look up, so the plan is complete
+    let mut source = source.clone();
+    let mut target = target.clone();
+    for node in [&mut source, &mut target] {
+        if node.validator_type == ValidatorType::Firedancer && node.firedancer_config_path.is_none()
+        {
+            node.firedancer_config_path = Some(
+                firedancer_config_path(&app_state.ssh_pool, &app_state.detected_ssh_keys, node)
+                    .await?,
+            );
+        }
+    }
+
+    let plan = SwitchPlan::new(
+        &validator_status.validator_pair,
+        &source,
+        &target,
+        app_state.config.switch_config.clone().unwrap_or_default(),
+    )?;
+    plan.save(path)?;
+
+    println!(
+        "\n{} {}",
+        "📝 Switch plan written to".bright_green().bold(),
+        path.display()
+    );
+    print_plan(&plan);
+    println!(
+        "\n{}",
+        format!("Review it, then run `svs apply {}`", path.display()).dimmed()
+    );
+    Ok(())
+}
+
+/// `svs apply <file>`: execute a plan once the nodes still match its preconditions
+pub async fn apply_command(app_state: &mut AppState, path: &Path) -> Result<bool> {
+    let plan = SwitchPlan::load(path)?;
+
+    let validator_idx = app_state
+        .validator_statuses
+        .iter()
+        .position(|v| v.validator_pair.identity_pubkey == plan.identity_pubkey)
+        .ok_or_else(|| {
+            anyhow!(
+                "Plan is for validator {} which is not in this configuration",
+                plan.identity_pubkey
+            )
+        })?;
+    app_state.selected_validator_index = validator_idx;
+
+    println!("\n{}", "📋 Applying switch plan".bright_cyan().bold());
+    print_plan(&plan);
+
+    let nodes = &mut app_state.validator_statuses[validator_idx].nodes_with_status;
+    let mismatches = plan.precondition_mismatches(nodes);
+    if !mismatches.is_empty() {
+        println!("\n{}", "❌ PLAN PRECONDITIONS NO LONGER HOLD".red().bold());
+        for mismatch in &mismatches {
+            println!("  • {}", mismatch.red());
+        }
+        println!(
+            "\n{}",
+            "Create a new plan with `svs switch --plan` and review it again.".yellow()
+        );
+        return Err(anyhow!(
+            "Plan preconditions no longer hold: {} difference(s)",
+            mismatches.len()
+        ));
+    }
+    println!(
+        "\n{}",
+        "✅ Nodes match the plan's preconditions".bright_green()
+    );
+
+    // Execute with the plan's resolved paths and switch settings
+    for node in nodes.iter_mut() {
+        if node.firedancer_config_path.is_none() {
+            if node.node.label == plan.source.label {
+                node.firedancer_config_path = plan.source.firedancer_config_path.clone();
+            } else if node.node.label == plan.target.label {
+                node.firedancer_config_path = plan.target.firedancer_config_path.clone();
+            }
+        }
+    }
+    app_state.config.switch_config = Some(plan.switch_config.clone());
+    app_state.switch_target_label = Some(plan.target.label.clone());
+
+    switch_command_with_confirmation(false, app_state, true).await
+}
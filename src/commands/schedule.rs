@@ -0,0 +1,308 @@
+use anyhow::{anyhow, Result};
+use chrono::{Duration as ChronoDuration, Local, Utc};
+use colored::*;
+
+use crate::alert::AlertManager;
+use crate::switch_schedule::{
+    format_duration, parse_duration, parse_schedule_time, ScheduledSwitch, SwitchSchedule,
+    MISSED_SWITCH_GRACE_SECONDS,
+};
+use crate::types::NodeStatus;
+use crate::AppState;
+
+/// `svs schedule add`: persist a planned switch for the selected validator
+pub fn schedule_add_command(
+    app_state: &AppState,
+    at: &str,
+    switch_back_after: Option<&str>,
+    to: Option<String>,
+) -> Result<()> {
+    let at = parse_schedule_time(at)?;
+    if at <= Utc::now() {
+        return Err(anyhow!(
+            "Scheduled time {} is in the past",
+            at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S %Z")
+        ));
+    }
+
+    let switch_back_after_seconds = switch_back_after
+        .map(parse_duration)
+        .transpose()?
+        .map(|duration| duration.as_secs());
+
+    let validator_status = &app_state.validator_statuses[app_state.selected_validator_index];
+    if validator_status.nodes_with_status.len() < 2 {
+        return Err(anyhow!(
+            "Validator {} has a single node - nothing to switch to",
+            validator_status.validator_pair.identity_pubkey
+        ));
+    }
+
+    if let Some(label) = &to {
+        if !validator_status
+            .nodes_with_status
+            .iter()
+            .any(|n| &n.node.label == label)
+        {
+            return Err(anyhow!(
+                "No node labelled '{}' in this validator pair",
+                label
+            ));
+        }
+    }
+
+    let mut schedule = SwitchSchedule::load()?;
+    let id = schedule.add(
+        &validator_status.validator_pair.identity_pubkey,
+        at,
+        to,
+        switch_back_after_seconds,
+        false,
+    );
+    schedule.save()?;
+
+    let scheduled = schedule
+        .switches
+        .iter()
+        .find(|s| s.id == id)
+        .expect("scheduled switch was just added");
+    println!(
+        "{} {}",
+        "✅ Scheduled switch".bright_green().bold(),
+        scheduled.describe()
+    );
+    println!(
+        "   Validator: {}",
+        validator_status.validator_pair.identity_pubkey
+    );
+    println!(
+        "{}",
+        "   Keep `svs status` running - it executes scheduled switches".dimmed()
+    );
+    Ok(())
+}
+
+/// `svs schedule list`
+pub fn schedule_list_command() -> Result<()> {
+    let schedule = SwitchSchedule::load()?;
+    if schedule.switches.is_empty() {
+        println!("{}", "No scheduled switches".dimmed());
+        return Ok(());
+    }
+
+    println!("{}", "🗓️  Scheduled switches".bright_cyan().bold());
+    for scheduled in &schedule.switches {
+        println!("   {}", scheduled.describe());
+        println!("      Validator: {}", scheduled.identity_pubkey.dimmed());
+    }
+    Ok(())
+}
+
+/// `svs schedule remove`
+pub fn schedule_remove_command(id: u64) -> Result<()> {
+    let mut schedule = SwitchSchedule::load()?;
+    let removed = schedule
+        .remove(id)
+        .ok_or_else(|| anyhow!("No scheduled switch with id {}", id))?;
+    schedule.save()?;
+
+    println!(
+        "{} {}",
+        "🗑️  Removed scheduled switch".bright_yellow(),
+        removed.describe()
+    );
+    Ok(())
+}
+
+async fn notify(
+    alert_manager: Option<&AlertManager>,
+    identity: &str,
+    title: &str,
+    details: &str,
+    high_priority: bool,
+) {
+    if let Some(alert_manager) = alert_manager {
+        let _ = alert_manager
+            .send_scheduled_switch_notice(identity, title, details, high_priority)
+            .await;
+    }
+}
+
+/// Scheduled switches run unattended: no confirmation prompt and no
+/// "press any key" pause, which would stall the status process
+const SCHEDULED_SWITCH_NON_INTERACTIVE: bool = true;
+
+/// Run a due scheduled switch from the status process. Returns whether a switch
+/// was performed; skipped switches are reported over Telegram instead.
+pub async fn execute_scheduled_switch(
+    app_state: &mut AppState,
+    scheduled: ScheduledSwitch,
+) -> Result<bool> {
+    let alert_manager = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .map(|config| AlertManager::new(config.clone()));
+    let alert_manager = alert_manager.as_ref();
+    let identity = scheduled.identity_pubkey.clone();
+
+    let Some(validator_idx) = app_state
+        .validator_statuses
+        .iter()
+        .position(|v| v.validator_pair.identity_pubkey == identity)
+    else {
+        notify(
+            alert_manager,
+            &identity,
+            "SCHEDULED SWITCH SKIPPED",
+            &format!(
+                "*Switch:* {}\n*Reason:* validator is no longer configured",
+                scheduled.describe()
+            ),
+            true,
+        )
+        .await;
+        return Ok(false);
+    };
+
+    let late_seconds = (Utc::now() - scheduled.at).num_seconds();
+    if late_seconds > MISSED_SWITCH_GRACE_SECONDS {
+        notify(
+            alert_manager,
+            &identity,
+            "SCHEDULED SWITCH SKIPPED",
+            &format!(
+                "*Switch:* {}\n*Reason:* missed by {} (svs was not running)",
+                scheduled.describe(),
+                format_duration(late_seconds as u64)
+            ),
+            true,
+        )
+        .await;
+        return Ok(false);
+    }
+
+    let nodes = &app_state.validator_statuses[validator_idx].nodes_with_status;
+    let previous_active = nodes
+        .iter()
+        .find(|n| n.status == NodeStatus::Active)
+        .map(|n| n.node.label.clone());
+
+    if let Some(label) = &scheduled.to_node {
+        if previous_active.as_ref() == Some(label) {
+            notify(
+                alert_manager,
+                &identity,
+                "SCHEDULED SWITCH SKIPPED",
+                &format!(
+                    "*Switch:* {}\n*Reason:* {} is already active",
+                    scheduled.describe(),
+                    label
+                ),
+                false,
+            )
+            .await;
+            return Ok(false);
+        }
+
+        if let Some(target) = nodes.iter().find(|n| &n.node.label == label) {
+            if target.swap_ready == Some(false) {
+                notify(
+                    alert_manager,
+                    &identity,
+                    "SCHEDULED SWITCH SKIPPED",
+                    &format!(
+                        "*Switch:* {}\n*Reason:* {} is not swap ready: {}",
+                        scheduled.describe(),
+                        label,
+                        target.swap_issues.join("; ")
+                    ),
+                    true,
+                )
+                .await;
+                return Ok(false);
+            }
+        }
+    }
+
+    app_state.selected_validator_index = validator_idx;
+    app_state.switch_target_label = scheduled.to_node.clone();
+
+    notify(
+        alert_manager,
+        &identity,
+        if scheduled.is_switch_back {
+            "SCHEDULED SWITCH BACK STARTING"
+        } else {
+            "SCHEDULED SWITCH STARTING"
+        },
+        &format!(
+            "*Switch:* {}\n*Active:* {}",
+            scheduled.describe(),
+            previous_active.as_deref().unwrap_or("unknown")
+        ),
+        false,
+    )
+    .await;
+
+    // The switch itself sends the usual completion/failure notification
+    match crate::commands::switch::run_switch(
+        false,
+        app_state,
+        false,
+        SCHEDULED_SWITCH_NON_INTERACTIVE,
+        &mut crate::commands::switch::SwitchReport::default(),
+    )
+    .await
+    {
+        Ok(_) => {
+            if let (Some(seconds), Some(previous_active)) =
+                (scheduled.switch_back_after_seconds, previous_active)
+            {
+                let at = Utc::now() + ChronoDuration::seconds(seconds as i64);
+                let mut schedule = SwitchSchedule::load()?;
+                schedule.add(&identity, at, Some(previous_active.clone()), None, true);
+                schedule.save()?;
+
+                notify(
+                    alert_manager,
+                    &identity,
+                    "SWITCH BACK SCHEDULED",
+                    &format!(
+                        "*Back to:* {}\n*At:* {}",
+                        previous_active,
+                        at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S %Z")
+                    ),
+                    false,
+                )
+                .await;
+            }
+            Ok(true)
+        }
+        Err(e) => {
+            notify(
+                alert_manager,
+                &identity,
+                "SCHEDULED SWITCH NOT COMPLETED",
+                &format!("*Switch:* {}\n*Error:* {}", scheduled.describe(), e),
+                true,
+            )
+            .await;
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SCHEDULED_SWITCH_NON_INTERACTIVE;
+    use crate::commands::switch::pauses_after_switch;
+
+    #[test]
+    fn scheduled_switches_never_wait_for_a_key_press() {
+        assert!(!pauses_after_switch(
+            false,
+            SCHEDULED_SWITCH_NON_INTERACTIVE
+        ));
+    }
+}
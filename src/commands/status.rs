@@ -143,22 +143,7 @@ async fn display_status_with_rpc_data(app_state: &AppState, full_display: bool)
             };
 
         if full_display {
-            // Get the two nodes with their statuses
-            let nodes_with_status = &validator_status.nodes_with_status;
-            if nodes_with_status.len() >= 2 {
-                let node_0 = &nodes_with_status[0];
-                let node_1 = &nodes_with_status[1];
-
-                display_simple_status_table_with_rpc(
-                    &node_0.node,
-                    &node_0.status,
-                    &node_1.node,
-                    &node_1.status,
-                    validator_status,
-                    vote_data.as_ref(),
-                    app_state,
-                );
-            }
+            display_simple_status_table(validator_status, vote_data.as_ref(), app_state);
 
             println!();
         }
@@ -199,306 +184,6 @@ fn display_vote_data(vote_data: &ValidatorVoteData) {
     );
 }
 
-#[allow(dead_code)]
-fn display_simple_status_table_with_rpc(
-    node_0: &crate::types::NodeConfig,
-    node_0_status: &crate::types::NodeStatus,
-    node_1: &crate::types::NodeConfig,
-    node_1_status: &crate::types::NodeStatus,
-    validator_status: &crate::ValidatorStatus,
-    vote_data: Option<&ValidatorVoteData>,
-    app_state: &AppState,
-) {
-    let mut table = Table::new();
-
-    // Create custom table style with minimal borders
-    table
-        .load_preset(comfy_table::presets::UTF8_BORDERS_ONLY)
-        .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_content_arrangement(ContentArrangement::Dynamic);
-
-    // Header row with dynamic labels
-    let node_0_label = match node_0_status {
-        crate::types::NodeStatus::Active => "ACTIVE",
-        crate::types::NodeStatus::Standby => "STANDBY",
-        crate::types::NodeStatus::Unknown => "UNKNOWN",
-    };
-    let node_1_label = match node_1_status {
-        crate::types::NodeStatus::Active => "ACTIVE",
-        crate::types::NodeStatus::Standby => "STANDBY",
-        crate::types::NodeStatus::Unknown => "UNKNOWN",
-    };
-
-    let node_0_color = if node_0_label == "ACTIVE" {
-        Color::Green
-    } else if node_0_label == "STANDBY" {
-        Color::Yellow
-    } else {
-        Color::DarkGrey
-    };
-    let node_1_color = if node_1_label == "ACTIVE" {
-        Color::Green
-    } else if node_1_label == "STANDBY" {
-        Color::Yellow
-    } else {
-        Color::DarkGrey
-    };
-
-    // Node info as header
-    let node_0_info = format!("🖥️ {} ({})", node_0.label, node_0.host);
-    let node_1_info = format!("🖥️ {} ({})", node_1.label, node_1.host);
-
-    table.add_row(vec![
-        Cell::new("Node")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(&node_0_info),
-        Cell::new(&node_1_info),
-    ]);
-
-    // Add separator line after subheader
-    table.add_row(vec![
-        Cell::new("─".repeat(15)).fg(Color::DarkGrey),
-        Cell::new("─".repeat(25)).fg(Color::DarkGrey),
-        Cell::new("─".repeat(25)).fg(Color::DarkGrey),
-    ]);
-
-    // Status rows with basic info
-    table.add_row(vec![
-        Cell::new("Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_label).fg(node_0_color),
-        Cell::new(node_1_label).fg(node_1_color),
-    ]);
-
-    // Add executable paths
-    let node_0_agave = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.agave_validator_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_agave = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.agave_validator_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Agave Executable")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_agave),
-        Cell::new(node_1_agave),
-    ]);
-
-    // Add Solana CLI executable row
-    let node_0_solana = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.solana_cli_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_solana = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.solana_cli_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Solana CLI")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_solana),
-        Cell::new(node_1_solana),
-    ]);
-
-    let node_0_fdctl = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.fdctl_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_fdctl = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.fdctl_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Fdctl Executable")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_fdctl),
-        Cell::new(node_1_fdctl),
-    ]);
-
-    // Add version information
-    let node_0_version = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.version.as_ref())
-        .cloned()
-        .unwrap_or("N/A".to_string());
-    let node_1_version = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.version.as_ref())
-        .cloned()
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Version")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_version),
-        Cell::new(node_1_version),
-    ]);
-
-    // Add sync status
-    let node_0_sync = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.sync_status.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-    let node_1_sync = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.sync_status.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-
-    table.add_row(vec![
-        Cell::new("Sync Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_sync),
-        Cell::new(node_1_sync),
-    ]);
-
-    // Add identity pubkey (from current_identity)
-    let node_0_identity = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.current_identity.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-    let node_1_identity = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.current_identity.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-
-    table.add_row(vec![
-        Cell::new("Identity")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_identity),
-        Cell::new(node_1_identity),
-    ]);
-
-    // Add ledger path (detected from running process)
-    let node_0_ledger = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.ledger_path.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_ledger = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.ledger_path.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Ledger Path")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_ledger),
-        Cell::new(node_1_ledger),
-    ]);
-
-    // Add swap readiness
-    let node_0_swap = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
-    let node_1_swap = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
-
-    table.add_row(vec![
-        Cell::new("Swap Ready")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_swap),
-        Cell::new(node_1_swap),
-    ]);
-
-    // Add RPC voting status row as the last row
-    if let Some(vote_data) = vote_data {
-        let voting_status = if vote_data.is_voting {
-            "✅ Voting"
-        } else {
-            "⚠️ Not Voting"
-        };
-
-        let vote_info = if let Some(recent_vote) = vote_data.recent_votes.first() {
-            let current_slot = vote_data.vote_account_info.current_slot.unwrap_or(0);
-            let diff = current_slot.saturating_sub(recent_vote.slot);
-            format!("(-{})", diff)
-        } else {
-            "No recent votes".to_string()
-        };
-
-        table.add_row(vec![
-            Cell::new("Vote Status")
-                .add_attribute(Attribute::Bold)
-                .fg(Color::Cyan),
-            Cell::new(voting_status).fg(if vote_data.is_voting {
-                Color::Green
-            } else {
-                Color::Yellow
-            }),
-            Cell::new(&vote_info),
-        ]);
-    }
-
-    // Add alert status row
-    let alert_status = match &app_state.config.alert_config {
-        Some(alert_config) if alert_config.enabled => {
-            if alert_config.telegram.is_some() {
-                "✅ Telegram"
-            } else {
-                "⚠️ Enabled (no method)"
-            }
-        }
-        _ => "Disabled",
-    };
-
-    table.add_row(vec![
-        Cell::new("Alert Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(alert_status),
-        Cell::new(alert_status),
-    ]);
-
-    println!("{}", table);
-}
-
 #[allow(dead_code)]
 async fn check_comprehensive_status(
     pool: &mut crate::ssh::AsyncSshPool,
@@ -1051,21 +736,7 @@ fn display_status_table_from_app_state(app_state: &AppState) {
         }
         println!();
 
-        // Get the two nodes with their statuses
-        let nodes_with_status = &validator_status.nodes_with_status;
-        if nodes_with_status.len() >= 2 {
-            let node_0 = &nodes_with_status[0];
-            let node_1 = &nodes_with_status[1];
-
-            display_simple_status_table(
-                &node_0.node,
-                &node_0.status,
-                &node_1.node,
-                &node_1.status,
-                validator_status,
-                app_state,
-            );
-        }
+        display_simple_status_table(validator_status, None, app_state);
 
         println!();
     }
@@ -1073,13 +744,15 @@ fn display_status_table_from_app_state(app_state: &AppState) {
 
 #[allow(dead_code)]
 fn display_simple_status_table(
-    node_0: &crate::types::NodeConfig,
-    node_0_status: &crate::types::NodeStatus,
-    node_1: &crate::types::NodeConfig,
-    node_1_status: &crate::types::NodeStatus,
     validator_status: &crate::ValidatorStatus,
+    vote_data: Option<&ValidatorVoteData>,
     app_state: &AppState,
 ) {
+    let nodes = &validator_status.nodes_with_status;
+    if nodes.is_empty() {
+        return;
+    }
+
     let mut table = Table::new();
 
     // Create custom table style with minimal borders
@@ -1088,235 +761,197 @@ fn display_simple_status_table(
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_content_arrangement(ContentArrangement::Dynamic);
 
-    // Header row with dynamic labels
-    let node_0_label = match node_0_status {
-        crate::types::NodeStatus::Active => "ACTIVE",
-        crate::types::NodeStatus::Standby => "STANDBY",
-        crate::types::NodeStatus::Unknown => "UNKNOWN",
-    };
-    let node_1_label = match node_1_status {
-        crate::types::NodeStatus::Active => "ACTIVE",
-        crate::types::NodeStatus::Standby => "STANDBY",
-        crate::types::NodeStatus::Unknown => "UNKNOWN",
-    };
-
-    let node_0_color = if node_0_label == "ACTIVE" {
-        Color::Green
-    } else if node_0_label == "STANDBY" {
-        Color::Yellow
-    } else {
-        Color::DarkGrey
-    };
-    let node_1_color = if node_1_label == "ACTIVE" {
-        Color::Green
-    } else if node_1_label == "STANDBY" {
-        Color::Yellow
-    } else {
-        Color::DarkGrey
+    // One column per configured node, labelled on the left
+    let add_node_row = |table: &mut Table, label: &str, cells: Vec<Cell>| {
+        let mut row = vec![Cell::new(label)
+            .add_attribute(Attribute::Bold)
+            .fg(Color::Cyan)];
+        row.extend(cells);
+        table.add_row(row);
     };
 
     // Node info as header
-    let node_0_info = format!("🖥️ {} ({})", node_0.label, node_0.host);
-    let node_1_info = format!("🖥️ {} ({})", node_1.label, node_1.host);
-
-    table.add_row(vec![
-        Cell::new("Node")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(&node_0_info),
-        Cell::new(&node_1_info),
-    ]);
+    add_node_row(
+        &mut table,
+        "Node",
+        nodes
+            .iter()
+            .map(|n| Cell::new(format!("🖥️ {} ({})", n.node.label, n.node.host)))
+            .collect(),
+    );
 
     // Add separator line after subheader
-    table.add_row(vec![
-        Cell::new("─".repeat(15)).fg(Color::DarkGrey),
-        Cell::new("─".repeat(25)).fg(Color::DarkGrey),
-        Cell::new("─".repeat(25)).fg(Color::DarkGrey),
-    ]);
+    let mut separator = vec![Cell::new("─".repeat(15)).fg(Color::DarkGrey)];
+    separator.extend(
+        nodes
+            .iter()
+            .map(|_| Cell::new("─".repeat(25)).fg(Color::DarkGrey)),
+    );
+    table.add_row(separator);
 
     // Status rows with basic info
-    table.add_row(vec![
-        Cell::new("Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_label).fg(node_0_color),
-        Cell::new(node_1_label).fg(node_1_color),
-    ]);
+    add_node_row(
+        &mut table,
+        "Status",
+        nodes
+            .iter()
+            .map(|n| match n.status {
+                crate::types::NodeStatus::Active => Cell::new("ACTIVE").fg(Color::Green),
+                crate::types::NodeStatus::Standby => Cell::new("STANDBY").fg(Color::Yellow),
+                crate::types::NodeStatus::Unknown => Cell::new("UNKNOWN").fg(Color::DarkGrey),
+            })
+            .collect(),
+    );
 
     // Add executable paths
-    let node_0_agave = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.agave_validator_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_agave = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.agave_validator_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Agave Executable")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_agave),
-        Cell::new(node_1_agave),
-    ]);
-
-    // Add Solana CLI executable row
-    let node_0_solana = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.solana_cli_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_solana = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.solana_cli_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Solana CLI")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_solana),
-        Cell::new(node_1_solana),
-    ]);
+    add_node_row(
+        &mut table,
+        "Agave Executable",
+        nodes
+            .iter()
+            .map(|n| {
+                Cell::new(
+                    n.agave_validator_executable
+                        .as_ref()
+                        .map(|path| truncate_path(path, 30))
+                        .unwrap_or("N/A".to_string()),
+                )
+            })
+            .collect(),
+    );
 
-    let node_0_fdctl = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.fdctl_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_fdctl = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.fdctl_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
+    add_node_row(
+        &mut table,
+        "Solana CLI",
+        nodes
+            .iter()
+            .map(|n| {
+                Cell::new(
+                    n.solana_cli_executable
+                        .as_ref()
+                        .map(|path| truncate_path(path, 30))
+                        .unwrap_or("N/A".to_string()),
+                )
+            })
+            .collect(),
+    );
 
-    table.add_row(vec![
-        Cell::new("Fdctl Executable")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_fdctl),
-        Cell::new(node_1_fdctl),
-    ]);
+    add_node_row(
+        &mut table,
+        "Fdctl Executable",
+        nodes
+            .iter()
+            .map(|n| {
+                Cell::new(
+                    n.fdctl_executable
+                        .as_ref()
+                        .map(|path| truncate_path(path, 30))
+                        .unwrap_or("N/A".to_string()),
+                )
+            })
+            .collect(),
+    );
 
     // Add version information
-    let node_0_version = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.version.as_ref())
-        .cloned()
-        .unwrap_or("N/A".to_string());
-    let node_1_version = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.version.as_ref())
-        .cloned()
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Version")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_version),
-        Cell::new(node_1_version),
-    ]);
+    add_node_row(
+        &mut table,
+        "Version",
+        nodes
+            .iter()
+            .map(|n| Cell::new(n.version.as_deref().unwrap_or("N/A")))
+            .collect(),
+    );
 
     // Add sync status
-    let node_0_sync = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.sync_status.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-    let node_1_sync = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.sync_status.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-
-    table.add_row(vec![
-        Cell::new("Sync Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_sync),
-        Cell::new(node_1_sync),
-    ]);
+    add_node_row(
+        &mut table,
+        "Sync Status",
+        nodes
+            .iter()
+            .map(|n| Cell::new(n.sync_status.as_deref().unwrap_or("Unknown")))
+            .collect(),
+    );
 
     // Add identity pubkey (from current_identity)
-    let node_0_identity = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.current_identity.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-    let node_1_identity = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.current_identity.as_ref())
-        .cloned()
-        .unwrap_or("Unknown".to_string());
-
-    table.add_row(vec![
-        Cell::new("Identity")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_identity),
-        Cell::new(node_1_identity),
-    ]);
+    add_node_row(
+        &mut table,
+        "Identity",
+        nodes
+            .iter()
+            .map(|n| Cell::new(n.current_identity.as_deref().unwrap_or("Unknown")))
+            .collect(),
+    );
 
     // Add ledger path (detected from running process)
-    let node_0_ledger = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.ledger_path.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_ledger = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.ledger_path.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Ledger Path")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_ledger),
-        Cell::new(node_1_ledger),
-    ]);
+    add_node_row(
+        &mut table,
+        "Ledger Path",
+        nodes
+            .iter()
+            .map(|n| {
+                Cell::new(
+                    n.ledger_path
+                        .as_ref()
+                        .map(|path| truncate_path(path, 30))
+                        .unwrap_or("N/A".to_string()),
+                )
+            })
+            .collect(),
+    );
 
     // Add swap readiness
-    let node_0_swap = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
-    let node_1_swap = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
+    add_node_row(
+        &mut table,
+        "Swap Ready",
+        nodes
+            .iter()
+            .map(|n| {
+                Cell::new(
+                    n.swap_ready
+                        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
+                        .unwrap_or("❓ Unknown"),
+                )
+            })
+            .collect(),
+    );
 
-    table.add_row(vec![
-        Cell::new("Swap Ready")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_swap),
-        Cell::new(node_1_swap),
-    ]);
+    // Add RPC voting status row, shown under whichever node is active
+    if let Some(vote_data) = vote_data {
+        let voting_status = if vote_data.is_voting {
+            "✅ Voting"
+        } else {
+            "⚠️ Not Voting"
+        };
+
+        let vote_info = if let Some(recent_vote) = vote_data.recent_votes.first() {
+            let current_slot = vote_data.vote_account_info.current_slot.unwrap_or(0);
+            let diff = current_slot.saturating_sub(recent_vote.slot);
+            format!("(-{})", diff)
+        } else {
+            "No recent votes".to_string()
+        };
+
+        add_node_row(
+            &mut table,
+            "Vote Status",
+            nodes
+                .iter()
+                .map(|n| {
+                    if n.status == crate::types::NodeStatus::Active {
+                        Cell::new(format!("{} {}", voting_status, vote_info)).fg(
+                            if vote_data.is_voting {
+                                Color::Green
+                            } else {
+                                Color::Yellow
+                            },
+                        )
+                    } else {
+                        Cell::new("-")
+                    }
+                })
+                .collect(),
+        );
+    }
 
     // Add alert status row
     let alert_status = match &app_state.config.alert_config {
@@ -1330,13 +965,11 @@ fn display_simple_status_table(
         _ => "Disabled",
     };
 
-    table.add_row(vec![
-        Cell::new("Alert Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(alert_status),
-        Cell::new(alert_status),
-    ]);
+    add_node_row(
+        &mut table,
+        "Alert Status",
+        nodes.iter().map(|_| Cell::new(alert_status)).collect(),
+    );
 
     println!("{}", table);
 }
@@ -1360,45 +993,25 @@ fn display_status_table(
         println!();
 
         // Get node statuses (no ordering, just display as configured)
-        let node_0_status = results
-            .get(&format!("validator_{}_node_0 (ACTIVE)", index))
-            .or_else(|| results.get(&format!("validator_{}_node_0 (STANDBY)", index)))
-            .or_else(|| results.get(&format!("validator_{}_node_0 (UNKNOWN)", index)));
-        let node_1_status = results
-            .get(&format!("validator_{}_node_1 (ACTIVE)", index))
-            .or_else(|| results.get(&format!("validator_{}_node_1 (STANDBY)", index)))
-            .or_else(|| results.get(&format!("validator_{}_node_1 (UNKNOWN)", index)));
-
-        // Determine status labels for each node
-        let node_0_label = if results.contains_key(&format!("validator_{}_node_0 (ACTIVE)", index))
-        {
-            "ACTIVE"
-        } else if results.contains_key(&format!("validator_{}_node_0 (STANDBY)", index)) {
-            "STANDBY"
-        } else {
-            "UNKNOWN"
-        };
-
-        let node_1_label = if results.contains_key(&format!("validator_{}_node_1 (ACTIVE)", index))
-        {
-            "ACTIVE"
-        } else if results.contains_key(&format!("validator_{}_node_1 (STANDBY)", index)) {
-            "STANDBY"
-        } else {
-            "UNKNOWN"
-        };
+        let node_statuses: Vec<_> = validator_pair
+            .nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(node_index, node)| {
+                ["ACTIVE", "STANDBY", "UNKNOWN"].iter().find_map(|label| {
+                    results
+                        .get(&format!(
+                            "validator_{}_node_{} ({})",
+                            index, node_index, label
+                        ))
+                        .map(|status| (node, *label, status))
+                })
+            })
+            .collect();
 
-        if let (Some(node_0_status), Some(node_1_status)) = (node_0_status, node_1_status) {
+        if !node_statuses.is_empty() {
             let validator_status = &app_state.validator_statuses[index];
-            display_primary_backup_table(
-                Some(&validator_pair.nodes[0]),
-                node_0_status,
-                Some(&validator_pair.nodes[1]),
-                node_1_status,
-                node_0_label,
-                node_1_label,
-                validator_status,
-            );
+            display_validator_nodes_table(&node_statuses, validator_status);
         }
 
         println!();
@@ -1463,14 +1076,12 @@ fn display_node_status(role: &str, node: &NodeConfig, status: &ComprehensiveStat
     }
 }
 
+/// A node's config, its role label and its comprehensive status
+type NodeStatusColumn<'a> = (&'a NodeConfig, &'a str, &'a ComprehensiveStatus);
+
 #[allow(dead_code)]
-fn display_primary_backup_table(
-    node_0: Option<&NodeConfig>,
-    node_0_status: &ComprehensiveStatus,
-    node_1: Option<&NodeConfig>,
-    node_1_status: &ComprehensiveStatus,
-    node_0_label: &str,
-    node_1_label: &str,
+fn display_validator_nodes_table(
+    node_statuses: &[NodeStatusColumn],
     validator_status: &crate::ValidatorStatus,
 ) {
     let mut table = Table::new();
@@ -1481,219 +1092,142 @@ fn display_primary_backup_table(
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_content_arrangement(ContentArrangement::Dynamic);
 
-    // Header row with dynamic labels
-    let node_0_color = if node_0_label == "ACTIVE" {
-        Color::Green
-    } else if node_0_label == "STANDBY" {
-        Color::Yellow
-    } else {
-        Color::DarkGrey
-    };
-    let node_1_color = if node_1_label == "ACTIVE" {
-        Color::Green
-    } else if node_1_label == "STANDBY" {
-        Color::Yellow
-    } else {
-        Color::DarkGrey
+    let label_color = |label: &str| match label {
+        "ACTIVE" => Color::Green,
+        "STANDBY" => Color::Yellow,
+        _ => Color::DarkGrey,
     };
 
-    table.add_row(vec![
-        Cell::new("").add_attribute(Attribute::Bold),
-        Cell::new(node_0_label)
+    // One column per node, labelled on the left
+    let add_node_row = |table: &mut Table, label: &str, cells: Vec<Cell>| {
+        let mut row = vec![Cell::new(label)
             .add_attribute(Attribute::Bold)
-            .fg(node_0_color),
-        Cell::new(node_1_label)
+            .fg(Color::Cyan)];
+        row.extend(cells);
+        table.add_row(row);
+    };
+
+    // Header row with dynamic labels
+    let mut header = vec![Cell::new("").add_attribute(Attribute::Bold)];
+    header.extend(node_statuses.iter().map(|(_, label, _)| {
+        Cell::new(label)
             .add_attribute(Attribute::Bold)
-            .fg(node_1_color),
-    ]);
+            .fg(label_color(label))
+    }));
+    table.add_row(header);
 
     // Node info as subheader
-    let node_0_info = node_0
-        .map(|n| format!("🖥️ {} ({})", n.label, n.host))
-        .unwrap_or("🖥️ Node 0".to_string());
-    let node_1_info = node_1
-        .map(|n| format!("🖥️ {} ({})", n.label, n.host))
-        .unwrap_or("🖥️ Node 1".to_string());
-
-    table.add_row(vec![
-        Cell::new("Node")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(&node_0_info).fg(Color::Green),
-        Cell::new(&node_1_info).fg(Color::Yellow),
-    ]);
+    add_node_row(
+        &mut table,
+        "Node",
+        node_statuses
+            .iter()
+            .map(|(node, label, _)| {
+                Cell::new(format!("🖥️ {} ({})", node.label, node.host)).fg(label_color(label))
+            })
+            .collect(),
+    );
 
     // Add separator line after subheader
-    table.add_row(vec![
-        Cell::new("─".repeat(15)).fg(Color::DarkGrey),
-        Cell::new("─".repeat(25)).fg(Color::DarkGrey),
-        Cell::new("─".repeat(25)).fg(Color::DarkGrey),
-    ]);
+    let mut separator = vec![Cell::new("─".repeat(15)).fg(Color::DarkGrey)];
+    separator.extend(
+        node_statuses
+            .iter()
+            .map(|_| Cell::new("─".repeat(25)).fg(Color::DarkGrey)),
+    );
+    table.add_row(separator);
 
     // Status rows with labels on the left
-    table.add_row(vec![
-        Cell::new("Connection")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_connection_status_plain(node_0_status)),
-        Cell::new(format_connection_status_plain(node_1_status)),
-    ]);
-
-    table.add_row(vec![
-        Cell::new("Process")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_process_status_plain(node_0_status)),
-        Cell::new(format_process_status_plain(node_1_status)),
-    ]);
-
-    table.add_row(vec![
-        Cell::new("Disk Usage")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_disk_usage_plain(node_0_status)),
-        Cell::new(format_disk_usage_plain(node_1_status)),
-    ]);
-
-    table.add_row(vec![
-        Cell::new("Identity")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_identity_status(&node_0_status.current_identity)),
-        Cell::new(format_identity_status(&node_1_status.current_identity)),
-    ]);
-
-    table.add_row(vec![
-        Cell::new("System Load")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_system_load_plain(node_0_status)),
-        Cell::new(format_system_load_plain(node_1_status)),
-    ]);
-
-    table.add_row(vec![
-        Cell::new("Sync Status")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_sync_status_plain(node_0_status)),
-        Cell::new(format_sync_status_plain(node_1_status)),
-    ]);
-
-    table.add_row(vec![
-        Cell::new("Version")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_version_plain(node_0_status)),
-        Cell::new(format_version_plain(node_1_status)),
-    ]);
+    type StatusFormat = fn(&ComprehensiveStatus) -> String;
+    let status_rows: [(&str, StatusFormat); 7] = [
+        ("Connection", format_connection_status_plain),
+        ("Process", format_process_status_plain),
+        ("Disk Usage", format_disk_usage_plain),
+        ("Identity", |status| {
+            format_identity_status(&status.current_identity)
+        }),
+        ("System Load", format_system_load_plain),
+        ("Sync Status", format_sync_status_plain),
+        ("Version", format_version_plain),
+    ];
+    for (label, format) in status_rows {
+        add_node_row(
+            &mut table,
+            label,
+            node_statuses
+                .iter()
+                .map(|(_, _, status)| Cell::new(format(status)))
+                .collect(),
+        );
+    }
 
     // Add executable paths
-    let node_0_agave = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.agave_validator_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_agave = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.agave_validator_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Agave Executable")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_agave),
-        Cell::new(node_1_agave),
-    ]);
-
-    // Add Solana CLI executable row
-    let node_0_solana = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.solana_cli_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_solana = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.solana_cli_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Solana CLI")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_solana),
-        Cell::new(node_1_solana),
-    ]);
-
-    let node_0_fdctl = validator_status
-        .nodes_with_status
-        .get(0)
-        .and_then(|n| n.fdctl_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-    let node_1_fdctl = validator_status
-        .nodes_with_status
-        .get(1)
-        .and_then(|n| n.fdctl_executable.as_ref())
-        .map(|path| truncate_path(path, 30))
-        .unwrap_or("N/A".to_string());
-
-    table.add_row(vec![
-        Cell::new("Fdctl Executable")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(node_0_fdctl),
-        Cell::new(node_1_fdctl),
-    ]);
+    let node_with_status = |node: &NodeConfig| {
+        validator_status
+            .nodes_with_status
+            .iter()
+            .find(|n| n.node.label == node.label && n.node.host == node.host)
+    };
+    type NodeExecutable = fn(&crate::types::NodeWithStatus) -> Option<&String>;
+    let executable_rows: [(&str, NodeExecutable); 3] = [
+        ("Agave Executable", |n| {
+            n.agave_validator_executable.as_ref()
+        }),
+        ("Solana CLI", |n| n.solana_cli_executable.as_ref()),
+        ("Fdctl Executable", |n| n.fdctl_executable.as_ref()),
+    ];
+    for (label, executable) in executable_rows {
+        add_node_row(
+            &mut table,
+            label,
+            node_statuses
+                .iter()
+                .map(|(node, _, _)| {
+                    Cell::new(
+                        node_with_status(node)
+                            .and_then(executable)
+                            .map(|path| truncate_path(path, 30))
+                            .unwrap_or("N/A".to_string()),
+                    )
+                })
+                .collect(),
+        );
+    }
 
-    table.add_row(vec![
-        Cell::new("Swap Ready")
-            .add_attribute(Attribute::Bold)
-            .fg(Color::Cyan),
-        Cell::new(format_swap_readiness_plain(node_0_status)),
-        Cell::new(format_swap_readiness_plain(node_1_status)),
-    ]);
+    add_node_row(
+        &mut table,
+        "Swap Ready",
+        node_statuses
+            .iter()
+            .map(|(_, _, status)| Cell::new(format_swap_readiness_plain(status)))
+            .collect(),
+    );
 
     // Add swap checklist as sub-rows
-    let node_0_checklist = format_swap_checklist(node_0_status);
-    let node_1_checklist = format_swap_checklist(node_1_status);
-
-    if !node_0_checklist.is_empty() || !node_1_checklist.is_empty() {
-        let max_lines = node_0_checklist.len().max(node_1_checklist.len());
-        for i in 0..max_lines {
-            let node_0_item = node_0_checklist.get(i).cloned().unwrap_or_default();
-            let node_1_item = node_1_checklist.get(i).cloned().unwrap_or_default();
-
-            let left_label = if i == 0 { "  └ Checklist" } else { "" };
-
-            table.add_row(vec![
-                Cell::new(left_label).fg(Color::DarkGrey),
-                Cell::new(node_0_item).fg(Color::DarkGrey),
-                Cell::new(node_1_item).fg(Color::DarkGrey),
-            ]);
-        }
+    let checklists: Vec<_> = node_statuses
+        .iter()
+        .map(|(_, _, status)| format_swap_checklist(status))
+        .collect();
+    let max_lines = checklists.iter().map(|c| c.len()).max().unwrap_or(0);
+    for i in 0..max_lines {
+        let left_label = if i == 0 { "  └ Checklist" } else { "" };
+
+        let mut row = vec![Cell::new(left_label).fg(Color::DarkGrey)];
+        row.extend(checklists.iter().map(|checklist| {
+            Cell::new(checklist.get(i).cloned().unwrap_or_default()).fg(Color::DarkGrey)
+        }));
+        table.add_row(row);
     }
 
     println!("{}", table);
 
     // Show verification issues if any
-    if !node_0_status.verification_issues.is_empty() {
-        println!("\n{} Node 0 Verification Issues:", "⚠️".yellow());
-        for issue in &node_0_status.verification_issues {
-            println!("  • {}", issue.yellow());
-        }
-    }
-
-    if !node_1_status.verification_issues.is_empty() {
-        println!("\n{} Node 1 Verification Issues:", "⚠️".yellow());
-        for issue in &node_1_status.verification_issues {
-            println!("  • {}", issue.yellow());
+    for (node, _, status) in node_statuses {
+        if !status.verification_issues.is_empty() {
+            println!("\n{} {} Verification Issues:", "⚠️".yellow(), node.label);
+            for issue in &status.verification_issues {
+                println!("  • {}", issue.yellow());
+            }
         }
     }
 }
@@ -2151,17 +1685,23 @@ async fn display_vote_status_row_only(app_state: &AppState) -> Result<()> {
                 "No recent votes".to_string()
             };
 
-            // Print the updated row
-            print!(
-                "│ {:14} │ {:24} │ {:24} │",
-                "Vote Status".cyan().bold(),
-                if vote_data.is_voting {
-                    voting_status.green()
+            // Print the updated row, one cell per node like the status table
+            let voting_display = format!("{} {}", voting_status, vote_info);
+            print!("│ {:14} │", "Vote Status".cyan().bold());
+            for node in &validator_status.nodes_with_status {
+                if node.status == crate::types::NodeStatus::Active {
+                    print!(
+                        " {:24} │",
+                        if vote_data.is_voting {
+                            voting_display.green()
+                        } else {
+                            voting_display.yellow()
+                        }
+                    );
                 } else {
-                    voting_status.yellow()
-                },
-                vote_info
-            );
+                    print!(" {:24} │", "-");
+                }
+            }
         }
     }
 
@@ -56,18 +56,34 @@ use crate::alert::ComprehensiveAlertTracker;
 use std::sync::{Mutex, OnceLock};
 
 static ALERT_TRACKER: OnceLock<Mutex<ComprehensiveAlertTracker>> = OnceLock::new();
+use crate::failover_budget::{FailoverBudget, FailoverDecision};
 use crate::solana_rpc::{fetch_vote_account_data, ValidatorVoteData};
+use crate::switch_schedule::{ScheduledSwitch, SwitchSchedule};
 use crate::types::{FailureTracker, NodeHealthStatus};
 use crate::{ssh::AsyncSshPool, AppState};
 
+/// Largest node count across all validator pairs; sizes the per-node alert trackers
+fn max_nodes_per_validator(validator_statuses: &[crate::ValidatorStatus]) -> usize {
+    validator_statuses
+        .iter()
+        .map(|vs| vs.nodes_with_status.len())
+        .max()
+        .unwrap_or(0)
+}
+
 /// Refresh vote data for all validators and send alerts
 async fn refresh_vote_data_for_alerts(
     app_state: Arc<AppState>,
     ui_state: Arc<RwLock<UiState>>,
     log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
     alert_manager: Option<AlertManager>,
+    emergency_takeover_flag: Arc<RwLock<bool>>,
 ) {
     let mut new_vote_data = Vec::new();
+    // Per validator, per endpoint: last vote slot seen, or the fetch error
+    let mut endpoint_results: Vec<Vec<std::result::Result<Option<u64>, String>>> = Vec::new();
+    // Per validator: most advanced finalized slot and that endpoint's delinquent stake share
+    let mut cluster_samples: Vec<Option<(u64, f64)>> = Vec::new();
 
     // Fetch vote data for all validators
     for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
@@ -84,7 +100,69 @@ async fn refresh_vote_data_for_alerts(
                 validator_status.nodes_with_status[0].node.label.clone()
             };
 
-        match fetch_vote_account_data(&validator_pair.rpc, &validator_pair.vote_pubkey).await {
+        // Poll every configured endpoint so one bad or lagging RPC cannot decide
+        // delinquency on its own. The display uses the most advanced view.
+        // The RPC client blocks, so each endpoint gets its own task.
+        let endpoints = crate::vote_quorum::rpc_endpoints(validator_pair);
+        let fetches = futures::future::join_all(endpoints.iter().map(|rpc| {
+            let rpc = rpc.clone();
+            let vote_pubkey = validator_pair.vote_pubkey.clone();
+            tokio::spawn(async move { fetch_vote_account_data(&rpc, &vote_pubkey).await })
+        }))
+        .await
+        .into_iter()
+        .map(|joined| joined.unwrap_or_else(|e| Err(anyhow::anyhow!("Vote fetch task failed: {}", e))));
+
+        let mut best: Option<ValidatorVoteData> = None;
+        let mut errors = Vec::new();
+        let mut results = Vec::new();
+        let mut cluster_sample: Option<(u64, f64)> = None;
+        for (rpc, fetch) in endpoints.iter().zip(fetches) {
+            match fetch {
+                Ok(data) => {
+                    let last_slot = data.recent_votes.last().map(|v| v.slot);
+                    results.push(Ok(last_slot));
+                    if let Some(root) = data.vote_account_info.current_slot {
+                        if cluster_sample.is_none_or(|(best_root, _)| root > best_root) {
+                            cluster_sample = Some((root, data.cluster_delinquent_stake_fraction));
+                        }
+                    }
+                    let best_slot = best
+                        .as_ref()
+                        .and_then(|b| b.recent_votes.last().map(|v| v.slot));
+                    if best.is_none() || last_slot > best_slot {
+                        best = Some(data);
+                    }
+                }
+                Err(e) => {
+                    results.push(Err(e.to_string()));
+                    errors.push(if endpoints.len() > 1 {
+                        format!("{}: {}", rpc, e)
+                    } else {
+                        e.to_string()
+                    });
+                }
+            }
+        }
+        endpoint_results.push(results);
+        cluster_samples.push(cluster_sample);
+
+        if best.is_some() && !errors.is_empty() {
+            let _ = log_sender.send(LogMessage {
+                host: validator_log_host(&app_state, idx),
+                message: format!(
+                    "[{}] {}/{} vote RPC endpoints failed: {}",
+                    node_label,
+                    errors.len(),
+                    endpoints.len(),
+                    errors.join("; ")
+                ),
+                timestamp: Instant::now(),
+                level: LogLevel::Warning,
+            });
+        }
+
+        match best.ok_or_else(|| errors.join("; ")) {
             Ok(data) => {
                 // Update RPC success. We intentionally do NOT clear
                 // last_vote_rpc_failure_times here: a successful fetch of the
@@ -108,9 +186,7 @@ async fn refresh_vote_data_for_alerts(
 
                 new_vote_data.push(Some(data));
             }
-            Err(e) => {
-                let error_message = e.to_string();
-
+            Err(error_message) => {
                 // Update vote-account RPC failure tracking and possibly send
                 // low-priority alert. This path means we cannot currently
                 // establish fresh on-chain vote status; it must not become a
@@ -137,7 +213,7 @@ async fn refresh_vote_data_for_alerts(
                                 let tracker_mutex = ALERT_TRACKER.get_or_init(|| {
                                     Mutex::new(ComprehensiveAlertTracker::new(
                                         app_state.validator_statuses.len(),
-                                        2,
+                                        max_nodes_per_validator(&app_state.validator_statuses),
                                     ))
                                 });
                                 let mut tracker = tracker_mutex.lock().unwrap();
@@ -207,6 +283,7 @@ async fn refresh_vote_data_for_alerts(
             // entire `app_state_health` Arc (which would move it on the first
             // iteration and break subsequent iterations).
             let validator_count = app_state_health.validator_statuses.len();
+            let node_count = max_nodes_per_validator(&app_state_health.validator_statuses);
             let validator_identity = validator_status.validator_pair.identity_pubkey.clone();
 
             for (nidx, node_with_status) in validator_status.nodes_with_status.iter().enumerate() {
@@ -236,12 +313,13 @@ async fn refresh_vote_data_for_alerts(
 
                     if let Some(ssh_key) = ssh_key_opt {
                         let rpc_port = crate::validator_rpc::get_rpc_port(validator_type, None);
-                        match crate::validator_rpc::get_health(&*ssh_pool, &node, &ssh_key, rpc_port).await {
+                        match crate::validator_rpc::get_health(&ssh_pool, &node, &ssh_key, rpc_port)
+                            .await
+                        {
                             Ok(is_healthy) => {
                                 // Update UI state rpc health
                                 if let Ok(mut st) = ui_state_local.try_write() {
-                                    if let Some(pair) = st.rpc_health_data.get_mut(vidx) {
-                                        let rpc_status = if nidx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                    if let Some(rpc_status) = st.rpc_health_data.get_mut(vidx).and_then(|pair| pair.nodes.get_mut(nidx)) {
                                         rpc_status.is_healthy = is_healthy;
                                         rpc_status.last_check = Some(Instant::now());
                                         rpc_status.error_message = None;
@@ -261,7 +339,7 @@ async fn refresh_vote_data_for_alerts(
                                 if !is_healthy && node_status == crate::types::NodeStatus::Standby {
                                     if let Some(am) = alert_mgr.as_ref() {
                                         let tracker_mutex = ALERT_TRACKER.get_or_init(|| {
-                                            Mutex::new(ComprehensiveAlertTracker::new(validator_count, 2))
+                                            Mutex::new(ComprehensiveAlertTracker::new(validator_count, node_count))
                                         });
                                         let (decision, remaining_seconds) = {
                                             let mut tracker = tracker_mutex.lock().unwrap();
@@ -324,8 +402,7 @@ async fn refresh_vote_data_for_alerts(
                                 let failure_start = {
                                     let mut start = None;
                                     if let Ok(mut st) = ui_state_local.try_write() {
-                                        if let Some(pair) = st.rpc_health_data.get_mut(vidx) {
-                                            let rpc_status = if nidx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                        if let Some(rpc_status) = st.rpc_health_data.get_mut(vidx).and_then(|pair| pair.nodes.get_mut(nidx)) {
                                             rpc_status.is_healthy = false;
                                             rpc_status.last_check = Some(Instant::now());
                                             rpc_status.error_message = Some(error_text.clone());
@@ -349,7 +426,7 @@ async fn refresh_vote_data_for_alerts(
 
                                     if let Some(am) = alert_mgr.as_ref() {
                                         let tracker_mutex = ALERT_TRACKER.get_or_init(|| {
-                                            Mutex::new(ComprehensiveAlertTracker::new(validator_count, 2))
+                                            Mutex::new(ComprehensiveAlertTracker::new(validator_count, node_count))
                                         });
                                         let (decision, remaining_rpc) = {
                                             let mut tracker = tracker_mutex.lock().unwrap();
@@ -423,6 +500,23 @@ async fn refresh_vote_data_for_alerts(
 
     // Update UI state and check for delinquency alerts
     if let Ok(mut state) = ui_state.try_write() {
+        // Record what each endpoint reported for the quorum check
+        let now = Instant::now();
+        for (quorum, results) in state.vote_quorums.iter_mut().zip(&endpoint_results) {
+            for (view, result) in quorum.views.iter_mut().zip(results) {
+                match result {
+                    Ok(Some(slot)) => view.record_vote(*slot, now),
+                    Ok(None) => {}
+                    Err(e) => view.record_failure(e.clone()),
+                }
+            }
+        }
+        for (health, sample) in state.cluster_health.iter_mut().zip(&cluster_samples) {
+            if let Some((root_slot, delinquent_stake_fraction)) = sample {
+                health.record(*root_slot, *delinquent_stake_fraction, now);
+            }
+        }
+
         // Update vote data
         let mut new_slot_times = Vec::new();
         let mut new_increments = Vec::new();
@@ -494,7 +588,7 @@ async fn refresh_vote_data_for_alerts(
             let _ = ALERT_TRACKER.get_or_init(|| {
                 Mutex::new(ComprehensiveAlertTracker::new(
                     app_state.validator_statuses.len(),
-                    2,
+                    max_nodes_per_validator(&app_state.validator_statuses),
                 ))
             });
 
@@ -502,6 +596,51 @@ async fn refresh_vote_data_for_alerts(
             let tracker_mutex = ALERT_TRACKER.get().unwrap();
             let mut tracker = tracker_mutex.lock().unwrap();
 
+            // Report each cluster-wide stall once; it also suppresses delinquency below
+            for idx in 0..state.cluster_health.len() {
+                let stall_reason = state.cluster_health[idx].stall_reason();
+                let health = &mut state.cluster_health[idx];
+                match stall_reason {
+                    Some(reason) if !health.stall_notified => {
+                        health.stall_notified = true;
+                        let _ = log_sender.send(LogMessage {
+                            host: validator_log_host(&app_state, idx),
+                            message: format!(
+                                "Cluster-wide stall detected ({}): delinquency alerts and auto-failover suspended",
+                                reason
+                            ),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Warning,
+                        });
+
+                        let alert_mgr = alert_mgr.clone();
+                        let log_sender = log_sender.clone();
+                        let identity = app_state.validator_statuses[idx].validator_pair.identity_pubkey.clone();
+                        let host = validator_log_host(&app_state, idx);
+                        tokio::spawn(async move {
+                            if let Err(e) = alert_mgr.send_cluster_stall_alert(&identity, &reason).await {
+                                let _ = log_sender.send(LogMessage {
+                                    host,
+                                    message: format!("Failed to send cluster stall alert: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Error,
+                                });
+                            }
+                        });
+                    }
+                    None if health.stall_notified => {
+                        health.stall_notified = false;
+                        let _ = log_sender.send(LogMessage {
+                            host: validator_log_host(&app_state, idx),
+                            message: "Cluster is making progress again: delinquency alerts and auto-failover resumed".to_string(),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Info,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
             // Collect alerts to send without holding locks while awaiting network calls
             let mut alerts_to_send: Vec<(usize, bool, crate::types::NodeConfig, u64, u64, NodeHealthStatus, bool)> = Vec::new();
 
@@ -565,6 +704,35 @@ async fn refresh_vote_data_for_alerts(
                             continue;
                         }
 
+                        // Our validator "stops voting" along with everyone else when the
+                        // cluster halts; switching nodes would not help
+                        if let Some(reason) = state.cluster_health[idx].stall_reason() {
+                            let _ = log_sender.send(LogMessage {
+                                host: validator_log_host(&app_state, idx),
+                                message: format!(
+                                    "Delinquency alert and auto-failover suppressed: cluster-wide stall ({})",
+                                    reason
+                                ),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                            continue;
+                        }
+
+                        let quorum = &state.vote_quorums[idx];
+                        if !quorum.agrees_delinquent(threshold, Instant::now()) {
+                            let _ = log_sender.send(LogMessage {
+                                host: validator_log_host(&app_state, idx),
+                                message: format!(
+                                    "Delinquency not declared: only {}",
+                                    quorum.describe(threshold, Instant::now())
+                                ),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                            continue;
+                        }
+
                         if should_send_high_priority_delinquency_alert(
                             vote_rpc_failures,
                             seconds_since_vote,
@@ -621,7 +789,58 @@ async fn refresh_vote_data_for_alerts(
             // Release tracker lock before awaiting network calls
             drop(tracker);
 
+            let auto_failover_enabled = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .is_some_and(|c| c.auto_failover_enabled);
+
             for (idx, is_backup, active_node, last_slot, seconds_since_vote, node_health, is_active) in alerts_to_send {
+                // Delinquency was confirmed by the RPC quorum above; take over with
+                // the best standby unless a takeover is already running
+                if !is_backup && auto_failover_enabled {
+                    let takeover_running = emergency_takeover_flag
+                        .try_read()
+                        .map(|flag| *flag)
+                        .unwrap_or(true);
+                    let identity = &app_state.validator_statuses[idx].validator_pair.identity_pubkey;
+                    let may_take_over = !takeover_running
+                        && check_failover_budget(
+                            &app_state,
+                            idx,
+                            identity,
+                            &alert_mgr,
+                            &log_sender,
+                        );
+                    if may_take_over {
+                        let _ = log_sender.send(LogMessage {
+                            host: validator_log_host(&app_state, idx),
+                            message: format!(
+                                "AUTO-FAILOVER: {}s without vote confirmed by {} of {} RPC endpoints - starting emergency takeover",
+                                seconds_since_vote,
+                                state.vote_quorums[idx].quorum,
+                                state.vote_quorums[idx].endpoints.len()
+                            ),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Error,
+                        });
+                        let validator_status = state
+                            .validator_statuses
+                            .get(idx)
+                            .cloned()
+                            .unwrap_or_else(|| app_state.validator_statuses[idx].clone());
+                        tokio::spawn(execute_emergency_failover(
+                            validator_status,
+                            alert_mgr.clone(),
+                            app_state.ssh_pool.clone(),
+                            app_state.detected_ssh_keys.clone(),
+                            app_state.config.switch_config.clone().unwrap_or_default(),
+                            app_state.config.alert_config.clone(),
+                            emergency_takeover_flag.clone(),
+                        ));
+                    }
+                }
+
                 let alert_mgr = alert_mgr.clone();
                 let log_sender = log_sender.clone();
                 let identity = app_state.validator_statuses[idx].validator_pair.identity_pubkey.clone();
@@ -682,11 +901,93 @@ async fn refresh_vote_data_for_alerts(
     }
 }
 
+/// Check the persisted auto-failover budget before an automatic takeover. The
+/// takeover only counts against the budget once it has promoted a standby (see
+/// `execute_emergency_failover`). Once the budget is used up, auto-failover is
+/// locked out for the validator and the operator is paged.
+fn check_failover_budget(
+    app_state: &AppState,
+    idx: usize,
+    identity: &str,
+    alert_manager: &AlertManager,
+    log_sender: &tokio::sync::mpsc::UnboundedSender<LogMessage>,
+) -> bool {
+    let Some(alert_config) = app_state.config.alert_config.as_ref() else {
+        return false;
+    };
+    let log = |message: String, level: LogLevel| {
+        let _ = log_sender.send(LogMessage {
+            host: validator_log_host(app_state, idx),
+            message,
+            timestamp: Instant::now(),
+            level,
+        });
+    };
+
+    let mut budget = match FailoverBudget::load() {
+        Ok(budget) => budget,
+        Err(e) => {
+            log(format!("AUTO-FAILOVER skipped: {}", e), LogLevel::Error);
+            return false;
+        }
+    };
+
+    let now = chrono::Utc::now();
+    match budget.check(identity, alert_config, now) {
+        FailoverDecision::Allowed => true,
+        FailoverDecision::BudgetExhausted {
+            takeovers_in_window,
+        } => {
+            budget.lock_out(identity, now);
+            if let Err(e) = budget.save() {
+                log(
+                    format!("Failed to persist auto-failover lockout: {}", e),
+                    LogLevel::Error,
+                );
+            }
+            log(
+                format!(
+                    "AUTO-FAILOVER LOCKED OUT: {} - run `svs failover reset` to re-enable",
+                    FailoverDecision::BudgetExhausted {
+                        takeovers_in_window
+                    }
+                ),
+                LogLevel::Error,
+            );
+
+            let alert_manager = alert_manager.clone();
+            let identity = identity.to_string();
+            let window_seconds = alert_config.auto_failover_window_seconds;
+            let log_sender = log_sender.clone();
+            let host = validator_log_host(app_state, idx);
+            tokio::spawn(async move {
+                if let Err(e) = alert_manager
+                    .send_failover_lockout_alert(&identity, takeovers_in_window, window_seconds)
+                    .await
+                {
+                    let _ = log_sender.send(LogMessage {
+                        host,
+                        message: format!("Failed to send auto-failover lockout alert: {}", e),
+                        timestamp: Instant::now(),
+                        level: LogLevel::Error,
+                    });
+                }
+            });
+            false
+        }
+        decision => {
+            log(format!("AUTO-FAILOVER skipped: {}", decision), LogLevel::Warning);
+            false
+        }
+    }
+}
+
 /// View states for the UI
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewState {
     Status,
     Switch,
+    Demote,
 }
 
 /// UI Actions that can be triggered by keypresses
@@ -698,13 +999,17 @@ enum UiAction {
     ConfirmSwitch,
     CancelSwitch,
     NextValidator,
+    PreviousSwitchTarget,
+    NextSwitchTarget,
+    ShowDemote,
+    ConfirmDemote,
 }
 
 /// Convert keyboard event to UI action without any async operations
 fn key_to_action(key: KeyEvent, current_view: &ViewState) -> Option<UiAction> {
     match key.code {
         KeyCode::Char('q') | KeyCode::Esc => {
-            if *current_view == ViewState::Switch {
+            if *current_view != ViewState::Status {
                 Some(UiAction::CancelSwitch)
             } else {
                 Some(UiAction::Quit)
@@ -718,9 +1023,14 @@ fn key_to_action(key: KeyEvent, current_view: &ViewState) -> Option<UiAction> {
                 None
             }
         }
-        KeyCode::Char('y') | KeyCode::Char('Y') => {
-            if *current_view == ViewState::Switch {
-                Some(UiAction::ConfirmSwitch)
+        KeyCode::Char('y') | KeyCode::Char('Y') => match current_view {
+            ViewState::Switch => Some(UiAction::ConfirmSwitch),
+            ViewState::Demote => Some(UiAction::ConfirmDemote),
+            ViewState::Status => None,
+        },
+        KeyCode::Char('d') | KeyCode::Char('D') => {
+            if *current_view == ViewState::Status {
+                Some(UiAction::ShowDemote)
             } else {
                 None
             }
@@ -739,11 +1049,26 @@ fn key_to_action(key: KeyEvent, current_view: &ViewState) -> Option<UiAction> {
                 None
             }
         }
+        KeyCode::Up | KeyCode::Char('k') => {
+            if *current_view == ViewState::Switch {
+                Some(UiAction::PreviousSwitchTarget)
+            } else {
+                None
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if *current_view == ViewState::Switch {
+                Some(UiAction::NextSwitchTarget)
+            } else {
+                None
+            }
+        }
         _ => None,
     }
 }
 
 /// Process UI actions with timeouts to prevent blocking
+#[allow(clippy::too_many_arguments)]
 async fn process_ui_action(
     action: UiAction,
     ui_state: &Arc<RwLock<UiState>>,
@@ -751,6 +1076,7 @@ async fn process_ui_action(
     view_state: &Arc<RwLock<ViewState>>,
     app_state: &Arc<AppState>,
     switch_confirmed: &Arc<RwLock<bool>>,
+    demote_confirmed: &Arc<RwLock<bool>>,
     log_sender: &tokio::sync::mpsc::UnboundedSender<LogMessage>,
 ) -> Result<()> {
     match action {
@@ -780,6 +1106,13 @@ async fn process_ui_action(
             if let Ok(mut view) = view_write {
                 *view = ViewState::Switch;
             }
+
+            // Every switch starts on "best standby"
+            if let Ok(mut state) =
+                tokio::time::timeout(Duration::from_millis(50), ui_state.write()).await
+            {
+                state.switch_target_selection = 0;
+            }
         }
         UiAction::ConfirmSwitch => {
             // Use timeouts for both write locks
@@ -794,6 +1127,26 @@ async fn process_ui_action(
                 *quit = true;
             }
         }
+        UiAction::ShowDemote => {
+            if let Ok(mut view) =
+                tokio::time::timeout(Duration::from_millis(50), view_state.write()).await
+            {
+                *view = ViewState::Demote;
+            }
+        }
+        UiAction::ConfirmDemote => {
+            // Leave the TUI like a confirmed switch; the status loop runs the demotion
+            let demote_write =
+                tokio::time::timeout(Duration::from_millis(50), demote_confirmed.write()).await;
+
+            let quit_write =
+                tokio::time::timeout(Duration::from_millis(50), should_quit.write()).await;
+
+            if let (Ok(mut demote), Ok(mut quit)) = (demote_write, quit_write) {
+                *demote = true;
+                *quit = true;
+            }
+        }
         UiAction::Refresh => {
             // Handle refresh with timeout
             handle_refresh_with_timeout(ui_state, app_state, log_sender).await?;
@@ -802,6 +1155,23 @@ async fn process_ui_action(
             // Handle validator switch with timeout
             handle_validator_switch_with_timeout(ui_state, app_state, log_sender).await?;
         }
+        UiAction::PreviousSwitchTarget | UiAction::NextSwitchTarget => {
+            if let Ok(mut state) =
+                tokio::time::timeout(Duration::from_millis(50), ui_state.write()).await
+            {
+                // Entry 0 is "best standby", followed by every non-active node
+                let options = state
+                    .validator_statuses
+                    .get(state.selected_validator_index)
+                    .map(|vs| switch_target_candidates(vs).len() + 1)
+                    .unwrap_or(1);
+                state.switch_target_selection = if matches!(action, UiAction::NextSwitchTarget) {
+                    (state.switch_target_selection + 1) % options
+                } else {
+                    (state.switch_target_selection + options - 1) % options
+                };
+            }
+        }
     }
 
     Ok(())
@@ -822,18 +1192,7 @@ async fn handle_refresh_with_timeout(
 
         // Set all field refresh states to true
         for refresh_state in ui_state_write.field_refresh_states.iter_mut() {
-            refresh_state.node_0.status_refreshing = true;
-            refresh_state.node_0.identity_refreshing = true;
-            refresh_state.node_0.version_refreshing = true;
-            refresh_state.node_0.ssh_connectivity_refreshing = true;
-            refresh_state.node_0.rpc_health_refreshing = true;
-            refresh_state.node_0.swap_readiness_refreshing = true;
-            refresh_state.node_1.status_refreshing = true;
-            refresh_state.node_1.identity_refreshing = true;
-            refresh_state.node_1.version_refreshing = true;
-            refresh_state.node_1.ssh_connectivity_refreshing = true;
-            refresh_state.node_1.rpc_health_refreshing = true;
-            refresh_state.node_1.swap_readiness_refreshing = true;
+            refresh_state.mark_all_refreshing();
         }
     }
 
@@ -875,18 +1234,7 @@ async fn handle_validator_switch_with_timeout(
 
         // Set all field refresh states to true for the new validator
         for refresh_state in ui_state_write.field_refresh_states.iter_mut() {
-            refresh_state.node_0.status_refreshing = true;
-            refresh_state.node_0.identity_refreshing = true;
-            refresh_state.node_0.version_refreshing = true;
-            refresh_state.node_0.ssh_connectivity_refreshing = true;
-            refresh_state.node_0.rpc_health_refreshing = true;
-            refresh_state.node_0.swap_readiness_refreshing = true;
-            refresh_state.node_1.status_refreshing = true;
-            refresh_state.node_1.identity_refreshing = true;
-            refresh_state.node_1.version_refreshing = true;
-            refresh_state.node_1.ssh_connectivity_refreshing = true;
-            refresh_state.node_1.rpc_health_refreshing = true;
-            refresh_state.node_1.swap_readiness_refreshing = true;
+            refresh_state.mark_all_refreshing();
         }
     }
 
@@ -903,6 +1251,15 @@ async fn handle_validator_switch_with_timeout(
     Ok(())
 }
 
+/// Where the status view and `svs monitor` append log messages by default
+pub fn default_log_path() -> std::path::PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join(".solana-validator-switch")
+        .join("logs")
+        .join("latest.log")
+}
+
 /// Enhanced UI App state with async support
 #[allow(dead_code)]
 pub struct EnhancedStatusApp {
@@ -914,8 +1271,10 @@ pub struct EnhancedStatusApp {
     pub view_state: Arc<RwLock<ViewState>>,
     pub emergency_takeover_in_progress: Arc<RwLock<bool>>,
     pub switch_confirmed: Arc<RwLock<bool>>,
+    pub demote_confirmed: Arc<RwLock<bool>>, // Demote every node of the selected validator after the UI exits
     pub background_tasks: Arc<RwLock<Vec<tokio::task::JoinHandle<()>>>>,
     pub last_manual_refresh: Arc<RwLock<Instant>>,
+    pub scheduled_switch: Arc<RwLock<Option<ScheduledSwitch>>>, // Due scheduled switch to run after the UI exits
 }
 
 /// UI State that can be shared across threads
@@ -936,16 +1295,22 @@ pub struct UiState {
     // later successful fetch observes a new vote slot.
     pub last_vote_rpc_failure_times: Vec<Option<Instant>>,
 
+    // Per-endpoint vote views for each validator; delinquency needs a quorum of them
+    pub vote_quorums: Vec<crate::vote_quorum::VoteQuorum>,
+
+    // Cluster-wide stall detection per validator; a stalled cluster suppresses takeovers
+    pub cluster_health: Vec<crate::cluster_health::ClusterHealth>,
+
     // Catchup status for each node
     pub catchup_data: Vec<NodePairStatus>,
 
-    // Track consecutive catchup failures for standby nodes
+    // Track consecutive catchup failures for standby nodes (per validator, per node)
     #[allow(dead_code)]
-    pub catchup_failure_counts: Vec<(u32, u32)>, // (node_0_failures, node_1_failures)
+    pub catchup_failure_counts: Vec<Vec<u32>>,
 
-    // Track last alert time for catchup failures
+    // Track last alert time for catchup failures (per validator, per node)
     #[allow(dead_code)]
-    pub last_catchup_alert_times: Vec<(Option<Instant>, Option<Instant>)>, // (node_0_last_alert, node_1_last_alert)
+    pub last_catchup_alert_times: Vec<Vec<Option<Instant>>>,
 
     // SSH health status for each node
     pub ssh_health_data: Vec<NodePairSshStatus>,
@@ -975,12 +1340,34 @@ pub struct UiState {
 
     // Track last refresh time (either manual or auto)
     pub last_refresh_time: Instant,
+
+    // Switch view target picker: 0 = best standby, n = n-th switch_target_candidates entry
+    pub switch_target_selection: usize,
 }
 
 #[derive(Debug, Clone)]
 pub struct NodeFieldRefreshState {
-    pub node_0: FieldRefreshStates,
-    pub node_1: FieldRefreshStates,
+    pub nodes: Vec<FieldRefreshStates>, // Indexed like nodes_with_status
+}
+
+impl NodeFieldRefreshState {
+    fn new(node_count: usize) -> Self {
+        Self {
+            nodes: vec![FieldRefreshStates::default(); node_count],
+        }
+    }
+
+    /// Flag every field of every node as refreshing
+    fn mark_all_refreshing(&mut self) {
+        for node in self.nodes.iter_mut() {
+            node.status_refreshing = true;
+            node.identity_refreshing = true;
+            node.version_refreshing = true;
+            node.ssh_connectivity_refreshing = true;
+            node.rpc_health_refreshing = true;
+            node.swap_readiness_refreshing = true;
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1001,8 +1388,7 @@ pub struct FieldRefreshStates {
 
 #[derive(Clone)]
 pub struct NodePairStatus {
-    pub node_0: Option<CatchupStatus>,
-    pub node_1: Option<CatchupStatus>,
+    pub nodes: Vec<Option<CatchupStatus>>, // Indexed like nodes_with_status
 }
 
 #[derive(Clone)]
@@ -1015,14 +1401,12 @@ pub struct CatchupStatus {
 
 #[derive(Clone)]
 pub struct NodePairSshStatus {
-    pub node_0: SshHealthStatus,
-    pub node_1: SshHealthStatus,
+    pub nodes: Vec<SshHealthStatus>, // Indexed like nodes_with_status
 }
 
 #[derive(Clone)]
 pub struct NodePairRpcStatus {
-    pub node_0: RpcHealthStatus,
-    pub node_1: RpcHealthStatus,
+    pub nodes: Vec<RpcHealthStatus>, // Indexed like nodes_with_status
 }
 
 #[derive(Clone)]
@@ -1206,10 +1590,16 @@ pub(crate) fn vote_rpc_failure_taints_last_vote_time(
 /// new. Backup nodes still run these checks at the normal 10 second cadence.
 const PRIMARY_SLOW_CHECK_INTERVAL: Duration = Duration::from_secs(600);
 
+/// How often the status process checks the switch schedule for due switches
+const SCHEDULE_POLL_INTERVAL_SECONDS: u64 = 15;
+
+/// (validator, node, check_kind) key for `PRIMARY_CHECK_TIMESTAMPS`
+type PrimaryCheckKey = (usize, usize, &'static str);
+
 /// Per-(validator, node, check_kind) timestamp of the last time we let a slow
 /// primary check run. Used by `should_throttle_primary_check` below.
 static PRIMARY_CHECK_TIMESTAMPS: OnceLock<
-    Mutex<std::collections::HashMap<(usize, usize, &'static str), Instant>>,
+    Mutex<std::collections::HashMap<PrimaryCheckKey, Instant>>,
 > = OnceLock::new();
 
 /// Returns true if a periodic check should be skipped on the primary because
@@ -1303,29 +1693,30 @@ async fn clear_field_refresh_flags<F>(
     F: FnOnce(&mut FieldRefreshStates),
 {
     let mut st = ui_state.write().await;
-    if let Some(refresh_state) = st.field_refresh_states.get_mut(validator_idx) {
-        let target = if node_idx == 0 {
-            &mut refresh_state.node_0
-        } else {
-            &mut refresh_state.node_1
-        };
+    if let Some(target) = st
+        .field_refresh_states
+        .get_mut(validator_idx)
+        .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+    {
         apply(target);
     }
 }
 
 impl EnhancedStatusApp {
     pub async fn new(app_state: Arc<AppState>) -> Result<Self> {
+        Self::with_log_path(app_state, default_log_path()).await
+    }
+
+    /// Like `new`, with log messages appended to `log_path`
+    pub async fn with_log_path(
+        app_state: Arc<AppState>,
+        log_path: std::path::PathBuf,
+    ) -> Result<Self> {
         let ssh_pool = Arc::clone(&app_state.ssh_pool);
 
         // Create unbounded channel for log messages
         let (log_sender, mut log_receiver) = tokio::sync::mpsc::unbounded_channel::<LogMessage>();
 
-        let log_path = dirs::home_dir()
-            .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join(".solana-validator-switch")
-            .join("logs")
-            .join("latest.log");
-
         tokio::spawn(async move {
             while let Some(message) = log_receiver.recv().await {
                 let level = match message.level {
@@ -1352,69 +1743,54 @@ impl EnhancedStatusApp {
         for validator_status in &app_state.validator_statuses {
             initial_vote_data.push(None);
 
-            // Initialize catchup status for standby nodes
-            let mut node_pair = NodePairStatus {
-                node_0: None,
-                node_1: None,
+            // Initialize catchup status for standby nodes or Firedancer nodes
+            let node_pair = NodePairStatus {
+                nodes: validator_status
+                    .nodes_with_status
+                    .iter()
+                    .map(|node| {
+                        let tracked = validator_status.nodes_with_status.len() >= 2
+                            && (node.status == crate::types::NodeStatus::Standby
+                                || node.validator_type == crate::types::ValidatorType::Firedancer);
+                        tracked.then(|| CatchupStatus {
+                            status: "⏳ Initializing...".to_string(),
+                            last_updated: Instant::now(),
+                            is_streaming: false,
+                        })
+                    })
+                    .collect(),
             };
 
-            if validator_status.nodes_with_status.len() >= 2 {
-                // Initialize for standby nodes or Firedancer nodes
-                if validator_status.nodes_with_status[0].status == crate::types::NodeStatus::Standby
-                    || validator_status.nodes_with_status[0].validator_type
-                        == crate::types::ValidatorType::Firedancer
-                {
-                    node_pair.node_0 = Some(CatchupStatus {
-                        status: "⏳ Initializing...".to_string(),
-                        last_updated: Instant::now(),
-                        is_streaming: false,
-                    });
-                }
-                if validator_status.nodes_with_status[1].status == crate::types::NodeStatus::Standby
-                    || validator_status.nodes_with_status[1].validator_type
-                        == crate::types::ValidatorType::Firedancer
-                {
-                    node_pair.node_1 = Some(CatchupStatus {
-                        status: "⏳ Initializing...".to_string(),
-                        last_updated: Instant::now(),
-                        is_streaming: false,
-                    });
-                }
-            }
-
             initial_catchup_data.push(node_pair);
 
             let ssh_pair = NodePairSshStatus {
-                node_0: SshHealthStatus {
-                    is_healthy: true,
-                    last_success: Some(Instant::now()),
-                    failure_start: None,
-                },
-                node_1: SshHealthStatus {
-                    is_healthy: true,
-                    last_success: Some(Instant::now()),
-                    failure_start: None,
-                },
+                nodes: validator_status
+                    .nodes_with_status
+                    .iter()
+                    .map(|_| SshHealthStatus {
+                        is_healthy: true,
+                        last_success: Some(Instant::now()),
+                        failure_start: None,
+                    })
+                    .collect(),
             };
             initial_ssh_health_data.push(ssh_pair);
         }
 
         // Initialize RPC health data
         let mut initial_rpc_health_data = Vec::new();
-        for _ in 0..app_state.validator_statuses.len() {
+        for validator_status in &app_state.validator_statuses {
             let rpc_pair = NodePairRpcStatus {
-                node_0: RpcHealthStatus {
-                    is_healthy: false,
-                    last_check: None,
-                    error_message: None,
-                    failure_start: None,
-                },
-                node_1: RpcHealthStatus {
-                    is_healthy: false,
-                    last_check: None,
-                    error_message: None,
-                    failure_start: None,
-                },
+                nodes: validator_status
+                    .nodes_with_status
+                    .iter()
+                    .map(|_| RpcHealthStatus {
+                        is_healthy: false,
+                        last_check: None,
+                        error_message: None,
+                        failure_start: None,
+                    })
+                    .collect(),
             };
             initial_rpc_health_data.push(rpc_pair);
         }
@@ -1434,11 +1810,10 @@ impl EnhancedStatusApp {
         }
 
         // Initialize field refresh states
-        let initial_field_refresh_states = (0..app_state.validator_statuses.len())
-            .map(|_| NodeFieldRefreshState {
-                node_0: FieldRefreshStates::default(),
-                node_1: FieldRefreshStates::default(),
-            })
+        let initial_field_refresh_states = app_state
+            .validator_statuses
+            .iter()
+            .map(|vs| NodeFieldRefreshState::new(vs.nodes_with_status.len()))
             .collect();
 
         let ui_state = Arc::new(RwLock::new(UiState {
@@ -1448,9 +1823,23 @@ impl EnhancedStatusApp {
             selected_validator_index: app_state.selected_validator_index,
             last_vote_slot_times: vec![None; app_state.validator_statuses.len()],
             last_vote_rpc_failure_times: vec![None; app_state.validator_statuses.len()],
+            vote_quorums: app_state
+                .validator_statuses
+                .iter()
+                .map(|vs| crate::vote_quorum::VoteQuorum::new(&vs.validator_pair))
+                .collect(),
+            cluster_health: vec![Default::default(); app_state.validator_statuses.len()],
             catchup_data: initial_catchup_data,
-            catchup_failure_counts: vec![(0, 0); app_state.validator_statuses.len()],
-            last_catchup_alert_times: vec![(None, None); app_state.validator_statuses.len()],
+            catchup_failure_counts: app_state
+                .validator_statuses
+                .iter()
+                .map(|vs| vec![0; vs.nodes_with_status.len()])
+                .collect(),
+            last_catchup_alert_times: app_state
+                .validator_statuses
+                .iter()
+                .map(|vs| vec![None; vs.nodes_with_status.len()])
+                .collect(),
             ssh_health_data: initial_ssh_health_data,
             rpc_health_data: initial_rpc_health_data,
             validator_health: initial_validator_health,
@@ -1462,6 +1851,7 @@ impl EnhancedStatusApp {
             validator_statuses: app_state.validator_statuses.clone(),
             is_refreshing: false,
             last_refresh_time: Instant::now(),
+            switch_target_selection: 0,
         }));
 
         Ok(Self {
@@ -1473,8 +1863,10 @@ impl EnhancedStatusApp {
             view_state: Arc::new(RwLock::new(ViewState::Status)),
             emergency_takeover_in_progress: Arc::new(RwLock::new(false)),
             switch_confirmed: Arc::new(RwLock::new(false)),
+            demote_confirmed: Arc::new(RwLock::new(false)),
             background_tasks: Arc::new(RwLock::new(Vec::new())),
             last_manual_refresh: Arc::new(RwLock::new(Instant::now() - Duration::from_secs(60))),
+            scheduled_switch: Arc::new(RwLock::new(None)),
         })
     }
 
@@ -1520,10 +1912,13 @@ impl EnhancedStatusApp {
         let node_status_poll_interval_seconds =
             node_status_poll_interval_seconds(self.app_state.config.alert_config.as_ref());
 
+        let mut handles = Vec::new();
+
         let ui_state_for_vote_refresh = Arc::clone(&self.ui_state);
         let app_state_for_vote_refresh = Arc::clone(&self.app_state);
         let log_sender_for_vote_refresh = self.log_sender.clone();
-        tokio::spawn(async move {
+        let emergency_takeover_flag = Arc::clone(&self.emergency_takeover_in_progress);
+        handles.push(tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(vote_account_poll_interval_seconds));
             interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
@@ -1541,15 +1936,16 @@ impl EnhancedStatusApp {
                     ui_state_for_vote_refresh.clone(),
                     log_sender_for_vote_refresh.clone(),
                     alert_manager.clone(),
+                    emergency_takeover_flag.clone(),
                 )
                 .await;
             }
-        });
+        }));
 
         let ui_state_for_node_refresh = Arc::clone(&self.ui_state);
         let app_state_for_node_refresh = Arc::clone(&self.app_state);
         let log_sender_for_node_refresh = self.log_sender.clone();
-        tokio::spawn(async move {
+        handles.push(tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(node_status_poll_interval_seconds));
             // Don't try to "catch up" on missed ticks. If the previous refresh
             // took longer than the configured interval, wait for the next
@@ -1576,18 +1972,7 @@ impl EnhancedStatusApp {
                     let selected_idx = state.selected_validator_index;
                     if selected_idx < state.field_refresh_states.len() {
                         let refresh_state = &mut state.field_refresh_states[selected_idx];
-                        refresh_state.node_0.status_refreshing = true;
-                        refresh_state.node_0.identity_refreshing = true;
-                        refresh_state.node_0.version_refreshing = true;
-                        refresh_state.node_0.ssh_connectivity_refreshing = true;
-                        refresh_state.node_0.rpc_health_refreshing = true;
-                        refresh_state.node_0.swap_readiness_refreshing = true;
-                        refresh_state.node_1.status_refreshing = true;
-                        refresh_state.node_1.identity_refreshing = true;
-                        refresh_state.node_1.version_refreshing = true;
-                        refresh_state.node_1.ssh_connectivity_refreshing = true;
-                        refresh_state.node_1.rpc_health_refreshing = true;
-                        refresh_state.node_1.swap_readiness_refreshing = true;
+                        refresh_state.mark_all_refreshing();
                     }
                 }
 
@@ -1598,11 +1983,98 @@ impl EnhancedStatusApp {
                     refresh_all_fields(app_state_clone, ui_state_clone, log_sender_clone).await;
                 });
             }
-        });
+        }));
+
+        // Telegram panic button: `/demote <validator>` from the alert chat
+        if let Some(alert_config) = self
+            .app_state
+            .config
+            .alert_config
+            .clone()
+            .filter(|config| config.enabled && config.telegram_commands)
+        {
+            let app_state_for_commands = Arc::clone(&self.app_state);
+            let log_sender_for_commands = self.log_sender.clone();
+            handles.push(tokio::spawn(async move {
+                handle_telegram_commands(
+                    app_state_for_commands,
+                    AlertManager::new(alert_config),
+                    log_sender_for_commands,
+                )
+                .await;
+            }));
+        }
+
+        // Double-signing guard: ask every node of every pair for its identity each
+        // node-status tick, unthrottled, and alert when two hold the funded identity
+        let ui_state_for_guard = Arc::clone(&self.ui_state);
+        let app_state_for_guard = Arc::clone(&self.app_state);
+        let log_sender_for_guard = self.log_sender.clone();
+        let emergency_takeover_flag_for_guard = Arc::clone(&self.emergency_takeover_in_progress);
+        handles.push(tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(node_status_poll_interval_seconds));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            let mut guards: Vec<crate::double_sign_guard::DoubleSignGuard> = Vec::new();
+
+            loop {
+                interval.tick().await;
+                // A takeover moves identities on purpose
+                if *emergency_takeover_flag_for_guard.read().await {
+                    continue;
+                }
+                check_double_signing(
+                    &app_state_for_guard,
+                    &ui_state_for_guard,
+                    &log_sender_for_guard,
+                    &mut guards,
+                )
+                .await;
+            }
+        }));
+
+        // Hand due scheduled switches to the status loop, which runs them outside the TUI
+        let scheduled_switch = Arc::clone(&self.scheduled_switch);
+        let should_quit_for_schedule = Arc::clone(&self.should_quit);
+        handles.push(tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(SCHEDULE_POLL_INTERVAL_SECONDS));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                interval.tick().await;
+                if *should_quit_for_schedule.read().await {
+                    break;
+                }
+
+                let Ok(mut schedule) = SwitchSchedule::load() else {
+                    continue;
+                };
+                if let Some(due) = schedule.take_due(chrono::Utc::now()) {
+                    // Remove it from disk first so a restart never runs it twice
+                    if schedule.save().is_err() {
+                        continue;
+                    }
+                    *scheduled_switch.write().await = Some(due);
+                    *should_quit_for_schedule.write().await = true;
+                    break;
+                }
+            }
+        }));
 
-        // Two background tasks run independently:
+        // Three background tasks run independently:
         // - vote-account polling hits the configured cluster RPC
         // - node-status polling hits validators directly over SSH/local RPC
+        // - schedule polling checks the switch schedule on disk
+        if let Ok(mut background_tasks) = self.background_tasks.try_write() {
+            background_tasks.extend(handles);
+        }
+    }
+
+    /// Abort the tasks started by `spawn_background_tasks`. A takeover that is
+    /// already running is its own task and finishes regardless.
+    pub async fn stop_background_tasks(&self) {
+        for task in self.background_tasks.write().await.drain(..) {
+            task.abort();
+        }
     }
 }
 
@@ -1695,10 +2167,8 @@ async fn stream_catchup_for_node(
                         is_streaming: true,
                     };
 
-                    if node_idx == 0 {
-                        catchup_data.node_0 = Some(catchup_status);
-                    } else {
-                        catchup_data.node_1 = Some(catchup_status);
+                    if let Some(slot) = catchup_data.nodes.get_mut(node_idx) {
+                        *slot = Some(catchup_status);
                     }
                 }
             }
@@ -1724,14 +2194,12 @@ async fn stream_catchup_for_node(
         // Mark as not streaming anymore
         {
             let mut state = ui_state.write().await;
-            if let Some(catchup_data) = state.catchup_data.get_mut(validator_idx) {
-                if node_idx == 0 {
-                    if let Some(ref mut status) = catchup_data.node_0 {
-                        status.is_streaming = false;
-                    }
-                } else if let Some(ref mut status) = catchup_data.node_1 {
-                    status.is_streaming = false;
-                }
+            if let Some(Some(status)) = state
+                .catchup_data
+                .get_mut(validator_idx)
+                .and_then(|catchup_data| catchup_data.nodes.get_mut(node_idx))
+            {
+                status.is_streaming = false;
             }
         }
 
@@ -1869,6 +2337,7 @@ pub async fn run_enhanced_ui(app: &mut EnhancedStatusApp) -> Result<bool> {
                     &app.view_state,
                     &app.app_state,
                     &app.switch_confirmed,
+                    &app.demote_confirmed,
                     &app.log_sender,
                 )
                 .await?;
@@ -1929,6 +2398,7 @@ pub async fn run_enhanced_ui(app: &mut EnhancedStatusApp) -> Result<bool> {
                 terminal.draw(|f| match *view_state_read {
                     ViewState::Status => draw_ui(f, &ui_state_read, &app.app_state),
                     ViewState::Switch => draw_switch_ui(f, &app.app_state, &ui_state_read),
+                    ViewState::Demote => draw_demote_ui(f, &ui_state_read),
                 })?;
 
                 drop(ui_state_read);
@@ -2045,73 +2515,26 @@ fn draw_side_by_side_tables(
     _last_ssh_health_refresh: Instant,
     field_refresh_state: Option<&NodeFieldRefreshState>,
 ) {
-    // Handle single node configuration
-    if validator_status.nodes_with_status.len() == 1 {
-        // Use the full area for single node
-        if let Some(node) = validator_status.nodes_with_status.get(0) {
-            let ssh_health = ssh_health_data.map(|s| &s.node_0);
-            let rpc_health = rpc_health_data.map(|r| &r.node_0);
-            let node_refresh_state = field_refresh_state.map(|s| &s.node_0);
-
-            draw_single_node_table(
-                f,
-                area,
-                validator_status,
-                node,
-                vote_data,
-                previous_last_slot,
-                increment_time,
-                app_state,
-                _last_catchup_refresh,
-                ssh_health,
-                rpc_health,
-                _last_ssh_health_refresh,
-                node_refresh_state,
-                false, // not a left table in split view
-            );
-        }
+    let node_count = validator_status.nodes_with_status.len();
+    if node_count == 0 {
         return;
     }
 
-    // Split area horizontally for two nodes
+    // Split area horizontally into one column per node. Nodes are always shown
+    // in config order so the hosts keep consistent positions.
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints(vec![Constraint::Ratio(1, node_count as u32); node_count])
         .split(area);
 
-    // Always show nodes in the same order (node 0 on left, node 1 on right)
-    // This keeps the hosts in consistent positions
-    let (left_node_idx, right_node_idx) = (0, 1);
-
-    // Draw left table (node 0)
-    if let Some(node) = validator_status.nodes_with_status.get(left_node_idx) {
-        let ssh_health = ssh_health_data.map(|s| {
-            if left_node_idx == 0 {
-                &s.node_0
-            } else {
-                &s.node_1
-            }
-        });
-
-        let rpc_health = rpc_health_data.map(|r| {
-            if left_node_idx == 0 {
-                &r.node_0
-            } else {
-                &r.node_1
-            }
-        });
-
-        let node_refresh_state = field_refresh_state.map(|s| {
-            if left_node_idx == 0 {
-                &s.node_0
-            } else {
-                &s.node_1
-            }
-        });
+    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate() {
+        let ssh_health = ssh_health_data.and_then(|s| s.nodes.get(node_idx));
+        let rpc_health = rpc_health_data.and_then(|r| r.nodes.get(node_idx));
+        let node_refresh_state = field_refresh_state.and_then(|s| s.nodes.get(node_idx));
 
         draw_single_node_table(
             f,
-            chunks[0],
+            chunks[node_idx],
             validator_status,
             node,
             vote_data,
@@ -2123,51 +2546,7 @@ fn draw_side_by_side_tables(
             rpc_health,
             _last_ssh_health_refresh,
             node_refresh_state,
-            true, // is_left_table
-        );
-    }
-
-    // Draw right table (node 1)
-    if let Some(node) = validator_status.nodes_with_status.get(right_node_idx) {
-        let ssh_health = ssh_health_data.map(|s| {
-            if right_node_idx == 0 {
-                &s.node_0
-            } else {
-                &s.node_1
-            }
-        });
-
-        let rpc_health = rpc_health_data.map(|r| {
-            if right_node_idx == 0 {
-                &r.node_0
-            } else {
-                &r.node_1
-            }
-        });
-
-        let node_refresh_state = field_refresh_state.map(|s| {
-            if right_node_idx == 0 {
-                &s.node_0
-            } else {
-                &s.node_1
-            }
-        });
-
-        draw_single_node_table(
-            f,
-            chunks[1],
-            validator_status,
-            node,
-            vote_data,
-            previous_last_slot,
-            increment_time,
-            app_state,
-            _last_catchup_refresh,
-            ssh_health,
-            rpc_health,
-            _last_ssh_health_refresh,
-            node_refresh_state,
-            false, // is_left_table
+            node_count > 1 && node_idx + 1 < node_count, // is_left_table
         );
     }
 }
@@ -2774,252 +3153,178 @@ fn draw_validator_table(
 
     let mut rows = vec![];
 
-    // Node status row with host and status
-    if validator_status.nodes_with_status.len() >= 2 {
-        let node_0 = &validator_status.nodes_with_status[0];
-        let node_1 = &validator_status.nodes_with_status[1];
+    let nodes = &validator_status.nodes_with_status;
+    let node_row = |label: &'static str, cells: Vec<Cell<'static>>| {
+        let mut row_cells = vec![Cell::from(label)];
+        row_cells.extend(cells);
+        Row::new(row_cells)
+    };
 
+    // Node status rows, one column per configured node
+    if !nodes.is_empty() {
         // Status row
-        rows.push(Row::new(vec![
-            Cell::from("Status"),
-            Cell::from(format!(
-                "{} ({})",
-                match node_0.status {
-                    crate::types::NodeStatus::Active => "🟢 ACTIVE",
-                    crate::types::NodeStatus::Standby => "🟡 STANDBY",
-                    crate::types::NodeStatus::Unknown => "🔴 UNKNOWN",
-                },
-                node_0.node.label
-            ))
-            .style(Style::default().fg(match node_0.status {
-                crate::types::NodeStatus::Active => Color::Green,
-                crate::types::NodeStatus::Standby => Color::Yellow,
-                crate::types::NodeStatus::Unknown => Color::Red,
-            })),
-            Cell::from(format!(
-                "{} ({})",
-                match node_1.status {
-                    crate::types::NodeStatus::Active => "🟢 ACTIVE",
-                    crate::types::NodeStatus::Standby => "🟡 STANDBY",
-                    crate::types::NodeStatus::Unknown => "🔴 UNKNOWN",
-                },
-                node_1.node.label
-            ))
-            .style(Style::default().fg(match node_1.status {
-                crate::types::NodeStatus::Active => Color::Green,
-                crate::types::NodeStatus::Standby => Color::Yellow,
-                crate::types::NodeStatus::Unknown => Color::Red,
-            })),
-        ]));
+        rows.push(node_row(
+            "Status",
+            nodes
+                .iter()
+                .map(|node| {
+                    Cell::from(format!(
+                        "{} ({})",
+                        match node.status {
+                            crate::types::NodeStatus::Active => "🟢 ACTIVE",
+                            crate::types::NodeStatus::Standby => "🟡 STANDBY",
+                            crate::types::NodeStatus::Unknown => "🔴 UNKNOWN",
+                        },
+                        node.node.label
+                    ))
+                    .style(Style::default().fg(match node.status {
+                        crate::types::NodeStatus::Active => Color::Green,
+                        crate::types::NodeStatus::Standby => Color::Yellow,
+                        crate::types::NodeStatus::Unknown => Color::Red,
+                    }))
+                })
+                .collect(),
+        ));
 
         // Host info row
-        rows.push(Row::new(vec![
-            Cell::from("Host"),
-            Cell::from(node_0.node.host.as_str()),
-            Cell::from(node_1.node.host.as_str()),
-        ]));
+        rows.push(node_row(
+            "Host",
+            nodes
+                .iter()
+                .map(|node| Cell::from(node.node.host.clone()))
+                .collect(),
+        ));
 
         // Validator type and version row
-        rows.push(Row::new(vec![
-            Cell::from("Type/Version"),
-            Cell::from({
-                let version = node_0.version.as_deref().unwrap_or("");
-                let cleaned_version = version
-                    .replace("Firedancer ", "")
-                    .replace("Agave ", "")
-                    .replace("Jito ", "");
-                format!(
-                    "{} {}",
-                    match node_0.validator_type {
-                        crate::types::ValidatorType::Firedancer => "Firedancer",
-                        crate::types::ValidatorType::Agave => "Agave",
-                        crate::types::ValidatorType::Jito => "Jito",
-                        crate::types::ValidatorType::Unknown => "Unknown",
-                    },
-                    cleaned_version
-                )
-            }),
-            Cell::from({
-                let version = node_1.version.as_deref().unwrap_or("");
-                let cleaned_version = version
-                    .replace("Firedancer ", "")
-                    .replace("Agave ", "")
-                    .replace("Jito ", "");
-                format!(
-                    "{} {}",
-                    match node_1.validator_type {
-                        crate::types::ValidatorType::Firedancer => "Firedancer",
-                        crate::types::ValidatorType::Agave => "Agave",
-                        crate::types::ValidatorType::Jito => "Jito",
-                        crate::types::ValidatorType::Unknown => "Unknown",
-                    },
-                    cleaned_version
-                )
-            }),
-        ]));
+        rows.push(node_row(
+            "Type/Version",
+            nodes
+                .iter()
+                .map(|node| {
+                    let version = node.version.as_deref().unwrap_or("");
+                    let cleaned_version = version
+                        .replace("Firedancer ", "")
+                        .replace("Agave ", "")
+                        .replace("Jito ", "");
+                    Cell::from(format!(
+                        "{} {}",
+                        match node.validator_type {
+                            crate::types::ValidatorType::Firedancer => "Firedancer",
+                            crate::types::ValidatorType::Agave => "Agave",
+                            crate::types::ValidatorType::Jito => "Jito",
+                            crate::types::ValidatorType::Unknown => "Unknown",
+                        },
+                        cleaned_version
+                    ))
+                })
+                .collect(),
+        ));
 
         // Identity row - format as ascd...edsas
-        let id0 = node_0.current_identity.as_deref().unwrap_or("Unknown");
-        let id1 = node_1.current_identity.as_deref().unwrap_or("Unknown");
-        let id0_formatted = if id0 != "Unknown" && id0.len() > 8 {
-            format!(
-                "{}…{}",
-                id0.chars().take(4).collect::<String>(),
-                id0.chars()
-                    .rev()
-                    .take(4)
-                    .collect::<String>()
-                    .chars()
-                    .rev()
-                    .collect::<String>()
-            )
-        } else {
-            id0.to_string()
-        };
-        let id1_formatted = if id1 != "Unknown" && id1.len() > 8 {
-            format!(
-                "{}…{}",
-                id1.chars().take(4).collect::<String>(),
-                id1.chars()
-                    .rev()
-                    .take(4)
-                    .collect::<String>()
-                    .chars()
-                    .rev()
-                    .collect::<String>()
-            )
-        } else {
-            id1.to_string()
-        };
-
-        rows.push(Row::new(vec![
-            Cell::from("Identity"),
-            Cell::from(id0_formatted),
-            Cell::from(id1_formatted),
-        ]));
+        rows.push(node_row(
+            "Identity",
+            nodes
+                .iter()
+                .map(|node| {
+                    let id = node.current_identity.as_deref().unwrap_or("Unknown");
+                    Cell::from(if id != "Unknown" && id.len() > 8 {
+                        format!(
+                            "{}…{}",
+                            id.chars().take(4).collect::<String>(),
+                            id.chars()
+                                .rev()
+                                .take(4)
+                                .collect::<String>()
+                                .chars()
+                                .rev()
+                                .collect::<String>()
+                        )
+                    } else {
+                        id.to_string()
+                    })
+                })
+                .collect(),
+        ));
 
         // Swap readiness row
-        rows.push(Row::new(vec![
-            Cell::from("Swap Ready"),
-            Cell::from(match node_0.swap_ready {
-                Some(true) => "✅ Ready",
-                Some(false) => "❌ Not Ready",
-                None => "⏳ Checking...",
-            })
-            .style(Style::default().fg(match node_0.swap_ready {
-                Some(true) => Color::Green,
-                Some(false) => Color::Red,
-                None => Color::Yellow,
-            })),
-            Cell::from(match node_1.swap_ready {
-                Some(true) => "✅ Ready",
-                Some(false) => "❌ Not Ready",
-                None => "⏳ Checking...",
-            })
-            .style(Style::default().fg(match node_1.swap_ready {
-                Some(true) => Color::Green,
-                Some(false) => Color::Red,
-                None => Color::Yellow,
-            })),
-        ]));
+        rows.push(node_row(
+            "Swap Ready",
+            nodes
+                .iter()
+                .map(|node| {
+                    Cell::from(match node.swap_ready {
+                        Some(true) => "✅ Ready",
+                        Some(false) => "❌ Not Ready",
+                        None => "⏳ Checking...",
+                    })
+                    .style(Style::default().fg(match node.swap_ready {
+                        Some(true) => Color::Green,
+                        Some(false) => Color::Red,
+                        None => Color::Yellow,
+                    }))
+                })
+                .collect(),
+        ));
 
         // Display swap issues if any
-        let node_0_has_issues =
-            matches!(node_0.swap_ready, Some(false)) && !node_0.swap_issues.is_empty();
-        let node_1_has_issues =
-            matches!(node_1.swap_ready, Some(false)) && !node_1.swap_issues.is_empty();
-
-        if node_0_has_issues || node_1_has_issues {
-            let max_issues = node_0.swap_issues.len().max(node_1.swap_issues.len());
-            for i in 0..max_issues {
-                let issue_0 = if i < node_0.swap_issues.len() && node_0_has_issues {
-                    format!("⚠️  {}", node_0.swap_issues[i])
-                } else {
-                    String::new()
-                };
-                let issue_1 = if i < node_1.swap_issues.len() && node_1_has_issues {
-                    format!("⚠️  {}", node_1.swap_issues[i])
+        let node_issues: Vec<&[String]> = nodes
+            .iter()
+            .map(|node| {
+                if matches!(node.swap_ready, Some(false)) {
+                    node.swap_issues.as_slice()
                 } else {
-                    String::new()
-                };
-
-                rows.push(Row::new(vec![
-                    Cell::from(if i == 0 { "  └─ Issues" } else { "" }),
-                    Cell::from(issue_0).style(Style::default().fg(Color::Yellow)),
-                    Cell::from(issue_1).style(Style::default().fg(Color::Yellow)),
-                ]));
-            }
-        }
-
-        // Sync status row if available
-        if node_0.sync_status.is_some() || node_1.sync_status.is_some() {
-            rows.push(Row::new(vec![
-                Cell::from("Sync Status"),
-                Cell::from(node_0.sync_status.as_deref().unwrap_or("N/A")),
-                Cell::from(node_1.sync_status.as_deref().unwrap_or("N/A")),
-            ]));
-        }
-
-        // Ledger path row if available
-        if node_0.ledger_path.is_some() || node_1.ledger_path.is_some() {
-            rows.push(Row::new(vec![
-                Cell::from("Ledger Path"),
-                Cell::from(
-                    node_0
-                        .ledger_path
-                        .as_deref()
-                        .unwrap_or("N/A")
-                        .split('/')
-                        .last()
-                        .unwrap_or("N/A"),
-                ),
-                Cell::from(
-                    node_1
-                        .ledger_path
-                        .as_deref()
-                        .unwrap_or("N/A")
-                        .split('/')
-                        .last()
-                        .unwrap_or("N/A"),
-                ),
-            ]));
-        }
-
-        // Executable paths - shortened to save space
-        if node_0.solana_cli_executable.is_some() || node_1.solana_cli_executable.is_some() {
-            rows.push(Row::new(vec![
-                Cell::from("Solana CLI"),
-                Cell::from(node_0.solana_cli_executable.as_deref().unwrap_or("N/A")),
-                Cell::from(node_1.solana_cli_executable.as_deref().unwrap_or("N/A")),
-            ]));
-        }
-
-        if node_0.fdctl_executable.is_some() || node_1.fdctl_executable.is_some() {
-            rows.push(Row::new(vec![
-                Cell::from("Fdctl Path"),
-                Cell::from(node_0.fdctl_executable.as_deref().unwrap_or("N/A")),
-                Cell::from(node_1.fdctl_executable.as_deref().unwrap_or("N/A")),
-            ]));
+                    &[]
+                }
+            })
+            .collect();
+        let max_issues = node_issues
+            .iter()
+            .map(|issues| issues.len())
+            .max()
+            .unwrap_or(0);
+        for i in 0..max_issues {
+            rows.push(node_row(
+                if i == 0 { "  └─ Issues" } else { "" },
+                node_issues
+                    .iter()
+                    .map(|issues| {
+                        Cell::from(
+                            issues
+                                .get(i)
+                                .map(|issue| format!("⚠️  {}", issue))
+                                .unwrap_or_default(),
+                        )
+                        .style(Style::default().fg(Color::Yellow))
+                    })
+                    .collect(),
+            ));
         }
 
-        if node_0.agave_validator_executable.is_some()
-            || node_1.agave_validator_executable.is_some()
-        {
-            rows.push(Row::new(vec![
-                Cell::from("Agave Path"),
-                Cell::from(
-                    node_0
-                        .agave_validator_executable
-                        .as_deref()
-                        .unwrap_or("N/A"),
-                ),
-                Cell::from(
-                    node_1
-                        .agave_validator_executable
-                        .as_deref()
-                        .unwrap_or("N/A"),
-                ),
-            ]));
+        // Optional text rows, shown when any node reports a value
+        type NodeField = fn(&crate::types::NodeWithStatus) -> Option<&str>;
+        let optional_rows: [(&'static str, NodeField); 5] = [
+            ("Sync Status", |node| node.sync_status.as_deref()),
+            ("Ledger Path", |node| {
+                node.ledger_path
+                    .as_deref()
+                    .and_then(|path| path.split('/').last())
+            }),
+            ("Solana CLI", |node| node.solana_cli_executable.as_deref()),
+            ("Fdctl Path", |node| node.fdctl_executable.as_deref()),
+            ("Agave Path", |node| {
+                node.agave_validator_executable.as_deref()
+            }),
+        ];
+        for (label, value) in optional_rows {
+            if nodes.iter().any(|node| value(node).is_some()) {
+                rows.push(node_row(
+                    label,
+                    nodes
+                        .iter()
+                        .map(|node| Cell::from(value(node).unwrap_or("N/A").to_string()))
+                        .collect(),
+                ));
+            }
         }
 
         // Vote status row with slot info - moved to bottom
@@ -3074,22 +3379,22 @@ fn draw_validator_table(
                 (display, style)
             };
 
-            let (node_0_display, node_0_style) =
-                build_vote_display(node_0.status == crate::types::NodeStatus::Active);
-            let (node_1_display, node_1_style) =
-                build_vote_display(node_1.status == crate::types::NodeStatus::Active);
-
-            rows.push(Row::new(vec![
-                Cell::from("Vote Status"),
-                Cell::from(node_0_display).style(node_0_style),
-                Cell::from(node_1_display).style(node_1_style),
-            ]));
+            rows.push(node_row(
+                "Vote Status",
+                nodes
+                    .iter()
+                    .map(|node| {
+                        let (display, style) =
+                            build_vote_display(node.status == crate::types::NodeStatus::Active);
+                        Cell::from(display).style(style)
+                    })
+                    .collect(),
+            ));
         } else {
-            rows.push(Row::new(vec![
-                Cell::from("Vote Status"),
-                Cell::from("Loading..."),
-                Cell::from("Loading..."),
-            ]));
+            rows.push(node_row(
+                "Vote Status",
+                nodes.iter().map(|_| Cell::from("Loading...")).collect(),
+            ));
         }
     }
 
@@ -3105,11 +3410,10 @@ fn draw_validator_table(
         _ => "Disabled",
     };
 
-    rows.push(Row::new(vec![
-        Cell::from("Alert Status"),
-        Cell::from(alert_status),
-        Cell::from(alert_status),
-    ]));
+    rows.push(node_row(
+        "Alert Status",
+        nodes.iter().map(|_| Cell::from(alert_status)).collect(),
+    ));
 
     // Add validator selection info if multiple validators
     let title = if app_state.validator_statuses.len() > 1 {
@@ -3130,15 +3434,13 @@ fn draw_validator_table(
         )
     };
 
-    let table = Table::new(
-        rows,
-        vec![
-            Constraint::Length(20), // Wider label column for better spacing
-            Constraint::Percentage(40),
-            Constraint::Percentage(40),
-        ],
-    )
-    .block(
+    let mut widths = vec![Constraint::Length(20)]; // Wider label column for better spacing
+    widths.extend(vec![
+        Constraint::Ratio(1, nodes.len().max(1) as u32);
+        nodes.len().max(1)
+    ]);
+
+    let table = Table::new(rows, widths).block(
         Block::default()
             .title(title)
             .title_alignment(Alignment::Center)
@@ -3187,9 +3489,12 @@ fn draw_footer(f: &mut ratatui::Frame, area: Rect, ui_state: &UiState, app_state
 
     // Add Tab option if multiple validators
     let help_text = if app_state.validator_statuses.len() > 1 {
-        format!("(Q)uit | {} | (S)witch | Tab: Next validator", refresh_text)
+        format!(
+            "(Q)uit | {} | (S)witch | (D)emote | Tab: Next validator",
+            refresh_text
+        )
     } else {
-        format!("(Q)uit | {} | (S)witch", refresh_text)
+        format!("(Q)uit | {} | (S)witch | (D)emote", refresh_text)
     };
 
     let footer = Paragraph::new(help_text)
@@ -3200,24 +3505,30 @@ fn draw_footer(f: &mut ratatui::Frame, area: Rect, ui_state: &UiState, app_state
 }
 
 /// Execute emergency failover for a validator
-#[allow(dead_code)] // This is called from tokio::spawn
 async fn execute_emergency_failover(
     validator_status: crate::ValidatorStatus,
     alert_manager: AlertManager,
     ssh_pool: Arc<crate::ssh::AsyncSshPool>,
     detected_ssh_keys: std::collections::HashMap<String, String>,
+    switch_config: crate::types::SwitchConfig,
+    alert_config: Option<crate::types::AlertConfig>,
     emergency_takeover_flag: Arc<RwLock<bool>>,
 ) {
-    // Find active and standby nodes
+    // Find the active node and the best standby (health, slot lag, priority)
+    let best_standby = crate::emergency_failover::select_best_standby(
+        &validator_status.nodes_with_status,
+        None,
+        &validator_status.validator_pair.rpc,
+        &ssh_pool,
+        &detected_ssh_keys,
+    )
+    .await;
     let (active_node, standby_node) = match (
         validator_status
             .nodes_with_status
             .iter()
             .find(|n| n.status == crate::types::NodeStatus::Active),
-        validator_status
-            .nodes_with_status
-            .iter()
-            .find(|n| n.status == crate::types::NodeStatus::Standby),
+        best_standby.and_then(|idx| validator_status.nodes_with_status.get(idx)),
     ) {
         (Some(active), Some(standby)) => (active.clone(), standby.clone()),
         _ => {
@@ -3259,6 +3570,7 @@ async fn execute_emergency_failover(
     // Wait a moment for the UI to stop rendering and cleanup terminal
     tokio::time::sleep(Duration::from_millis(300)).await;
 
+    let identity_pubkey = validator_status.validator_pair.identity_pubkey.clone();
     let mut emergency_failover = crate::emergency_failover::EmergencyFailover::new(
         active_node,
         standby_node,
@@ -3266,10 +3578,18 @@ async fn execute_emergency_failover(
         ssh_pool,
         detected_ssh_keys,
         alert_manager,
-    );
-
-    if let Err(e) = emergency_failover.execute_emergency_takeover().await {
-        eprintln!("❌ Emergency failover error: {}", e);
+    )
+    .with_switch_config(switch_config);
+
+    match emergency_failover.execute_emergency_takeover().await {
+        // Only a takeover that promoted a standby uses up the failover budget;
+        // skipped or failed attempts left the pair as it was
+        Ok(()) => {
+            if let Some(alert_config) = alert_config.as_ref() {
+                crate::failover_budget::record_automatic_takeover(&identity_pubkey, alert_config);
+            }
+        }
+        Err(e) => eprintln!("❌ Emergency failover error: {}", e),
     }
 
     // Wait a moment for the user to see the results
@@ -3279,6 +3599,28 @@ async fn execute_emergency_failover(
     *emergency_takeover_flag.write().await = false;
 }
 
+/// Nodes the operator can pick as the new active node: every node except the active one
+fn switch_target_candidates(
+    validator_status: &crate::ValidatorStatus,
+) -> Vec<&crate::types::NodeWithStatus> {
+    validator_status
+        .nodes_with_status
+        .iter()
+        .filter(|n| n.status != crate::types::NodeStatus::Active)
+        .collect()
+}
+
+/// Label of the node picked in the switch view, or None for "best standby"
+fn selected_switch_target(ui_state: &UiState) -> Option<String> {
+    let validator_status = ui_state
+        .validator_statuses
+        .get(ui_state.selected_validator_index)?;
+    let selection = ui_state.switch_target_selection.checked_sub(1)?;
+    switch_target_candidates(validator_status)
+        .get(selection)
+        .map(|n| n.node.label.clone())
+}
+
 /// Draw the switch UI
 fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiState) {
     let chunks = Layout::default()
@@ -3301,44 +3643,57 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiSta
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(header, chunks[0]);
 
-    // Content area
-    let content_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(2)
-        .constraints([
-            Constraint::Length(10), // Status info
-            Constraint::Length(10), // Actions
-            Constraint::Min(0),     // Messages
-        ])
-        .split(chunks[1]);
-
     // Current status
-    if !app_state.validator_statuses.is_empty() {
-        let validator_status = &app_state.validator_statuses[ui_state.selected_validator_index];
+    if let Some(validator_status) = ui_state
+        .validator_statuses
+        .get(ui_state.selected_validator_index)
+    {
+        let candidates = switch_target_candidates(validator_status);
+        let picker_height = if candidates.len() > 1 {
+            candidates.len() as u16 + 3
+        } else {
+            0
+        };
+
+        // Content area
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([
+                Constraint::Length(validator_status.nodes_with_status.len() as u16 + 7), // Status info
+                Constraint::Length(picker_height), // Target picker
+                Constraint::Length(10),            // Actions
+                Constraint::Min(0),                // Messages
+            ])
+            .split(chunks[1]);
 
         let active_node = validator_status
             .nodes_with_status
             .iter()
             .find(|n| n.status == crate::types::NodeStatus::Active);
-        let standby_node = validator_status
-            .nodes_with_status
-            .iter()
-            .find(|n| n.status == crate::types::NodeStatus::Standby);
+        let selected_target = selected_switch_target(ui_state);
+        let target_label = match (&selected_target, candidates.as_slice()) {
+            (Some(label), _) => Some(label.clone()),
+            (None, [only]) => Some(only.node.label.clone()),
+            (None, _) => None,
+        };
 
         let mut status_text = vec![];
         status_text.push(
             Line::from("Current State:").style(Style::default().add_modifier(Modifier::BOLD)),
         );
 
-        if let (Some(active), Some(standby)) = (active_node, standby_node) {
+        if let (Some(active), false) = (active_node, candidates.is_empty()) {
             status_text.push(
                 Line::from(format!("  {} → ACTIVE", active.node.label))
                     .style(Style::default().fg(Color::Green)),
             );
-            status_text.push(
-                Line::from(format!("  {} → STANDBY", standby.node.label))
-                    .style(Style::default().fg(Color::Yellow)),
-            );
+            for standby in &candidates {
+                status_text.push(
+                    Line::from(format!("  {} → STANDBY", standby.node.label))
+                        .style(Style::default().fg(Color::Yellow)),
+                );
+            }
             status_text.push(Line::from(""));
             status_text.push(
                 Line::from("After Switch:").style(Style::default().add_modifier(Modifier::BOLD)),
@@ -3348,8 +3703,11 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiSta
                     .style(Style::default().fg(Color::Yellow)),
             );
             status_text.push(
-                Line::from(format!("  {} → ACTIVE (was standby)", standby.node.label))
-                    .style(Style::default().fg(Color::Green)),
+                Line::from(match &target_label {
+                    Some(label) => format!("  {} → ACTIVE (was standby)", label),
+                    None => "  best standby → ACTIVE (by health, slot lag, priority)".to_string(),
+                })
+                .style(Style::default().fg(Color::Green)),
             );
         } else {
             status_text.push(
@@ -3366,17 +3724,61 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiSta
         );
         f.render_widget(status_widget, content_chunks[0]);
 
+        // Target picker (only when there is more than one node to choose from)
+        if candidates.len() > 1 {
+            let option_style = |selected: bool| {
+                if selected {
+                    Style::default().fg(Color::Black).bg(Color::Cyan)
+                } else {
+                    Style::default()
+                }
+            };
+            let mut picker_text = vec![Line::from("  Auto - best standby (health, slot lag, priority)")
+                .style(option_style(ui_state.switch_target_selection == 0))];
+            for (idx, node) in candidates.iter().enumerate() {
+                let readiness = match node.swap_ready {
+                    Some(true) => "✅ swap ready".to_string(),
+                    Some(false) => format!("⚠️  {}", node.swap_issues.join(", ")),
+                    None => "swap readiness unknown".to_string(),
+                };
+                picker_text.push(
+                    Line::from(format!("  {} - {}", node.node.label, readiness))
+                        .style(option_style(ui_state.switch_target_selection == idx + 1)),
+                );
+            }
+
+            let picker_widget = Paragraph::new(picker_text).block(
+                Block::default()
+                    .title(" New Active Node (↑/↓) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            );
+            f.render_widget(picker_widget, content_chunks[1]);
+        }
+
         // Actions that will be performed
-        let actions_text = vec![
-            Line::from("Actions that will be performed:")
-                .style(Style::default().add_modifier(Modifier::BOLD)),
+        let mut actions_text = vec![Line::from("Actions that will be performed:")
+            .style(Style::default().add_modifier(Modifier::BOLD))];
+        let min_leader_gap = app_state
+            .config
+            .switch_config
+            .as_ref()
+            .map(|c| c.min_leader_gap_slots)
+            .unwrap_or(0);
+        if min_leader_gap > 0 {
+            actions_text.push(Line::from(format!(
+                "  0. Wait for ≥{} slots before our next leader slot",
+                min_leader_gap
+            )));
+        }
+        actions_text.extend([
             Line::from("  1. Switch active node to unfunded identity"),
             Line::from("  2. Delete tower file on standby node"),
             Line::from("  3. Switch standby node to funded identity"),
             Line::from(""),
             Line::from("[!] Press 'y' to confirm switch or 'q' to cancel")
                 .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-        ];
+        ]);
 
         let actions_widget = Paragraph::new(actions_text).block(
             Block::default()
@@ -3384,11 +3786,73 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiSta
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Red)),
         );
-        f.render_widget(actions_widget, content_chunks[1]);
+        f.render_widget(actions_widget, content_chunks[2]);
     }
 
     // Footer
-    let footer = Paragraph::new("Press 'y' to confirm switch | Press 'q' to cancel")
+    let footer = Paragraph::new("↑/↓ to pick the new active node | Press 'y' to confirm switch | Press 'q' to cancel")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[2]);
+}
+
+fn draw_demote_ui(f: &mut ratatui::Frame, ui_state: &UiState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.size());
+
+    let header = Paragraph::new("🛑 EMERGENCY DEMOTION")
+        .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(header, chunks[0]);
+
+    if let Some(validator_status) = ui_state
+        .validator_statuses
+        .get(ui_state.selected_validator_index)
+    {
+        let mut text = vec![
+            Line::from(format!(
+                "Validator: {}",
+                validator_status.validator_pair.identity_pubkey
+            ))
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+            Line::from(""),
+        ];
+        for node in &validator_status.nodes_with_status {
+            text.push(Line::from(format!(
+                "  {} ({}) → UNFUNDED",
+                node.node.label, node.node.host
+            )));
+        }
+        text.extend([
+            Line::from(""),
+            Line::from("No node is promoted: the validator stops voting until the next switch"),
+            Line::from("Auto-failover stays locked out until `svs failover reset`"),
+            Line::from(""),
+            Line::from("[!] Press 'y' to demote every node or 'q' to cancel")
+                .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+        ]);
+
+        let widget = Paragraph::new(text).block(
+            Block::default()
+                .title(" Demote All Nodes ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        );
+        let area = Layout::default()
+            .margin(2)
+            .constraints([Constraint::Min(0)])
+            .split(chunks[1]);
+        f.render_widget(widget, area[0]);
+    }
+
+    let footer = Paragraph::new("Press 'y' to demote every node | Press 'q' to cancel")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
     f.render_widget(footer, chunks[2]);
@@ -3463,6 +3927,205 @@ fn shorten_path(path: &str, max_len: usize) -> String {
     }
 }
 
+/// Poll the alert chat for `/demote <validator>` and demote every node of
+/// that validator. Commands sent before svs started are skipped.
+async fn handle_telegram_commands(
+    app_state: Arc<AppState>,
+    alert_manager: AlertManager,
+    log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
+) {
+    let log = |message: String, level: LogLevel| {
+        let _ = log_sender.send(LogMessage {
+            host: "telegram".to_string(),
+            message,
+            timestamp: Instant::now(),
+            level,
+        });
+    };
+
+    let mut offset = 0;
+    while let Err(e) = alert_manager.fetch_telegram_commands(&mut offset, 0).await {
+        log(
+            format!("Telegram command polling failed: {}", e),
+            LogLevel::Warning,
+        );
+        tokio::time::sleep(Duration::from_secs(30)).await;
+    }
+
+    loop {
+        let texts = match alert_manager.fetch_telegram_commands(&mut offset, 30).await {
+            Ok(texts) => texts,
+            Err(e) => {
+                log(
+                    format!("Telegram command polling failed: {}", e),
+                    LogLevel::Warning,
+                );
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                continue;
+            }
+        };
+
+        for text in texts {
+            let Some(validator_arg) = crate::commands::demote::parse_demote_command(&text) else {
+                continue;
+            };
+            let mut selection = (*app_state).clone();
+            let reply = match validator_arg
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Usage: /demote <validator index or identity prefix>")
+                })
+                .and_then(|arg| selection.select_validator_from_arg(arg))
+            {
+                Ok(()) => {
+                    let validator_status =
+                        &selection.validator_statuses[selection.selected_validator_index];
+                    log(
+                        format!(
+                            "Telegram /demote: demoting every node of {}",
+                            validator_status.validator_pair.identity_pubkey
+                        ),
+                        LogLevel::Warning,
+                    );
+                    let demotion = crate::commands::demote::demote_all_nodes(
+                        &selection.ssh_pool,
+                        &selection.detected_ssh_keys,
+                        validator_status,
+                    )
+                    .await;
+                    crate::commands::demote::send_demotion_alert(
+                        &selection,
+                        validator_status,
+                        &demotion,
+                        "Telegram /demote",
+                    )
+                    .await
+                }
+                Err(e) => alert_manager.send_command_reply(&e.to_string()).await,
+            };
+            if let Err(e) = reply {
+                log(format!("Failed to answer /demote: {}", e), LogLevel::Error);
+            }
+        }
+    }
+}
+
+/// One double-signing guard tick over every validator pair
+async fn check_double_signing(
+    app_state: &Arc<AppState>,
+    ui_state: &Arc<RwLock<UiState>>,
+    log_sender: &tokio::sync::mpsc::UnboundedSender<LogMessage>,
+    guards: &mut Vec<crate::double_sign_guard::DoubleSignGuard>,
+) {
+    let (validator_statuses, vote_quorums) = {
+        let state = ui_state.read().await;
+        (state.validator_statuses.clone(), state.vote_quorums.clone())
+    };
+    guards.resize_with(validator_statuses.len(), Default::default);
+    let alert_config = app_state.config.alert_config.as_ref();
+    let auto_demote = alert_config.is_some_and(|config| config.double_sign_auto_demote);
+
+    for (idx, (validator_status, guard)) in
+        validator_statuses.iter().zip(guards.iter_mut()).enumerate()
+    {
+        let nodes = &validator_status.nodes_with_status;
+        if nodes.len() < 2 {
+            continue;
+        }
+        let funded = crate::double_sign_guard::funded_nodes(
+            &app_state.ssh_pool,
+            &app_state.detected_ssh_keys,
+            &validator_status.validator_pair,
+            nodes,
+        )
+        .await;
+        let Some(incident) = guard.observe(&funded) else {
+            continue;
+        };
+
+        let _ = log_sender.send(LogMessage {
+            host: incident.funded_nodes.join(", "),
+            message: format!(
+                "DOUBLE-SIGNING RISK: {} all report the funded identity {}",
+                incident.funded_nodes.join(", "),
+                validator_status.validator_pair.identity_pubkey
+            ),
+            timestamp: Instant::now(),
+            level: LogLevel::Error,
+        });
+
+        let mut demotions = Vec::new();
+        if auto_demote {
+            let funded_nodes: Vec<&crate::types::NodeWithStatus> = nodes
+                .iter()
+                .filter(|node| incident.funded_nodes.contains(&node.node.label))
+                .collect();
+            // Only a vote account that is still advancing shows a node is landing votes
+            let votes_landing = match (alert_config, vote_quorums.get(idx)) {
+                (Some(config), Some(quorum)) => {
+                    quorum.agrees_voting(config.delinquency_threshold_seconds, Instant::now())
+                }
+                _ => false,
+            };
+            let healthy = if votes_landing {
+                crate::double_sign_guard::healthy_nodes(
+                    &app_state.ssh_pool,
+                    &app_state.detected_ssh_keys,
+                    &funded_nodes,
+                )
+                .await
+            } else {
+                Vec::new()
+            };
+            let targets = incident.demotion_targets(&healthy, votes_landing);
+            if targets.is_empty() {
+                let _ = log_sender.send(LogMessage {
+                    host: incident.funded_nodes.join(", "),
+                    message: "Double-signing guard cannot tell which node is landing votes - demoting nobody".to_string(),
+                    timestamp: Instant::now(),
+                    level: LogLevel::Error,
+                });
+            }
+            for label in targets {
+                let Some(node) = funded_nodes.iter().find(|node| node.node.label == label) else {
+                    continue;
+                };
+                let result = crate::commands::switch::demote_to_unfunded(
+                    &app_state.ssh_pool,
+                    &app_state.detected_ssh_keys,
+                    node,
+                )
+                .await
+                .map_err(|e| e.to_string());
+                let _ = log_sender.send(LogMessage {
+                    host: label.clone(),
+                    message: match &result {
+                        Ok(()) => "Double-signing guard demoted this node to its unfunded identity"
+                            .to_string(),
+                        Err(e) => format!("Double-signing guard could not demote this node: {}", e),
+                    },
+                    timestamp: Instant::now(),
+                    level: if result.is_ok() {
+                        LogLevel::Warning
+                    } else {
+                        LogLevel::Error
+                    },
+                });
+                demotions.push((label, result));
+            }
+        }
+
+        if let Some(config) = alert_config.filter(|config| config.enabled) {
+            let _ = AlertManager::new(config.clone())
+                .send_double_signing_alert(
+                    &validator_status.validator_pair.identity_pubkey,
+                    &incident.funded_nodes,
+                    &demotions,
+                )
+                .await;
+        }
+    }
+}
+
 /// Refresh all fields for all validators
 async fn refresh_all_fields(
     app_state: Arc<AppState>,
@@ -3685,13 +4348,11 @@ async fn refresh_ssh_connectivity(
 
     // Update UI state
     let mut state = ui_state.write().await;
-    if let Some(ssh_data) = state.ssh_health_data.get_mut(validator_idx) {
-        let ssh_status = if node_idx == 0 {
-            &mut ssh_data.node_0
-        } else {
-            &mut ssh_data.node_1
-        };
-
+    if let Some(ssh_status) = state
+        .ssh_health_data
+        .get_mut(validator_idx)
+        .and_then(|ssh_data| ssh_data.nodes.get_mut(node_idx))
+    {
         ssh_status.is_healthy = is_healthy;
         if is_healthy {
             ssh_status.last_success = Some(Instant::now());
@@ -3703,12 +4364,12 @@ async fn refresh_ssh_connectivity(
     }
 
     // Clear the refresh flag
-    if let Some(refresh_state) = state.field_refresh_states.get_mut(validator_idx) {
-        if node_idx == 0 {
-            refresh_state.node_0.ssh_connectivity_refreshing = false;
-        } else {
-            refresh_state.node_1.ssh_connectivity_refreshing = false;
-        }
+    if let Some(refresh_state) = state
+        .field_refresh_states
+        .get_mut(validator_idx)
+        .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+    {
+        refresh_state.ssh_connectivity_refreshing = false;
     }
 
     // Update refresh timestamp
@@ -3755,12 +4416,11 @@ async fn refresh_rpc_health(
 
         {
             let mut st = ui_state.write().await;
-            if let Some(pair) = st.rpc_health_data.get_mut(validator_idx) {
-                let rpc_status = if node_idx == 0 {
-                    &mut pair.node_0
-                } else {
-                    &mut pair.node_1
-                };
+            if let Some(rpc_status) = st
+                .rpc_health_data
+                .get_mut(validator_idx)
+                .and_then(|pair| pair.nodes.get_mut(node_idx))
+            {
                 match cluster_is_voting {
                     Some(true) => {
                         rpc_status.is_healthy = true;
@@ -3785,12 +4445,11 @@ async fn refresh_rpc_health(
                 rpc_status.last_check = Some(Instant::now());
             }
 
-            if let Some(refresh_state) = st.field_refresh_states.get_mut(validator_idx) {
-                let target = if node_idx == 0 {
-                    &mut refresh_state.node_0
-                } else {
-                    &mut refresh_state.node_1
-                };
+            if let Some(target) = st
+                .field_refresh_states
+                .get_mut(validator_idx)
+                .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+            {
                 target.rpc_health_refreshing = false;
             }
         }
@@ -3810,13 +4469,11 @@ async fn refresh_rpc_health(
 
     // Update UI state
     let mut state = ui_state.write().await;
-    if let Some(rpc_data) = state.rpc_health_data.get_mut(validator_idx) {
-        let rpc_status = if node_idx == 0 {
-            &mut rpc_data.node_0
-        } else {
-            &mut rpc_data.node_1
-        };
-
+    if let Some(rpc_status) = state
+        .rpc_health_data
+        .get_mut(validator_idx)
+        .and_then(|rpc_data| rpc_data.nodes.get_mut(node_idx))
+    {
         rpc_status.is_healthy = is_healthy;
         rpc_status.last_check = Some(Instant::now());
         rpc_status.error_message = error_msg.clone();
@@ -3851,16 +4508,17 @@ async fn refresh_rpc_health(
     });
 
     // Clear the refresh flag
-    if let Some(refresh_state) = state.field_refresh_states.get_mut(validator_idx) {
-        if node_idx == 0 {
-            refresh_state.node_0.rpc_health_refreshing = false;
-        } else {
-            refresh_state.node_1.rpc_health_refreshing = false;
-        }
+    if let Some(refresh_state) = state
+        .field_refresh_states
+        .get_mut(validator_idx)
+        .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+    {
+        refresh_state.rpc_health_refreshing = false;
     }
 }
 
 /// Refresh node status and identity
+#[allow(clippy::too_many_arguments)]
 async fn refresh_node_status_and_identity(
     validator_idx: usize,
     node_idx: usize,
@@ -4213,12 +4871,11 @@ async fn refresh_node_status_and_identity(
         }
 
         // Clear refreshing flags
-        if let Some(refresh_state) = ui_state_write.field_refresh_states.get_mut(validator_idx) {
-            let field_state = if node_idx == 0 {
-                &mut refresh_state.node_0
-            } else {
-                &mut refresh_state.node_1
-            };
+        if let Some(field_state) = ui_state_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
             field_state.status_refreshing = false;
             field_state.identity_refreshing = false;
         }
@@ -4369,12 +5026,11 @@ async fn refresh_node_version(
         }
 
         // Clear refreshing flag
-        if let Some(refresh_state) = ui_state_write.field_refresh_states.get_mut(validator_idx) {
-            let field_state = if node_idx == 0 {
-                &mut refresh_state.node_0
-            } else {
-                &mut refresh_state.node_1
-            };
+        if let Some(field_state) = ui_state_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
             field_state.version_refreshing = false;
         }
     }
@@ -4438,16 +5094,12 @@ async fn refresh_swap_readiness(
     // Set refreshing state
     {
         let mut ui_write = ui_state.write().await;
-        if validator_idx < ui_write.field_refresh_states.len() {
-            if node_idx == 0 {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_0
-                    .swap_readiness_refreshing = true;
-            } else {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_1
-                    .swap_readiness_refreshing = true;
-            }
+        if let Some(field_state) = ui_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
+            field_state.swap_readiness_refreshing = true;
         }
     }
 
@@ -4492,16 +5144,12 @@ async fn refresh_swap_readiness(
     // Clear refreshing state
     {
         let mut ui_write = ui_state.write().await;
-        if validator_idx < ui_write.field_refresh_states.len() {
-            if node_idx == 0 {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_0
-                    .swap_readiness_refreshing = false;
-            } else {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_1
-                    .swap_readiness_refreshing = false;
-            }
+        if let Some(field_state) = ui_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
+            field_state.swap_readiness_refreshing = false;
         }
     }
 }
@@ -4523,6 +5171,38 @@ pub async fn show_enhanced_status_ui(app_state: &AppState) -> Result<()> {
         let mut app = EnhancedStatusApp::new(app_state_arc.clone()).await?;
         let switch_confirmed = run_enhanced_ui(&mut app).await?;
 
+        let scheduled = app.scheduled_switch.write().await.take();
+        if let Some(scheduled) = scheduled {
+            println!("\n🗓️  Running scheduled switch {}", scheduled.describe());
+            match crate::commands::schedule::execute_scheduled_switch(
+                &mut current_app_state,
+                scheduled,
+            )
+            .await
+            {
+                Ok(true) => println!("\n✅ Scheduled switch completed"),
+                Ok(false) => println!("\n⏭️  Scheduled switch skipped"),
+                Err(e) => println!("\n❌ Scheduled switch failed: {}", e),
+            }
+            println!("📊 Returning to validator status view...\n");
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            continue;
+        }
+
+        if *app.demote_confirmed.read().await {
+            if let Ok(ui_state_guard) = app.ui_state.try_read() {
+                current_app_state.selected_validator_index =
+                    ui_state_guard.selected_validator_index;
+            }
+            if let Err(e) = crate::commands::demote::demote_command(&current_app_state, true).await
+            {
+                println!("\n❌ Demotion incomplete: {}", e);
+            }
+            println!("📊 Returning to validator status view...\n");
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            continue;
+        }
+
         if !switch_confirmed {
             // User quit without requesting a switch - exit the loop
             break;
@@ -4533,6 +5213,7 @@ pub async fn show_enhanced_status_ui(app_state: &AppState) -> Result<()> {
         // This ensures we switch the validator the user was viewing, not the default
         if let Ok(ui_state_guard) = app.ui_state.try_read() {
             current_app_state.selected_validator_index = ui_state_guard.selected_validator_index;
+            current_app_state.switch_target_label = selected_switch_target(&ui_state_guard);
         }
 
         let result = crate::commands::switch::switch_command_with_confirmation(
@@ -5088,6 +5769,11 @@ mod vote_account_poll_interval_tests {
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         }
     }
 
@@ -1,10 +1,20 @@
-use crate::commands::error_handler::ProgressSpinner;
+use crate::commands::error_handler::{
+    classify_step_error, find_switch_error, ProgressSpinner, SwitchError,
+};
+use crate::pipelined_demotion::{
+    is_demotion_exit_line, pipelined_demotion_script, PipelinedDemotion,
+};
 use crate::ssh::AsyncSshPool;
-use crate::types::NodeConfig;
+use crate::switch_journal::{SwitchJournal, SwitchStep};
+use crate::switch_preflight::PreflightCheck;
+use crate::switch_strategy::{identity_command, resolve_strategy, IdentityRole, StrategyCommand};
+use crate::types::{HookPoint, NodeConfig};
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose, Engine as _};
 use colored::*;
+use serde::Serialize;
 use sha2::{Digest, Sha256};
+use solana_sdk::commitment_config::CommitmentConfig;
 use std::io::Write;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -70,11 +80,116 @@ pub async fn switch_command_with_confirmation(
     app_state: &mut crate::AppState,
     require_confirmation: bool,
 ) -> Result<bool> {
+    run_switch(
+        dry_run,
+        app_state,
+        require_confirmation,
+        false,
+        &mut SwitchReport::default(),
+    )
+    .await
+}
+
+/// Step timings in milliseconds, as reported by `--output json`
+#[derive(Debug, Default, Serialize)]
+pub struct SwitchTimings {
+    pub active_switch_time: Option<u128>,
+    pub tower_transfer_time: Option<u128>,
+    pub standby_switch_time: Option<u128>,
+    pub offline_window_time: Option<u128>,
+    pub identity_switch_time: Option<u128>,
+    pub first_vote_time: Option<u128>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NodeState {
+    pub label: String,
+    pub host: String,
+    pub status: String,
+}
+
+/// Machine-readable outcome of a switch, printed by `svs switch --output json`
+#[derive(Debug, Default, Serialize)]
+pub struct SwitchReport {
+    pub success: bool,
+    pub dry_run: bool,
+    pub validator: Option<String>,
+    pub from_node: Option<String>,
+    pub to_node: Option<String>,
+    pub timings_ms: SwitchTimings,
+    pub identities_swapped: bool,
+    pub failed_back: bool,
+    pub final_state: Vec<NodeState>,
+    /// Dry-run preflight checklist
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub preflight: Vec<PreflightCheck>,
+    pub error: Option<String>,
+    pub exit_code: i32,
+}
+
+impl SwitchReport {
+    /// Record the result and the node states the switch left behind
+    pub fn finish(&mut self, app_state: &crate::AppState, result: &Result<bool>) {
+        self.success = result.is_ok();
+        self.error = result.as_ref().err().map(|e| e.to_string());
+        self.exit_code = result
+            .as_ref()
+            .err()
+            .map(crate::commands::error_handler::exit_code_for)
+            .unwrap_or(0);
+
+        if let Some(validator_status) = app_state
+            .validator_statuses
+            .get(app_state.selected_validator_index)
+        {
+            self.validator = Some(validator_status.validator_pair.identity_pubkey.clone());
+            self.final_state = validator_status
+                .nodes_with_status
+                .iter()
+                .map(|n| NodeState {
+                    label: n.node.label.clone(),
+                    host: n.node.host.clone(),
+                    status: crate::switch_plan::status_name(&n.status).to_string(),
+                })
+                .collect();
+        }
+    }
+
+    fn record_timings(&mut self, switch_manager: &SwitchManager) {
+        let millis = |time: Option<Duration>| time.map(|t| t.as_millis());
+        self.timings_ms = SwitchTimings {
+            active_switch_time: millis(switch_manager.active_switch_time),
+            tower_transfer_time: millis(switch_manager.tower_transfer_time),
+            standby_switch_time: millis(switch_manager.standby_switch_time),
+            offline_window_time: millis(switch_manager.offline_window_time),
+            identity_switch_time: millis(switch_manager.identity_switch_time),
+            first_vote_time: millis(switch_manager.first_vote_time),
+        };
+        self.identities_swapped = switch_manager.identities_swapped();
+        self.failed_back = switch_manager.failed_back;
+        self.preflight = switch_manager.preflight.clone();
+    }
+}
+
+/// Run a switch, filling `report` as it goes. `non_interactive` skips the
+/// "press any key" pause after a completed switch (`svs switch --yes`).
+pub async fn run_switch(
+    dry_run: bool,
+    app_state: &mut crate::AppState,
+    require_confirmation: bool,
+    non_interactive: bool,
+    report: &mut SwitchReport,
+) -> Result<bool> {
+    report.dry_run = dry_run;
+
     // Validate we have at least one validator configured
     if app_state.config.validators.is_empty() {
         return Err(anyhow!("No validators configured"));
     }
 
+    // A --to target (or TUI pick) applies to this switch only
+    let target_label = app_state.switch_target_label.take();
+
     // Use the selected validator
     let validator_status = &app_state.validator_statuses[app_state.selected_validator_index];
     let validator_pair = &validator_status.validator_pair;
@@ -91,136 +206,10 @@ pub async fn switch_command_with_confirmation(
         return Ok(false);
     }
 
-    // Find active and standby nodes with full status information
-    let active_node_with_status = validator_status
-        .nodes_with_status
-        .iter()
-        .find(|n| n.status == crate::types::NodeStatus::Active);
-    let standby_node_with_status = validator_status
-        .nodes_with_status
-        .iter()
-        .find(|n| n.status == crate::types::NodeStatus::Standby);
-
     let (active_node_with_status, standby_node_with_status) =
-        match (active_node_with_status, standby_node_with_status) {
-            (Some(active), Some(standby)) => (active, standby),
-            _ => {
-                // Handle special case: both nodes are standby or both unknown
-                let standby_nodes: Vec<_> = validator_status
-                    .nodes_with_status
-                    .iter()
-                    .filter(|n| n.status == crate::types::NodeStatus::Standby)
-                    .collect();
-
-                let unknown_nodes: Vec<_> = validator_status
-                    .nodes_with_status
-                    .iter()
-                    .filter(|n| n.status == crate::types::NodeStatus::Unknown)
-                    .collect();
-
-                if standby_nodes.len() == 2 {
-                    println_if_not_silent!(
-                        "\n{}",
-                        "⚠️  Both nodes are in STANDBY state - Recovery Mode"
-                            .yellow()
-                            .bold()
-                    );
-
-                    // In recovery mode, try to identify which node has a tower file
-                    // The node with a tower file should be the "source" (assigned to active_node_with_status)
-                    let node0_has_tower =
-                        validator_status.nodes_with_status[0].tower_path.is_some();
-                    let node1_has_tower =
-                        validator_status.nodes_with_status[1].tower_path.is_some();
-
-                    let (source_idx, target_idx) = match (node0_has_tower, node1_has_tower) {
-                        (true, false) => {
-                            // Node 0 has tower, use it as source
-                            println_if_not_silent!(
-                                "   Tower file found on {} - using as source",
-                                validator_status.nodes_with_status[0].node.label
-                            );
-                            (0, 1)
-                        }
-                        (false, true) => {
-                            // Node 1 has tower, use it as source
-                            println_if_not_silent!(
-                                "   Tower file found on {} - using as source",
-                                validator_status.nodes_with_status[1].node.label
-                            );
-                            (1, 0)
-                        }
-                        (true, true) => {
-                            // Both have tower files - use node[0] as source (default)
-                            println_if_not_silent!(
-                                "   Both nodes have tower files - using {} as source",
-                                validator_status.nodes_with_status[0].node.label
-                            );
-                            (0, 1)
-                        }
-                        (false, false) => {
-                            // Neither has a detected tower file - this is risky
-                            println_if_not_silent!(
-                                "{}",
-                                "   ⚠️  WARNING: No tower file detected on either node!"
-                                    .bright_red()
-                            );
-                            println_if_not_silent!(
-                                "   Using {} as source (may fail if tower doesn't exist)",
-                                validator_status.nodes_with_status[0].node.label
-                            );
-                            (0, 1)
-                        }
-                    };
-
-                    println_if_not_silent!(
-                        "Will activate {} and keep {} as standby",
-                        validator_status.nodes_with_status[target_idx].node.label,
-                        validator_status.nodes_with_status[source_idx].node.label
-                    );
-
-                    (
-                        &validator_status.nodes_with_status[source_idx], // Source: has tower, will be demoted
-                        &validator_status.nodes_with_status[target_idx], // Target: will receive tower and become active
-                    )
-                } else if unknown_nodes.len() == 2 {
-                    // Both nodes have Unknown status - RPC likely down on both
-                    println_if_not_silent!(
-                        "\n{}",
-                        "⚠️  Both nodes have UNKNOWN status - RPC may be down"
-                            .yellow()
-                            .bold()
-                    );
-                    println_if_not_silent!(
-                        "{}",
-                        "   Cannot safely determine which node is active!".bright_red()
-                    );
-                    println_if_not_silent!(
-                        "   Please verify node status manually before proceeding."
-                    );
-                    return Err(anyhow!(
-                        "Cannot switch: Both nodes have Unknown status. \
-                        Verify RPC health and node status before attempting switch."
-                    ));
-                } else {
-                    // Fallback: use first two nodes if we can't determine status
-                    if validator_status.nodes_with_status.len() < 2 {
-                        return Err(anyhow!(
-                            "Validator must have at least 2 nodes configured for switching"
-                        ));
-                    }
-                    println_if_not_silent!(
-                        "\n{}",
-                        "⚠️  Cannot determine Active/Standby status - using default node order"
-                            .yellow()
-                    );
-                    (
-                        &validator_status.nodes_with_status[0],
-                        &validator_status.nodes_with_status[1],
-                    )
-                }
-            }
-        };
+        resolve_switch_nodes(app_state, validator_status, target_label.as_deref()).await?;
+    report.from_node = Some(active_node_with_status.node.label.clone());
+    report.to_node = Some(standby_node_with_status.node.label.clone());
 
     println_if_not_silent!(
         "\n{}",
@@ -240,124 +229,19 @@ pub async fn switch_command_with_confirmation(
         );
         println_if_not_silent!(
             "{}",
-            "ℹ️  Tower file transfer will be performed to measure timing".yellow()
-        );
-        println_if_not_silent!();
-    }
-
-    // Targeted validation: Check only what's needed for this specific switch
-    let mut validation_errors = Vec::new();
-    let mut validation_warnings = Vec::new();
-
-    // Check target (standby) node - this is critical for switch success
-    if standby_node_with_status.status == crate::types::NodeStatus::Unknown {
-        validation_errors.push(format!(
-            "Target node {} is unreachable (SSH connection failed)",
-            standby_node_with_status.node.label
-        ));
-    } else {
-        // Since we skip swap readiness checks at startup, we need to check now
-        // For standby nodes, we check all requirements except tower file
-        println_if_not_silent!("🔍 Checking target node swap readiness...");
-
-        if let Some(ssh_key) = app_state
-            .detected_ssh_keys
-            .get(&standby_node_with_status.node.host)
-        {
-            let (is_ready, issues) = crate::startup::check_node_swap_readiness(
-                &app_state.ssh_pool,
-                &standby_node_with_status.node,
-                ssh_key,
-                standby_node_with_status.ledger_path.as_ref(),
-                Some(true), // is_standby = true, skip tower check
-            )
-            .await;
-
-            if !is_ready {
-                validation_errors.push(format!(
-                    "Target node {} is not swap-ready: {}",
-                    standby_node_with_status.node.label,
-                    issues.join(", ")
-                ));
-            }
-        }
-    }
-
-    // Check if we can get SSH key for target node
-    if !app_state
-        .detected_ssh_keys
-        .contains_key(&standby_node_with_status.node.host)
-    {
-        validation_errors.push(format!(
-            "No SSH key available for target node {}",
-            standby_node_with_status.node.label
-        ));
-    }
-
-    // Check source (active) node - this is preferred but not critical (emergency scenarios)
-    if active_node_with_status.status == crate::types::NodeStatus::Unknown {
-        validation_warnings.push(format!(
-            "Source node {} is unreachable - will skip optional steps (tower copy, graceful shutdown)",
-            active_node_with_status.node.label
-        ));
-    }
-    // Skip detailed swap readiness check for source node - not critical for switch
-
-    // Check if we can get SSH key for source node
-    if !app_state
-        .detected_ssh_keys
-        .contains_key(&active_node_with_status.node.host)
-    {
-        validation_warnings.push(format!(
-            "No SSH key available for source node {} - will skip optional steps",
-            active_node_with_status.node.label
-        ));
-    }
-
-    // Show validation results
-    if !validation_errors.is_empty() {
-        println_if_not_silent!("\n{}", "❌ SWITCH VALIDATION FAILED".red().bold());
-        println_if_not_silent!("\nCritical issues that prevent switching:\n");
-        for error in &validation_errors {
-            println_if_not_silent!("  • {}", error.red());
-        }
-        println_if_not_silent!(
-            "\n{}",
-            "Please resolve these issues before attempting to switch.".yellow()
+            "ℹ️  Read-only preflight checks will probe both nodes".yellow()
         );
-        return Err(anyhow::anyhow!(
-            "Switch validation failed: {} critical issue(s)",
-            validation_errors.len()
-        ));
-    }
-
-    if !validation_warnings.is_empty() {
-        println_if_not_silent!("\n{}", "⚠️  SWITCH WARNINGS".yellow().bold());
-        println_if_not_silent!("\nNon-critical issues (switch will continue with limitations):\n");
-        for warning in &validation_warnings {
-            println_if_not_silent!("  • {}", warning.yellow());
-        }
-
-        if require_confirmation && !dry_run {
-            println_if_not_silent!(
-                "\n{}",
-                "Do you want to continue with the switch despite these warnings?".bright_yellow()
-            );
-
-            // Actually wait for ANY key press, not just Enter
-            use crossterm::event::{self, Event};
-            crossterm::terminal::enable_raw_mode().ok();
-            loop {
-                if let Ok(Event::Key(_)) = event::read() {
-                    break;
-                }
-            }
-            crossterm::terminal::disable_raw_mode().ok();
-        }
         println_if_not_silent!();
     }
 
-    println_if_not_silent!("✅ Switch validation passed - proceeding with operation\n");
+    validate_switch_nodes(
+        app_state,
+        active_node_with_status,
+        standby_node_with_status,
+        dry_run,
+        require_confirmation,
+    )
+    .await?;
 
     let mut switch_manager = SwitchManager::new(
         active_node_with_status.clone(),
@@ -365,7 +249,10 @@ pub async fn switch_command_with_confirmation(
         validator_pair.clone(),
         app_state.ssh_pool.clone(),
         app_state.detected_ssh_keys.clone(),
-    );
+    )
+    .with_switch_config(app_state.config.switch_config.clone().unwrap_or_default());
+    let from_label = active_node_with_status.node.label.clone();
+    let to_label = standby_node_with_status.node.label.clone();
 
     // Pre-warm SSH connections to both nodes for faster switching
     if !dry_run {
@@ -385,12 +272,17 @@ pub async fn switch_command_with_confirmation(
         {
             let pool = app_state.ssh_pool.clone();
             // Trigger connection creation for both nodes
-            let _ = pool
-                .get_session(&active_node_with_status.node, active_ssh_key)
-                .await?;
-            let _ = pool
-                .get_session(&standby_node_with_status.node, standby_ssh_key)
-                .await?;
+            for (node, ssh_key) in [
+                (&active_node_with_status.node, active_ssh_key),
+                (&standby_node_with_status.node, standby_ssh_key),
+            ] {
+                pool.get_session(node, ssh_key).await.map_err(|e| {
+                    SwitchError::SshConnectionFailed {
+                        host: node.host.clone(),
+                        details: e.to_string(),
+                    }
+                })?;
+            }
         }
 
         spinner.stop_with_message("✅ SSH connections ready");
@@ -400,6 +292,16 @@ pub async fn switch_command_with_confirmation(
     let switch_result = switch_manager
         .execute_switch(dry_run, require_confirmation)
         .await;
+    report.record_timings(&switch_manager);
+
+    // Let on_failure hooks page or revert external changes (DNS, firewall, ...)
+    if !dry_run {
+        if let Err(e) = &switch_result {
+            let _ = switch_manager
+                .run_switch_hooks(HookPoint::OnFailure, false, Some(e.to_string()))
+                .await;
+        }
+    }
 
     // Send Telegram notification for switch result (only for live switches)
     if !dry_run {
@@ -430,11 +332,36 @@ pub async fn switch_command_with_confirmation(
                             Some(&e.to_string()),
                         )
                         .await;
+
+                    if let Some(detail) = &switch_manager.vote_landing_failure {
+                        let _ = alert_manager
+                            .send_vote_landing_timeout_alert(
+                                &switch_manager.validator_pair.identity_pubkey,
+                                &standby_node_with_status.node.label,
+                                switch_manager.switch_config.vote_landing_timeout_seconds,
+                                detail,
+                            )
+                            .await;
+                    }
                 }
             }
         }
     }
 
+    // Any switch that moved the identity starts the auto-failover dwell time
+    if !dry_run && switch_manager.identities_swapped() {
+        crate::failover_budget::record_completed_switch(
+            &app_state.validator_statuses[app_state.selected_validator_index]
+                .validator_pair
+                .identity_pubkey,
+        );
+    }
+
+    // A failed post-switch verification still leaves the identities swapped
+    if switch_result.is_err() && switch_manager.identities_swapped() {
+        swap_active_standby_status(app_state, &from_label, &to_label);
+    }
+
     // Re-check the result and propagate any error
     let show_status = switch_result?;
 
@@ -472,6 +399,12 @@ pub async fn switch_command_with_confirmation(
                     format!("{}ms", standby_time.as_millis()).bright_yellow()
                 );
             }
+            if let Some(first_vote_time) = switch_manager.first_vote_time {
+                println_if_not_silent!(
+                    "   Step 5 - First landed vote:  {}",
+                    format!("{}ms", first_vote_time.as_millis()).bright_yellow()
+                );
+            }
         } else {
             println_if_not_silent!(
                 "\n{}",
@@ -484,66 +417,739 @@ pub async fn switch_command_with_confirmation(
         // Update the node statuses in app_state to reflect the switch
         // Note: Always update state after successful switch, regardless of show_status
         // This ensures UI state stays in sync even when called from auto-failover
-        if !dry_run && !app_state.validator_statuses.is_empty() {
-            // Find the indices of active and standby nodes
-            let mut active_idx = None;
-            let mut standby_idx = None;
-
-            for (idx, node_with_status) in app_state.validator_statuses
-                [app_state.selected_validator_index]
-                .nodes_with_status
-                .iter()
-                .enumerate()
-            {
-                match node_with_status.status {
-                    crate::types::NodeStatus::Active => active_idx = Some(idx),
-                    crate::types::NodeStatus::Standby => standby_idx = Some(idx),
-                    _ => {}
-                }
-            }
-
-            // Swap the statuses
-            if let (Some(active), Some(standby)) = (active_idx, standby_idx) {
-                app_state.validator_statuses[app_state.selected_validator_index]
-                    .nodes_with_status[active]
-                    .status = crate::types::NodeStatus::Standby;
-                app_state.validator_statuses[app_state.selected_validator_index]
-                    .nodes_with_status[standby]
-                    .status = crate::types::NodeStatus::Active;
-            }
+        if !dry_run {
+            swap_active_standby_status(app_state, &from_label, &to_label);
         }
 
-        println_if_not_silent!();
-        println_if_not_silent!("{}", "Press any key to view status...".dimmed());
-        if !is_silent_mode() {
-            // Actually wait for ANY key press, not just Enter
-            use crossterm::event::{self, Event};
-            crossterm::terminal::enable_raw_mode().ok();
-            loop {
-                if let Ok(Event::Key(_)) = event::read() {
-                    break;
-                }
-            }
-            crossterm::terminal::disable_raw_mode().ok();
+        if pauses_after_switch(dry_run, non_interactive) {
+            println_if_not_silent!();
+            println_if_not_silent!("{}", "Press any key to view status...".dimmed());
+            crate::terminal::wait_for_any_key();
         }
     }
 
     Ok(show_status)
 }
 
-pub(crate) struct SwitchManager {
-    active_node_with_status: crate::types::NodeWithStatus,
-    standby_node_with_status: crate::types::NodeWithStatus,
-    #[allow(dead_code)]
-    validator_pair: crate::types::ValidatorPair,
-    ssh_pool: Arc<crate::ssh::AsyncSshPool>,
-    detected_ssh_keys: std::collections::HashMap<String, String>,
-    tower_file_name: Option<String>,
+/// Whether `run_switch` waits for a key press after a completed live switch
+pub(crate) fn pauses_after_switch(dry_run: bool, non_interactive: bool) -> bool {
+    !dry_run && !non_interactive && !is_silent_mode()
+}
+
+/// Pick the source (active) and target node for a switch of `validator_status`:
+/// the `--to` target if given, otherwise the best standby (or recovery-mode pick)
+pub(crate) async fn resolve_switch_nodes<'a>(
+    app_state: &crate::AppState,
+    validator_status: &'a crate::ValidatorStatus,
+    target_label: Option<&str>,
+) -> Result<(&'a crate::types::NodeWithStatus, &'a crate::types::NodeWithStatus)> {
+    let validator_pair = &validator_status.validator_pair;
+    let selected = if let Some(target_label) = target_label {
+        let (source, target) =
+            targeted_switch_nodes(&validator_status.nodes_with_status, target_label)?;
+        println_if_not_silent!(
+            "🎯 Targeted switch: {} → {}",
+            source.node.label,
+            target.node.label
+        );
+        (source, target)
+    } else {
+        // Find active and standby nodes with full status information
+        let active_node_with_status = validator_status
+            .nodes_with_status
+            .iter()
+            .find(|n| n.status == crate::types::NodeStatus::Active);
+        let standby_count = validator_status
+            .nodes_with_status
+            .iter()
+            .filter(|n| n.status == crate::types::NodeStatus::Standby)
+            .count();
+        let standby_node_with_status = if active_node_with_status.is_some() && standby_count > 1 {
+            // Several warm spares: promote the best one by health, slot lag and priority
+            println_if_not_silent!(
+                "🔍 Selecting the best of {} standby nodes...",
+                standby_count
+            );
+            crate::emergency_failover::select_best_standby(
+                &validator_status.nodes_with_status,
+                None,
+                &validator_pair.rpc,
+                &app_state.ssh_pool,
+                &app_state.detected_ssh_keys,
+            )
+            .await
+            .map(|idx| &validator_status.nodes_with_status[idx])
+        } else {
+            validator_status
+                .nodes_with_status
+                .iter()
+                .find(|n| n.status == crate::types::NodeStatus::Standby)
+        };
+
+        match (active_node_with_status, standby_node_with_status) {
+            (Some(active), Some(standby)) => (active, standby),
+            _ => {
+                // Handle special case: all nodes are standby or all unknown
+                let node_count = validator_status.nodes_with_status.len();
+                let unknown_count = validator_status
+                    .nodes_with_status
+                    .iter()
+                    .filter(|n| n.status == crate::types::NodeStatus::Unknown)
+                    .count();
+
+                if standby_count == node_count {
+                    println_if_not_silent!(
+                        "\n{}",
+                        "⚠️  All nodes are in STANDBY state - Recovery Mode"
+                            .yellow()
+                            .bold()
+                    );
+
+                    // In recovery mode, try to identify which node has a tower file
+                    // The node with a tower file should be the "source" (assigned to active_node_with_status)
+                    let nodes_with_tower: Vec<usize> = validator_status
+                        .nodes_with_status
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, n)| n.tower_path.is_some())
+                        .map(|(idx, _)| idx)
+                        .collect();
+
+                    let source_idx = match nodes_with_tower.as_slice() {
+                        [] => {
+                            // No node has a detected tower file - this is risky
+                            println_if_not_silent!(
+                                "{}",
+                                "   ⚠️  WARNING: No tower file detected on any node!"
+                                    .bright_red()
+                            );
+                            println_if_not_silent!(
+                                "   Using {} as source (may fail if tower doesn't exist)",
+                                validator_status.nodes_with_status[0].node.label
+                            );
+                            0
+                        }
+                        [only] => {
+                            println_if_not_silent!(
+                                "   Tower file found on {} - using as source",
+                                validator_status.nodes_with_status[*only].node.label
+                            );
+                            *only
+                        }
+                        [first, ..] => {
+                            // Several nodes have tower files - use the first in config order
+                            println_if_not_silent!(
+                                "   Multiple nodes have tower files - using {} as source",
+                                validator_status.nodes_with_status[*first].node.label
+                            );
+                            *first
+                        }
+                    };
+                    // Activate the best of the other standbys, ranked like a failover target
+                    let target_idx = crate::emergency_failover::select_best_standby(
+                        &validator_status.nodes_with_status,
+                        Some(source_idx),
+                        &validator_pair.rpc,
+                        &app_state.ssh_pool,
+                        &app_state.detected_ssh_keys,
+                    )
+                    .await
+                    .ok_or_else(|| {
+                        anyhow!("Recovery mode needs a second standby node to activate")
+                    })?;
+
+                    println_if_not_silent!(
+                        "Will activate {} and keep {} as standby",
+                        validator_status.nodes_with_status[target_idx].node.label,
+                        validator_status.nodes_with_status[source_idx].node.label
+                    );
+
+                    (
+                        &validator_status.nodes_with_status[source_idx], // Source: has tower, will be demoted
+                        &validator_status.nodes_with_status[target_idx], // Target: will receive tower and become active
+                    )
+                } else if unknown_count == node_count {
+                    // All nodes have Unknown status - RPC likely down everywhere
+                    println_if_not_silent!(
+                        "\n{}",
+                        "⚠️  All nodes have UNKNOWN status - RPC may be down"
+                            .yellow()
+                            .bold()
+                    );
+                    println_if_not_silent!(
+                        "{}",
+                        "   Cannot safely determine which node is active!".bright_red()
+                    );
+                    println_if_not_silent!(
+                        "   Please verify node status manually before proceeding."
+                    );
+                    return Err(anyhow!(
+                        "Cannot switch: All nodes have Unknown status. \
+                        Verify RPC health and node status before attempting switch."
+                    ));
+                } else {
+                    // Fallback: use first two nodes if we can't determine status
+                    if validator_status.nodes_with_status.len() < 2 {
+                        return Err(anyhow!(
+                            "Validator must have at least 2 nodes configured for switching"
+                        ));
+                    }
+                    println_if_not_silent!(
+                        "\n{}",
+                        "⚠️  Cannot determine Active/Standby status - using default node order"
+                            .yellow()
+                    );
+                    (
+                        &validator_status.nodes_with_status[0],
+                        &validator_status.nodes_with_status[1],
+                    )
+                }
+            }
+        }
+    };
+    Ok(selected)
+}
+
+/// Check the chosen nodes before a switch: errors abort, warnings are shown (and
+/// acknowledged with a key press when confirmation is required)
+pub(crate) async fn validate_switch_nodes(
+    app_state: &crate::AppState,
+    active_node_with_status: &crate::types::NodeWithStatus,
+    standby_node_with_status: &crate::types::NodeWithStatus,
+    dry_run: bool,
+    require_confirmation: bool,
+) -> Result<()> {
+    // Targeted validation: Check only what's needed for this specific switch
+    let mut validation_errors = Vec::new();
+    let mut validation_warnings = Vec::new();
+
+    // Check target (standby) node - this is critical for switch success
+    if standby_node_with_status.status == crate::types::NodeStatus::Unknown {
+        validation_errors.push(format!(
+            "Target node {} is unreachable (SSH connection failed)",
+            standby_node_with_status.node.label
+        ));
+    } else {
+        // Since we skip swap readiness checks at startup, we need to check now
+        // For standby nodes, we check all requirements except tower file
+        println_if_not_silent!("🔍 Checking target node swap readiness...");
+
+        if let Some(ssh_key) = app_state
+            .detected_ssh_keys
+            .get(&standby_node_with_status.node.host)
+        {
+            let (is_ready, issues) = crate::startup::check_node_swap_readiness(
+                &app_state.ssh_pool,
+                &standby_node_with_status.node,
+                ssh_key,
+                standby_node_with_status.ledger_path.as_ref(),
+                Some(true), // is_standby = true, skip tower check
+            )
+            .await;
+
+            if !is_ready {
+                validation_errors.push(format!(
+                    "Target node {} is not swap-ready: {}",
+                    standby_node_with_status.node.label,
+                    issues.join(", ")
+                ));
+            }
+        }
+
+        // The tower is copied into the target's ledger directory, so fail now
+        // rather than after the active node has been demoted
+        if standby_node_with_status.ledger_path.is_none() {
+            validation_errors.push(format!(
+                "Ledger path not detected on target node {} ({})",
+                standby_node_with_status.node.label,
+                if standby_node_with_status.validator_type
+                    == crate::types::ValidatorType::Firedancer
+                {
+                    "set [ledger] path in the Firedancer config"
+                } else {
+                    "is the validator running with --ledger?"
+                }
+            ));
+        }
+    }
+
+    // Check if we can get SSH key for target node
+    if !app_state
+        .detected_ssh_keys
+        .contains_key(&standby_node_with_status.node.host)
+    {
+        validation_errors.push(format!(
+            "No SSH key available for target node {}",
+            standby_node_with_status.node.label
+        ));
+    }
+
+    // Check source (active) node - this is preferred but not critical (emergency scenarios)
+    if active_node_with_status.status == crate::types::NodeStatus::Unknown {
+        validation_warnings.push(format!(
+            "Source node {} is unreachable - will skip optional steps (tower copy, graceful shutdown)",
+            active_node_with_status.node.label
+        ));
+    }
+    // Skip detailed swap readiness check for source node - not critical for switch
+
+    // Check if we can get SSH key for source node
+    if !app_state
+        .detected_ssh_keys
+        .contains_key(&active_node_with_status.node.host)
+    {
+        validation_warnings.push(format!(
+            "No SSH key available for source node {} - will skip optional steps",
+            active_node_with_status.node.label
+        ));
+    }
+
+    // Show validation results
+    if !validation_errors.is_empty() {
+        println_if_not_silent!("\n{}", "❌ SWITCH VALIDATION FAILED".red().bold());
+        println_if_not_silent!("\nCritical issues that prevent switching:\n");
+        for error in &validation_errors {
+            println_if_not_silent!("  • {}", error.red());
+        }
+        println_if_not_silent!(
+            "\n{}",
+            "Please resolve these issues before attempting to switch.".yellow()
+        );
+        return Err(SwitchError::ValidationFailed {
+            issues: validation_errors,
+        }
+        .into());
+    }
+
+    if !validation_warnings.is_empty() {
+        println_if_not_silent!("\n{}", "⚠️  SWITCH WARNINGS".yellow().bold());
+        println_if_not_silent!("\nNon-critical issues (switch will continue with limitations):\n");
+        for warning in &validation_warnings {
+            println_if_not_silent!("  • {}", warning.yellow());
+        }
+
+        if require_confirmation && !dry_run {
+            println_if_not_silent!(
+                "\n{}",
+                "Do you want to continue with the switch despite these warnings?".bright_yellow()
+            );
+
+            crate::terminal::wait_for_any_key();
+        }
+        println_if_not_silent!();
+    }
+
+    println_if_not_silent!("✅ Switch validation passed - proceeding with operation\n");
+    Ok(())
+}
+
+/// Resolve the source (currently active) node and an operator-chosen target node.
+/// The target must be a swap-ready node other than the active one.
+pub(crate) fn targeted_switch_nodes<'a>(
+    nodes_with_status: &'a [crate::types::NodeWithStatus],
+    target_label: &str,
+) -> Result<(
+    &'a crate::types::NodeWithStatus,
+    &'a crate::types::NodeWithStatus,
+)> {
+    let target = nodes_with_status
+        .iter()
+        .find(|n| n.node.label == target_label)
+        .ok_or_else(|| anyhow!("No node labelled '{}' in this validator pair", target_label))?;
+
+    if target.status == crate::types::NodeStatus::Active {
+        return Err(anyhow!("{} is already the active node", target_label));
+    }
+    if let Some(false) = target.swap_ready {
+        return Err(anyhow!(
+            "Target node {} is not swap-ready: {}",
+            target_label,
+            target.swap_issues.join(", ")
+        ));
+    }
+
+    // Without an active node (recovery mode), demote the node holding a tower file
+    let source = nodes_with_status
+        .iter()
+        .find(|n| n.status == crate::types::NodeStatus::Active)
+        .or_else(|| {
+            nodes_with_status
+                .iter()
+                .find(|n| n.node.label != target_label && n.tower_path.is_some())
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Cannot determine the active node - refusing targeted switch to {}",
+                target_label
+            )
+        })?;
+
+    Ok((source, target))
+}
+
+/// Swap the Active/Standby statuses of the two nodes involved in a switch of the
+/// selected validator; other standbys keep their status
+fn swap_active_standby_status(app_state: &mut crate::AppState, from_label: &str, to_label: &str) {
+    if app_state.validator_statuses.is_empty() {
+        return;
+    }
+
+    let nodes = &mut app_state.validator_statuses[app_state.selected_validator_index]
+        .nodes_with_status;
+    let from_idx = nodes.iter().position(|n| n.node.label == from_label);
+    let to_idx = nodes.iter().position(|n| n.node.label == to_label);
+
+    // Swap the statuses
+    if let (Some(from_idx), Some(to_idx)) = (from_idx, to_idx) {
+        nodes[from_idx].status = crate::types::NodeStatus::Standby;
+        nodes[to_idx].status = crate::types::NodeStatus::Active;
+    }
+}
+
+/// Look for switch journals left behind by an interrupted svs process and offer
+/// to finish or roll back each unfinished switch.
+/// A switch without an operator must not start over an unfinished one: only
+/// an interactive run can resume it or roll it back
+pub fn ensure_no_unfinished_switch(app_state: &crate::AppState) -> Result<()> {
+    let identity = &app_state.validator_statuses[app_state.selected_validator_index]
+        .validator_pair
+        .identity_pubkey;
+    let Some(journal) = SwitchJournal::load(identity)? else {
+        return Ok(());
+    };
+    Err(SwitchError::PartialSwitch {
+        active_status: format!(
+            "{} ({}) - unfinished switch stopped at: {}",
+            journal.from_node,
+            journal.from_host,
+            journal.step.description()
+        ),
+        standby_status: format!(
+            "{} ({}) - run svs interactively to resume or roll it back",
+            journal.to_node, journal.to_host
+        ),
+    }
+    .into())
+}
+
+pub async fn recover_unfinished_switches(app_state: &mut crate::AppState) -> Result<()> {
+    use inquire::Select;
+
+    for validator_idx in 0..app_state.validator_statuses.len() {
+        let identity = app_state.validator_statuses[validator_idx]
+            .validator_pair
+            .identity_pubkey
+            .clone();
+
+        let journal = match SwitchJournal::load(&identity) {
+            Ok(Some(journal)) => journal,
+            Ok(None) => continue,
+            Err(e) => {
+                println!("{}", format!("⚠️  {}", e).yellow());
+                continue;
+            }
+        };
+
+        // Nobody can choose how to recover; keep the journal for the next
+        // interactive run
+        if !crate::terminal::is_interactive() {
+            println!(
+                "{}",
+                format!(
+                    "⚠️  Unfinished switch for {} left for the next interactive run",
+                    identity
+                )
+                .yellow()
+            );
+            continue;
+        }
+
+        println!(
+            "\n{}",
+            "⚠️  Unfinished validator switch detected".bright_red().bold()
+        );
+        println!("{}", "━".repeat(50).dimmed());
+        println!("  Validator: {}", journal.identity_pubkey);
+        println!(
+            "  Switch:    {} ({}) → {} ({})",
+            journal.from_node, journal.from_host, journal.to_node, journal.to_host
+        );
+        println!("  Started:   {}", journal.started_at);
+        println!("  Last step: {}", journal.step.description().yellow());
+        if let Some(error) = &journal.last_error {
+            println!("  Error:     {}", error.red());
+        }
+        println!();
+
+        let validator_status = &app_state.validator_statuses[validator_idx];
+        let from_idx = validator_status
+            .nodes_with_status
+            .iter()
+            .position(|n| n.node.label == journal.from_node);
+        let to_idx = validator_status
+            .nodes_with_status
+            .iter()
+            .position(|n| n.node.label == journal.to_node);
+
+        let resume_option = format!("⏩ Resume - finish switching to {}", journal.to_node);
+        let rollback_option = format!("⏪ Roll back - restore {} as active", journal.from_node);
+        let discard_option = "🗑️  Discard journal - nodes were fixed manually".to_string();
+        let ignore_option = "⏭️  Ignore for now".to_string();
+
+        let (from_idx, to_idx) = match (from_idx, to_idx) {
+            (Some(from_idx), Some(to_idx)) => (from_idx, to_idx),
+            _ => {
+                println!(
+                    "{}",
+                    "Nodes in this journal are no longer configured - it can only be discarded."
+                        .yellow()
+                );
+                let selection = Select::new(
+                    "What would you like to do?",
+                    vec![discard_option.clone(), ignore_option],
+                )
+                .prompt()?;
+                if selection == discard_option {
+                    SwitchJournal::clear(&identity)?;
+                }
+                continue;
+            }
+        };
+
+        let selection = Select::new(
+            "How do you want to recover?",
+            vec![
+                resume_option.clone(),
+                rollback_option.clone(),
+                discard_option.clone(),
+                ignore_option,
+            ],
+        )
+        .prompt()?;
+
+        if selection == discard_option {
+            SwitchJournal::clear(&identity)?;
+            println!("{}", "✅ Switch journal discarded".green());
+            continue;
+        }
+        if selection != resume_option && selection != rollback_option {
+            continue;
+        }
+
+        let forward = selection == resume_option;
+        let from_node = validator_status.nodes_with_status[from_idx].clone();
+        let to_node = validator_status.nodes_with_status[to_idx].clone();
+
+        let mut switch_manager = SwitchManager::new(
+            from_node,
+            to_node,
+            validator_status.validator_pair.clone(),
+            app_state.ssh_pool.clone(),
+            app_state.detected_ssh_keys.clone(),
+        )
+        .with_switch_config(app_state.config.switch_config.clone().unwrap_or_default());
+
+        match switch_manager.resume_from_journal(journal, forward).await {
+            Ok(()) => {
+                let (active_idx, standby_idx) = if forward {
+                    (to_idx, from_idx)
+                } else {
+                    (from_idx, to_idx)
+                };
+                let nodes = &mut app_state.validator_statuses[validator_idx].nodes_with_status;
+                nodes[active_idx].status = crate::types::NodeStatus::Active;
+                nodes[standby_idx].status = crate::types::NodeStatus::Standby;
+
+                println!(
+                    "\n{}",
+                    format!(
+                        "✅ Recovery complete - {} is active",
+                        nodes[active_idx].node.label
+                    )
+                    .bright_green()
+                    .bold()
+                );
+            }
+            Err(e) => {
+                eprintln!(
+                    "\n{}",
+                    format!("🚨 Recovery failed: {}", e).bright_red().bold()
+                );
+                eprintln!(
+                    "   ⚠️  MANUAL INTERVENTION REQUIRED: Check validator status on both nodes!"
+                );
+                eprintln!("   The switch journal was kept so you can retry on next start.");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Upper bound for the local fencing command
+const FENCING_COMMAND_TIMEOUT_SECONDS: u64 = 60;
+
+/// How long a promoted standby has to report the funded identity over getIdentity
+const PROMOTION_CONFIRM_TIMEOUT_SECONDS: u64 = 5;
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum FenceCheck {
+    /// Reports the expected unfunded pubkey
+    Fenced,
+    /// Still reports the funded identity
+    StillFunded,
+    /// Reports something else; the funded identity is gone either way
+    UnexpectedIdentity,
+}
+
+/// Outcome of polling a promoted standby's `getIdentity`
+#[derive(Debug, PartialEq)]
+pub(crate) enum PromotionCheck {
+    Confirmed,
+    /// Anything else: the standby must be demoted again before the rollback,
+    /// since a late `set-identity` would leave two nodes on the funded identity
+    Unconfirmed(String),
+}
+
+/// Classify what a promoted standby reported against the funded identity
+pub(crate) fn check_promoted_identity(reported: &Result<String>, funded: &str) -> PromotionCheck {
+    match reported {
+        Ok(identity) if identity == funded => PromotionCheck::Confirmed,
+        Ok(identity) => PromotionCheck::Unconfirmed(format!(
+            "reports {} instead of the funded identity {}",
+            identity, funded
+        )),
+        Err(e) => PromotionCheck::Unconfirmed(format!("getIdentity failed: {}", e)),
+    }
+}
+
+/// The slot a new vote must pass in Step 5: the highest known last vote of
+/// the old primary, or `None` when nothing was captured
+pub(crate) fn vote_landing_floor(candidates: &[Option<u64>]) -> Option<u64> {
+    candidates.iter().flatten().copied().max()
+}
+
+/// Classify the identity an old primary reports after Step 1
+pub(crate) fn check_fenced_identity(
+    reported: &str,
+    funded: &str,
+    expected_unfunded: Option<&str>,
+) -> FenceCheck {
+    if reported == funded {
+        FenceCheck::StillFunded
+    } else if expected_unfunded.is_none_or(|unfunded| unfunded == reported) {
+        FenceCheck::Fenced
+    } else {
+        FenceCheck::UnexpectedIdentity
+    }
+}
+
+/// Firedancer config path cached at startup, or looked up from the running fdctl process
+pub(crate) async fn firedancer_config_path(
+    ssh_pool: &crate::ssh::AsyncSshPool,
+    detected_ssh_keys: &std::collections::HashMap<String, String>,
+    node_with_status: &crate::types::NodeWithStatus,
+) -> Result<String> {
+    if let Some(config_path) = &node_with_status.firedancer_config_path {
+        return Ok(config_path.clone());
+    }
+
+    // Fall back to one process lookup only if startup did not cache the config path.
+    let ssh_key = detected_ssh_keys
+        .get(&node_with_status.node.host)
+        .ok_or_else(|| anyhow!("No SSH key detected for host: {}", node_with_status.node.host))?;
+    let process_info = ssh_pool
+        .execute_command(
+            &node_with_status.node,
+            ssh_key,
+            "ps aux | grep 'bin/fdctl ' | grep -v grep",
+        )
+        .await?;
+
+    crate::executable_utils::extract_firedancer_config_path(&process_info)
+}
+
+/// The command that moves `node` into `role` with its switch strategy,
+/// resolving the Firedancer config path only when set-identity needs it
+pub(crate) async fn strategy_command(
+    ssh_pool: &crate::ssh::AsyncSshPool,
+    detected_ssh_keys: &std::collections::HashMap<String, String>,
+    node: &crate::types::NodeWithStatus,
+    role: IdentityRole,
+    require_tower: bool,
+) -> Result<StrategyCommand> {
+    let firedancer_config_path = if node.validator_type == crate::types::ValidatorType::Firedancer
+        && resolve_strategy(node) == crate::types::SwitchStrategy::SetIdentity
+    {
+        Some(firedancer_config_path(ssh_pool, detected_ssh_keys, node).await?)
+    } else {
+        None
+    };
+    identity_command(node, firedancer_config_path.as_deref(), role, require_tower)
+}
+
+pub(crate) async fn run_strategy_command(
+    ssh_pool: &crate::ssh::AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    command: &StrategyCommand,
+) -> Result<()> {
+    let args: Vec<&str> = command.args.iter().map(String::as_str).collect();
+    let output = ssh_pool
+        .execute_command_with_args(node, ssh_key, &command.program, &args)
+        .await?;
+    command.check_output(&output)
+}
+
+/// Step 1 of a switch on its own: move `node` to its unfunded identity
+pub(crate) async fn demote_to_unfunded(
+    ssh_pool: &crate::ssh::AsyncSshPool,
+    detected_ssh_keys: &std::collections::HashMap<String, String>,
+    node: &crate::types::NodeWithStatus,
+) -> Result<()> {
+    let ssh_key = detected_ssh_keys
+        .get(&node.node.host)
+        .ok_or_else(|| anyhow!("No SSH key detected for host: {}", node.node.host))?;
+    let command = strategy_command(
+        ssh_pool,
+        detected_ssh_keys,
+        node,
+        IdentityRole::Unfunded,
+        false,
+    )
+    .await?;
+    run_strategy_command(ssh_pool, &node.node, ssh_key, &command).await
+}
+
+pub(crate) struct SwitchManager {
+    active_node_with_status: crate::types::NodeWithStatus,
+    standby_node_with_status: crate::types::NodeWithStatus,
+    validator_pair: crate::types::ValidatorPair,
+    ssh_pool: Arc<crate::ssh::AsyncSshPool>,
+    detected_ssh_keys: std::collections::HashMap<String, String>,
+    switch_config: crate::types::SwitchConfig,
+    journal: Option<SwitchJournal>,
+    tower_file_name: Option<String>,
     tower_transfer_time: Option<Duration>,
     identity_switch_time: Option<Duration>,
     active_switch_time: Option<Duration>,
     standby_switch_time: Option<Duration>,
     offline_window_time: Option<Duration>,
+    first_vote_time: Option<Duration>,
+    failed_back: bool,
+    onchain_last_vote: Option<u64>,
+    /// Confirmed `last_vote` captured before Step 1, the floor for Step 5
+    pre_switch_last_vote: Option<u64>,
+    /// Old primary's last voted slot read from the transferred tower
+    tower_last_vote: Option<u64>,
+    /// Why Step 5 saw no vote land, for the dedicated alert
+    vote_landing_failure: Option<String>,
+    unfunded_pubkey: Option<String>,
+    /// Tower read (as base64) in the Step 1 round trip, consumed by Step 2
+    pipelined_tower: Option<Result<String>>,
+    /// When a pipelined Step 1 saw the demotion finish, before the tower read
+    pipelined_demoted_at: Option<Instant>,
+    preflight: Vec<PreflightCheck>,
 }
 
 impl SwitchManager {
@@ -560,12 +1166,48 @@ impl SwitchManager {
             validator_pair,
             ssh_pool,
             detected_ssh_keys,
+            switch_config: crate::types::SwitchConfig::default(),
+            journal: None,
             tower_file_name: None,
             tower_transfer_time: None,
             identity_switch_time: None,
             active_switch_time: None,
             standby_switch_time: None,
             offline_window_time: None,
+            first_vote_time: None,
+            failed_back: false,
+            onchain_last_vote: None,
+            pre_switch_last_vote: None,
+            tower_last_vote: None,
+            vote_landing_failure: None,
+            unfunded_pubkey: None,
+            pipelined_tower: None,
+            pipelined_demoted_at: None,
+            preflight: Vec::new(),
+        }
+    }
+
+    /// Apply switch defaults from config (leader gap, ...)
+    pub(crate) fn with_switch_config(mut self, switch_config: crate::types::SwitchConfig) -> Self {
+        self.switch_config = switch_config;
+        self
+    }
+
+    /// Persist the current step to the switch journal (live switches only)
+    fn record_journal_step(&mut self, step: SwitchStep, error: Option<String>) {
+        if let Some(journal) = self.journal.as_mut() {
+            if let Err(e) = journal.record(step, error) {
+                println_if_not_silent!("   ⚠️  Failed to write switch journal: {}", e);
+            }
+        }
+    }
+
+    /// Remove the switch journal once both nodes are in a consistent state
+    fn finish_journal(&mut self) {
+        if let Some(journal) = self.journal.take() {
+            if let Err(e) = SwitchJournal::clear(&journal.identity_pubkey) {
+                println_if_not_silent!("   ⚠️  Failed to remove switch journal: {}", e);
+            }
         }
     }
 
@@ -577,28 +1219,6 @@ impl SwitchManager {
             .ok_or_else(|| anyhow!("No SSH key detected for host: {}", host))
     }
 
-    async fn get_firedancer_config_path(
-        &self,
-        node_with_status: &crate::types::NodeWithStatus,
-    ) -> Result<String> {
-        if let Some(config_path) = &node_with_status.firedancer_config_path {
-            return Ok(config_path.clone());
-        }
-
-        // Fall back to one process lookup only if startup did not cache the config path.
-        let ssh_key = self.get_ssh_key_for_node(&node_with_status.node.host)?;
-        let pool = self.ssh_pool.clone();
-        let process_info = pool
-            .execute_command(
-                &node_with_status.node,
-                &ssh_key,
-                "ps aux | grep 'bin/fdctl ' | grep -v grep",
-            )
-            .await?;
-
-        crate::executable_utils::extract_firedancer_config_path(&process_info)
-    }
-
     async fn execute_switch(&mut self, dry_run: bool, require_confirmation: bool) -> Result<bool> {
         // Show confirmation dialog (except for dry run or when explicitly disabled)
         if !dry_run && require_confirmation {
@@ -646,6 +1266,9 @@ impl SwitchManager {
 
             // Use inquire for confirmation
             use inquire::Confirm;
+            crate::terminal::ensure_interactive(
+                "Do you want to proceed with the validator switch?",
+            )?;
             let confirmed = Confirm::new("Do you want to proceed with the validator switch?")
                 .with_default(false)
                 .prompt()?;
@@ -659,11 +1282,72 @@ impl SwitchManager {
             std::io::stdout().flush()?;
         }
 
+        // Capture the on-chain last vote now so the tower freshness check adds
+        // no RPC latency to the offline window
+        if self.switch_config.validate_tower && !dry_run {
+            match crate::solana_rpc::fetch_vote_account_data(
+                &self.validator_pair.rpc,
+                &self.validator_pair.vote_pubkey,
+            )
+            .await
+            {
+                Ok(vote_data) => {
+                    self.onchain_last_vote = Some(vote_data.vote_account_info.last_vote)
+                }
+                Err(e) => println_if_not_silent!(
+                    "  ⚠️  Could not fetch on-chain last vote ({}) - tower freshness will not be checked",
+                    e
+                ),
+            }
+        }
+
+        // Step 5 only counts votes past the last one the old primary landed
+        if self.switch_config.verify_vote_landing && !dry_run {
+            self.pre_switch_last_vote = crate::solana_rpc::fetch_last_vote(
+                &self.validator_pair.rpc,
+                &self.validator_pair.vote_pubkey,
+                CommitmentConfig::confirmed(),
+            )
+            .await
+            .ok();
+        }
+
+        // Resolve the old primary's unfunded pubkey up front so fencing can match it exactly
+        if self.switch_config.fence_old_primary && !dry_run {
+            match self.resolve_unfunded_pubkey().await {
+                Ok(pubkey) => self.unfunded_pubkey = Some(pubkey),
+                Err(e) => println_if_not_silent!(
+                    "  ⚠️  Could not resolve the unfunded pubkey ({}) - fencing will only check the funded identity is gone",
+                    e
+                ),
+            }
+        }
+
+        // Configured hooks may veto the switch before anything changes
+        self.run_switch_hooks(HookPoint::PreSwitch, dry_run, None)
+            .await?;
+
+        if dry_run {
+            self.run_preflight().await;
+        }
+
+        if !dry_run {
+            self.warmup_backup_connection("the failover").await?;
+        }
+
+        // Wait for a leader gap last, so the pre-switch calls above can't eat into it
+        self.wait_for_leader_gap(dry_run).await?;
+
         // Start timing the entire switch operation
         let total_switch_start = Instant::now();
 
         if !dry_run {
-            self.warmup_backup_connection("the failover").await?;
+            self.journal = Some(SwitchJournal::new(
+                &self.validator_pair,
+                &self.active_node_with_status.node,
+                &self.standby_node_with_status.node,
+            ));
+            self.record_journal_step(SwitchStep::DemotingPrimary, None);
         }
 
         // Step 1: Switch active node to unfunded identity
@@ -674,12 +1358,24 @@ impl SwitchManager {
                 .bold()
         );
         let active_switch_start = Instant::now();
-        self.switch_primary_to_unfunded(dry_run).await?;
-        // Track that step 1 completed for potential rollback
-        let step1_completed = true;
-        self.active_switch_time = Some(active_switch_start.elapsed());
-        // Mark primary offline start point (after active node switched to unfunded)
-        let primary_offline_start = Instant::now();
+        if let Err(e) = self.switch_primary_to_unfunded(dry_run).await {
+            // Keep the journal: the demotion may have been applied before the error
+            self.record_journal_step(SwitchStep::DemotingPrimary, Some(e.to_string()));
+            return Err(classify_step_error(
+                e,
+                "switching the active node to the unfunded identity",
+                &self.active_node_with_status.node.host,
+            ));
+        }
+        self.record_journal_step(SwitchStep::PrimaryDemoted, None);
+        // Mark primary offline start point (after active node switched to unfunded).
+        // A pipelined Step 1 also read the tower; its window starts at the demotion
+        // exit marker so both transfer modes measure the same thing.
+        let primary_offline_start = self
+            .pipelined_demoted_at
+            .take()
+            .unwrap_or_else(Instant::now);
+        self.active_switch_time = Some(primary_offline_start.duration_since(active_switch_start));
         if !dry_run {
             println_if_not_silent!(
                 "   ✓ Completed in {}",
@@ -689,49 +1385,58 @@ impl SwitchManager {
             );
         }
 
+        // Fencing: the standby must not get the funded identity while the old primary still votes with it
+        if let Err(e) = self.fence_old_primary(dry_run).await {
+            let host = self.active_node_with_status.node.host.clone();
+            return Err(self
+                .roll_back_demotion(
+                    e,
+                    dry_run,
+                    "⚠️  Fencing failed! Attempting rollback...",
+                    "fencing the old primary",
+                    &host,
+                )
+                .await);
+        }
+
         // Step 2: Transfer tower file (with rollback on failure)
         println_if_not_silent!(
             "\n{}",
             "📤 Step 2: Transfer Tower File".bright_blue().bold()
         );
+        self.record_journal_step(SwitchStep::TransferringTower, None);
         if let Err(e) = self.transfer_tower_file(dry_run).await {
             // Step 2 failed - attempt rollback of Step 1
-            if step1_completed && !dry_run {
-                println_if_not_silent!(
-                    "\n{}",
-                    "⚠️  Tower transfer failed! Attempting rollback..."
-                        .bright_red()
-                        .bold()
-                );
-                if let Err(rollback_err) = self.rollback_primary_to_funded().await {
-                    // CRITICAL: Both forward and rollback failed
-                    eprintln!(
-                        "\n{}",
-                        "🚨 CRITICAL: Rollback failed! Validator may be in inconsistent state!"
-                            .bright_red()
-                            .bold()
-                    );
-                    eprintln!("   Original error: {}", e);
-                    eprintln!("   Rollback error: {}", rollback_err);
-                    eprintln!(
-                        "   ⚠️  MANUAL INTERVENTION REQUIRED: Check validator status on both nodes!"
-                    );
-                    return Err(anyhow!(
-                        "Switch failed and rollback failed. Original: {}. Rollback: {}",
-                        e,
-                        rollback_err
-                    ));
-                }
-                println_if_not_silent!(
-                    "{}",
-                    "   ✓ Rollback successful - active node restored to funded identity"
-                        .bright_green()
-                );
-            }
-            return Err(e);
+            let host = self.standby_node_with_status.node.host.clone();
+            return Err(self
+                .roll_back_demotion(
+                    e,
+                    dry_run,
+                    "⚠️  Tower transfer failed! Attempting rollback...",
+                    "transferring the tower file",
+                    &host,
+                )
+                .await);
         }
+        self.record_journal_step(SwitchStep::TowerTransferred, None);
         // Note: tower_transfer_time is set inside transfer_tower_file method
 
+        if let Err(e) = self
+            .run_switch_hooks(HookPoint::PostTower, dry_run, None)
+            .await
+        {
+            let host = self.standby_node_with_status.node.host.clone();
+            return Err(self
+                .roll_back_demotion(
+                    e,
+                    dry_run,
+                    "⚠️  post_tower hook failed! Attempting rollback...",
+                    "running post_tower hooks",
+                    &host,
+                )
+                .await);
+        }
+
         // Step 3: Switch standby node to funded identity (with rollback on failure)
         println_if_not_silent!(
             "\n{}",
@@ -740,47 +1445,40 @@ impl SwitchManager {
                 .bold()
         );
         let standby_switch_start = Instant::now();
+        self.record_journal_step(SwitchStep::PromotingStandby, None);
         if let Err(e) = self.switch_backup_to_funded(dry_run).await {
+            // The standby may still hold the funded identity; re-promoting the
+            // old primary now could double sign
+            if matches!(
+                find_switch_error(&e),
+                Some(SwitchError::PartialSwitch { .. })
+            ) {
+                self.record_journal_step(SwitchStep::PromotingStandby, Some(e.to_string()));
+                return Err(e);
+            }
             // Step 3 failed - attempt rollback of Step 1
             // Note: Tower file was transferred but that's okay, it can be overwritten later
-            if step1_completed && !dry_run {
-                println_if_not_silent!(
-                    "\n{}",
-                    "⚠️  Standby activation failed! Attempting rollback..."
-                        .bright_red()
-                        .bold()
-                );
-                if let Err(rollback_err) = self.rollback_primary_to_funded().await {
-                    // CRITICAL: Both forward and rollback failed
-                    eprintln!(
-                        "\n{}",
-                        "🚨 CRITICAL: Rollback failed! Validator may be in inconsistent state!"
-                            .bright_red()
-                            .bold()
-                    );
-                    eprintln!("   Original error: {}", e);
-                    eprintln!("   Rollback error: {}", rollback_err);
-                    eprintln!(
-                        "   ⚠️  MANUAL INTERVENTION REQUIRED: Check validator status on both nodes!"
-                    );
-                    return Err(anyhow!(
-                        "Switch failed and rollback failed. Original: {}. Rollback: {}",
-                        e,
-                        rollback_err
-                    ));
-                }
-                println_if_not_silent!(
-                    "{}",
-                    "   ✓ Rollback successful - active node restored to funded identity"
-                        .bright_green()
-                );
-            }
-            return Err(e);
+            let host = self.standby_node_with_status.node.host.clone();
+            return Err(self
+                .roll_back_demotion(
+                    e,
+                    dry_run,
+                    "⚠️  Standby activation failed! Attempting rollback...",
+                    "switching the standby node to the funded identity",
+                    &host,
+                )
+                .await);
         }
         self.standby_switch_time = Some(standby_switch_start.elapsed());
+        // Both nodes now hold their intended identities
+        self.finish_journal();
         // Record downtime: from primary_offline_start to when standby finished activating
         let primary_offline_end = Instant::now();
         self.offline_window_time = Some(primary_offline_end.duration_since(primary_offline_start));
+        // Snapshot last_vote now, before Step 4's catchup check gives the new
+        // active node time to land votes; a fallback floor when neither the
+        // tower nor the pre-switch capture is available
+        let vote_baseline = self.vote_landing_baseline(dry_run).await;
         if !dry_run {
             println_if_not_silent!(
                 "   ✓ Completed in {}",
@@ -805,6 +1503,10 @@ impl SwitchManager {
             }
         }
 
+        // The switch is done; an aborting hook only fails the result
+        self.run_switch_hooks(HookPoint::PostPromotion, dry_run, None)
+            .await?;
+
         // Step 4: Verify new active node health (former standby)
         println_if_not_silent!(
             "\n{}",
@@ -816,151 +1518,620 @@ impl SwitchManager {
         // because the switch has completed - both nodes have correct identities
         self.verify_backup_catchup(dry_run).await?;
 
+        // With auto-failback armed, the new active node must become healthy in time
+        let auto_failback = self.switch_config.auto_failback && !dry_run;
+        let mut verification = Ok(());
+        if auto_failback {
+            verification = self.wait_for_new_active_health(primary_offline_end).await;
+        }
+
+        // Step 5 (optional): Confirm the new active node's votes land on-chain
+        if verification.is_ok() && self.switch_config.verify_vote_landing {
+            println_if_not_silent!(
+                "\n{}",
+                "🗳️  Step 5: Confirm Votes Landing On-Chain"
+                    .bright_blue()
+                    .bold()
+            );
+            verification = self
+                .verify_vote_landing(dry_run, primary_offline_end, vote_baseline)
+                .await;
+        }
+
+        if let Err(e) = verification {
+            if auto_failback {
+                return Err(self.failback_to_original(e).await);
+            }
+            return Err(e);
+        }
+
+        // A dry run only passes if every preflight probe did
+        let failed_checks: Vec<String> = self
+            .preflight
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| format!("{} ({}): {}", check.check, check.node, check.detail))
+            .collect();
+        if !failed_checks.is_empty() {
+            return Err(SwitchError::ValidationFailed {
+                issues: failed_checks,
+            }
+            .into());
+        }
+
         // Summary
         self.print_summary(dry_run);
 
         Ok(!dry_run)
     }
 
+    /// Probe both nodes read-only and print the pass/fail checklist
+    async fn run_preflight(&mut self) {
+        println_if_not_silent!(
+            "\n{}",
+            "🧪 Preflight Checks (read-only)".bright_blue().bold()
+        );
+        let spinner = ConditionalSpinner::new("Probing both nodes...");
+        self.preflight = crate::switch_preflight::run_preflight(
+            &self.ssh_pool,
+            &self.detected_ssh_keys,
+            &self.validator_pair,
+            &self.active_node_with_status,
+            &self.standby_node_with_status,
+        )
+        .await;
+        let passed = self.preflight.iter().filter(|check| check.passed).count();
+        spinner.stop_with_message(&format!(
+            "  {}/{} checks passed",
+            passed,
+            self.preflight.len()
+        ));
+        for check in &self.preflight {
+            println_if_not_silent!("  {}", check.line());
+        }
+    }
+
+    /// Run the pair's and both nodes' hooks for `point`. Only a failing hook
+    /// with `on_failure: abort` returns an error; dry runs just list the hooks.
+    async fn run_switch_hooks(
+        &self,
+        point: HookPoint,
+        dry_run: bool,
+        error: Option<String>,
+    ) -> Result<()> {
+        let scheduled = crate::switch_hooks::scheduled_hooks(
+            &self.validator_pair,
+            &self.active_node_with_status.node,
+            &self.standby_node_with_status.node,
+            point,
+        );
+        if scheduled.is_empty() {
+            return Ok(());
+        }
+
+        println_if_not_silent!("\n{}", format!("🪝 Hooks: {}", point).bright_blue().bold());
+        if dry_run {
+            for hook in &scheduled {
+                println_if_not_silent!(
+                    "  Would run {} {} (timeout {}s, on failure: {:?}): {}",
+                    hook.hook.name,
+                    hook.location(),
+                    hook.hook.timeout_seconds,
+                    hook.hook.on_failure,
+                    hook.hook.command
+                );
+            }
+            return Ok(());
+        }
+
+        let ctx = crate::switch_hooks::HookContext {
+            validator_pair: &self.validator_pair,
+            source: &self.active_node_with_status.node,
+            target: &self.standby_node_with_status.node,
+            error,
+        };
+        let outcomes = crate::switch_hooks::run_hooks(
+            point,
+            &ctx,
+            &self.ssh_pool,
+            &self.detected_ssh_keys,
+            // The switch has already failed, so on_failure hooks can only warn
+            point == HookPoint::OnFailure,
+        )
+        .await;
+
+        for outcome in &outcomes {
+            match &outcome.error {
+                None => println_if_not_silent!(
+                    "  ✅ {} ({}) in {}ms",
+                    outcome.name,
+                    outcome.location,
+                    outcome.elapsed.as_millis()
+                ),
+                Some(e) if outcome.aborts() => println_if_not_silent!(
+                    "  ❌ {} ({}) failed: {}",
+                    outcome.name,
+                    outcome.location,
+                    e
+                ),
+                Some(e) => println_if_not_silent!(
+                    "  ⚠️  {} ({}) failed: {} - continuing",
+                    outcome.name,
+                    outcome.location,
+                    e
+                ),
+            }
+        }
+
+        match outcomes.iter().find(|outcome| outcome.aborts()) {
+            Some(outcome) => Err(anyhow!(
+                "{} hook '{}' failed: {}",
+                point,
+                outcome.name,
+                outcome.error.as_deref().unwrap_or_default()
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Wait until our next leader slot is at least `min_leader_gap_slots` away,
+    /// refusing the switch if that doesn't happen within the configured wait.
+    async fn wait_for_leader_gap(&self, dry_run: bool) -> Result<()> {
+        let min_gap = self.switch_config.min_leader_gap_slots;
+        if min_gap == 0 {
+            return Ok(());
+        }
+
+        println_if_not_silent!(
+            "\n{}",
+            format!("📅 Checking leader schedule (need ≥{} slots)", min_gap)
+                .bright_blue()
+                .bold()
+        );
+
+        let rpc_url = &self.validator_pair.rpc;
+        let identity = &self.validator_pair.identity_pubkey;
+        let (mut current_slot, leader_slots) =
+            crate::solana_rpc::fetch_upcoming_leader_slots(rpc_url, identity)
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Refusing to switch: unable to fetch leader schedule ({}). \
+                        Use --wait-for-leader-gap 0 to skip this check.",
+                        e
+                    )
+                })?;
+
+        if dry_run {
+            match crate::solana_rpc::slots_until_next_leader(&leader_slots, current_slot) {
+                Some(gap) if gap < min_gap => println_if_not_silent!(
+                    "   Next leader slot in {} slots - a live switch would wait for the leader window to pass",
+                    gap
+                ),
+                Some(gap) => println_if_not_silent!("   ✓ Next leader slot in {} slots", gap),
+                None => println_if_not_silent!("   ✓ No upcoming leader slots"),
+            }
+            return Ok(());
+        }
+
+        let max_wait = Duration::from_secs(self.switch_config.leader_gap_max_wait_seconds);
+        let wait_start = Instant::now();
+        let mut announced_wait = false;
+
+        loop {
+            match crate::solana_rpc::slots_until_next_leader(&leader_slots, current_slot) {
+                None => {
+                    println_if_not_silent!("   ✓ No upcoming leader slots - safe to switch");
+                    return Ok(());
+                }
+                Some(gap) if gap >= min_gap => {
+                    println_if_not_silent!(
+                        "   ✓ Next leader slot in {} slots (slot {})",
+                        gap,
+                        current_slot + gap
+                    );
+                    return Ok(());
+                }
+                Some(gap) => {
+                    if wait_start.elapsed() >= max_wait {
+                        return Err(anyhow!(
+                            "Refusing to switch: next leader slot is only {} slots away (need at least {}) \
+                            after waiting {}s. Retry later or lower --wait-for-leader-gap.",
+                            gap,
+                            min_gap,
+                            max_wait.as_secs()
+                        ));
+                    }
+                    if !announced_wait {
+                        println_if_not_silent!(
+                            "   ⏳ Next leader slot in {} slots - waiting up to {}s for our leader window to pass...",
+                            gap,
+                            max_wait.as_secs()
+                        );
+                        announced_wait = true;
+                    }
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            current_slot = crate::solana_rpc::fetch_current_slot(rpc_url).await?;
+        }
+    }
+
     pub(crate) async fn switch_primary_to_unfunded(&mut self, dry_run: bool) -> Result<()> {
-        // Use already-detected validator type instead of re-parsing ps aux
-        let (subtitle, switch_command) = match self.active_node_with_status.validator_type {
-            crate::types::ValidatorType::Firedancer => {
-                // Get fdctl executable path from config
-                let fdctl_path =
-                    crate::executable_utils::get_fdctl_path(&self.active_node_with_status)?;
-                let config_path = self
-                    .get_firedancer_config_path(&self.active_node_with_status)
-                    .await?;
-
-                (
-                    "Using Firedancer fdctl set-identity",
-                    format!(
-                        "{} set-identity --config \"{}\" \"{}\"",
-                        fdctl_path,
-                        config_path,
-                        self.active_node_with_status.node.paths.unfunded_identity
-                    ),
-                )
+        let command = self
+            .strategy_command(&self.active_node_with_status, IdentityRole::Unfunded)
+            .await?;
+
+        println_if_not_silent!("{}", command.subtitle.dimmed());
+        println_if_not_silent!(
+            "ssh {}@{} '{}'",
+            self.active_node_with_status.node.user,
+            self.active_node_with_status.node.host,
+            command.display
+        );
+
+        self.pipelined_tower = None;
+        self.pipelined_demoted_at = None;
+        let pipelined_tower_path = self.pipelined_tower_path();
+        if let Some(tower_path) = &pipelined_tower_path {
+            println_if_not_silent!(
+                "   {}",
+                format!("Tower read in the same call: base64 {}", tower_path).dimmed()
+            );
+        }
+
+        if !dry_run {
+            let spinner =
+                ConditionalSpinner::new("Switching active validator to unfunded identity...");
+            let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+            match pipelined_tower_path {
+                Some(tower_path) => {
+                    let script = pipelined_demotion_script(&command, &tower_path);
+                    // Stream the output so the demotion is timed when its exit
+                    // marker arrives, not after the tower read that follows it
+                    let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(64);
+                    let run = self.ssh_pool.execute_command_streaming(
+                        &self.active_node_with_status.node,
+                        &ssh_key,
+                        &script,
+                        tx,
+                    );
+                    let collect = async {
+                        let mut output = String::new();
+                        let mut demoted_at = None;
+                        while let Some(line) = rx.recv().await {
+                            if demoted_at.is_none() && is_demotion_exit_line(&line) {
+                                demoted_at = Some(Instant::now());
+                            }
+                            output.push_str(&line);
+                        }
+                        (output, demoted_at)
+                    };
+                    let (result, (output, demoted_at)) =
+                        tokio::time::timeout(Duration::from_secs(60), async {
+                            tokio::join!(run, collect)
+                        })
+                        .await
+                        .map_err(|_| anyhow!("Command timed out after 60s"))?;
+                    result?;
+                    let pipelined = PipelinedDemotion::parse(&output);
+                    pipelined.check_demotion(&command)?;
+                    self.pipelined_demoted_at = demoted_at;
+                    // Tower errors belong to Step 2, which rolls the demotion back
+                    self.pipelined_tower = Some(pipelined.into_tower_base64(&tower_path));
+                }
+                None => {
+                    self.run_strategy_command(
+                        &self.active_node_with_status.node,
+                        &ssh_key,
+                        &command,
+                    )
+                    .await?
+                }
             }
-            crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
-                // Use detected agave executable path if available
-                let agave_path = self
-                    .active_node_with_status
-                    .agave_validator_executable
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Agave validator executable path not found"))?;
-
-                // Use detected ledger path if available, otherwise error
-                let ledger_path = self
-                    .active_node_with_status
-                    .ledger_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Ledger path not detected for active node"))?;
-
-                (
-                    "Using Agave validator set-identity",
-                    format!(
-                        "{} -l \"{}\" set-identity \"{}\"",
-                        agave_path,
-                        ledger_path,
-                        self.active_node_with_status.node.paths.unfunded_identity
-                    ),
-                )
+            // No sleep - move immediately to next step!
+            spinner.stop_with_message("✅ Active validator switched to unfunded identity");
+        }
+
+        Ok(())
+    }
+
+    /// The active node's tower path when Step 1 should also read the tower
+    /// (`tower_transfer_mode: pipelined`)
+    fn pipelined_tower_path(&self) -> Option<String> {
+        if self.switch_config.tower_transfer_mode != crate::types::TowerTransferMode::Pipelined {
+            return None;
+        }
+        self.active_node_with_status.tower_path.clone()
+    }
+
+    async fn strategy_command(
+        &self,
+        node: &crate::types::NodeWithStatus,
+        role: IdentityRole,
+    ) -> Result<StrategyCommand> {
+        strategy_command(
+            &self.ssh_pool,
+            &self.detected_ssh_keys,
+            node,
+            role,
+            self.switch_config.require_tower,
+        )
+        .await
+    }
+
+    /// Where the transferred tower lands on the standby
+    fn standby_tower_path(&self) -> String {
+        let ledger_path = self
+            .standby_node_with_status
+            .ledger_path
+            .as_deref()
+            .unwrap_or("<ledger>");
+        let tower_file_name = self
+            .tower_file_name
+            .clone()
+            .unwrap_or_else(|| format!("tower-1_9-{}.bin", self.validator_pair.identity_pubkey));
+        format!("{}/{}", ledger_path, tower_file_name)
+    }
+
+    /// Firedancer has no `--require-tower`: refuse the promotion unless the
+    /// transferred tower is present and non-empty on the standby
+    async fn check_standby_tower(&self) -> Result<()> {
+        let tower_path = self.standby_tower_path();
+        let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+        let check = self
+            .ssh_pool
+            .execute_command(
+                &self.standby_node_with_status.node,
+                &ssh_key,
+                &format!(
+                    "test -s \"{}\" && echo 'exists' || echo 'missing'",
+                    tower_path
+                ),
+            )
+            .await?;
+
+        if check.trim() != "exists" {
+            return Err(SwitchError::TowerFileNotFound { path: tower_path }.into());
+        }
+        println_if_not_silent!("   ✓ Tower present on standby: {}", tower_path);
+        Ok(())
+    }
+
+    async fn run_strategy_command(
+        &self,
+        node: &NodeConfig,
+        ssh_key: &str,
+        command: &StrategyCommand,
+    ) -> Result<()> {
+        run_strategy_command(&self.ssh_pool, node, ssh_key, command).await
+    }
+
+    /// Pubkey of the active node's unfunded identity keypair
+    async fn resolve_unfunded_pubkey(&self) -> Result<String> {
+        let node = &self.active_node_with_status;
+        let solana_cli = node
+            .solana_cli_executable
+            .as_deref()
+            .unwrap_or(&node.node.paths.solana_cli);
+        let ssh_key = self.get_ssh_key_for_node(&node.node.host)?;
+        let output = self
+            .ssh_pool
+            .execute_command(
+                &node.node,
+                &ssh_key,
+                &format!("{} address -k {}", solana_cli, node.node.paths.unfunded_identity),
+            )
+            .await?;
+
+        let pubkey = output.trim();
+        pubkey
+            .parse::<solana_sdk::pubkey::Pubkey>()
+            .map_err(|_| anyhow!("unexpected output from solana address: {}", pubkey))?;
+        Ok(pubkey.to_string())
+    }
+
+    /// Confirm the old primary dropped the funded identity after Step 1. If it
+    /// cannot be queried, the configured fencing command has to succeed instead.
+    pub(crate) async fn fence_old_primary(&mut self, dry_run: bool) -> Result<()> {
+        if !self.switch_config.fence_old_primary {
+            return Ok(());
+        }
+
+        println_if_not_silent!(
+            "\n{}",
+            "🛡️  Fencing: Confirm Old Primary Dropped Funded Identity"
+                .bright_blue()
+                .bold()
+        );
+
+        let node = self.active_node_with_status.clone();
+        let funded = self.validator_pair.identity_pubkey.clone();
+
+        // Only set-identity leaves the old process running under a new identity to check
+        match resolve_strategy(&node) {
+            crate::types::SwitchStrategy::AuthorizedVoter => {
+                println_if_not_silent!(
+                    "  ✅ {} keeps its identity; authorized-voter remove-all already stopped its votes",
+                    node.node.label
+                );
+                return Ok(());
             }
-            _ => {
-                // Use detected ledger path if available, otherwise error
-                let ledger_path = self
-                    .active_node_with_status
-                    .ledger_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Ledger path not detected for active node"))?;
-
-                (
-                    "Using Solana validator restart",
-                    format!("{} exit && solana-validator --identity {} --vote-account {} --ledger {} --limit-ledger-size 100000000 --log - &",
-                        "solana-validator",
-                        self.active_node_with_status.node.paths.unfunded_identity,
-                        self.active_node_with_status.node.paths.vote_keypair,
-                        ledger_path)
-                )
+            crate::types::SwitchStrategy::Restart => {
+                println_if_not_silent!(
+                    "  ✅ {} was restarted with its unfunded identity",
+                    node.node.label
+                );
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        if dry_run {
+            println_if_not_silent!(
+                "  Would require getIdentity on {} to stop reporting {}",
+                node.node.label,
+                funded
+            );
+            if let Some(command) = &self.switch_config.fencing_command {
+                println_if_not_silent!("  Fallback fencing command: {}", command);
+            }
+            return Ok(());
+        }
+
+        let ssh_key = self.get_ssh_key_for_node(&node.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(node.validator_type.clone(), None);
+        let deadline =
+            Instant::now() + Duration::from_secs(self.switch_config.fencing_timeout_seconds);
+
+        // Poll until the identity changes; only RPC failures lead to the fencing command
+        let last_check = loop {
+            let check =
+                crate::validator_rpc::get_identity(&self.ssh_pool, &node.node, &ssh_key, rpc_port)
+                    .await;
+            if let Ok(identity) = &check {
+                match check_fenced_identity(identity, &funded, self.unfunded_pubkey.as_deref()) {
+                    FenceCheck::Fenced => {
+                        println_if_not_silent!(
+                            "  ✅ {} reports its unfunded identity {}",
+                            node.node.label,
+                            identity
+                        );
+                        return Ok(());
+                    }
+                    FenceCheck::UnexpectedIdentity => {
+                        println_if_not_silent!(
+                            "  ⚠️  {} reports {} (not the expected unfunded pubkey) - funded identity is gone",
+                            node.node.label,
+                            identity
+                        );
+                        return Ok(());
+                    }
+                    FenceCheck::StillFunded => {}
+                }
             }
+
+            if Instant::now() >= deadline {
+                break check;
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        };
+
+        match last_check {
+            Ok(identity) => Err(anyhow!(
+                "Old primary {} still reports the funded identity {} after set-identity",
+                node.node.label,
+                identity
+            )),
+            Err(e) => self.run_fencing_command(&node.node, e).await,
+        }
+    }
+
+    /// Fall back to the configured fencing command when the old primary's RPC
+    /// cannot be queried. The command runs locally with SVS_FENCE_LABEL,
+    /// SVS_FENCE_HOST and SVS_FENCE_USER set to the old primary.
+    async fn run_fencing_command(
+        &self,
+        node: &crate::types::NodeConfig,
+        rpc_error: anyhow::Error,
+    ) -> Result<()> {
+        let Some(command) = self.switch_config.fencing_command.as_deref() else {
+            return Err(anyhow!(
+                "Cannot confirm old primary {} dropped the funded identity ({}) and no fencing_command is configured",
+                node.label,
+                rpc_error
+            ));
         };
 
-        println_if_not_silent!("{}", subtitle.dimmed());
         println_if_not_silent!(
-            "ssh {}@{} '{}'",
-            self.active_node_with_status.node.user,
-            self.active_node_with_status.node.host,
-            switch_command
+            "  ⚠️  getIdentity on {} failed ({}) - running fencing command",
+            node.label,
+            rpc_error
         );
+        println_if_not_silent!("  $ {}", command);
+
+        let output = tokio::time::timeout(
+            Duration::from_secs(FENCING_COMMAND_TIMEOUT_SECONDS),
+            tokio::process::Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .env("SVS_FENCE_LABEL", &node.label)
+                .env("SVS_FENCE_HOST", &node.host)
+                .env("SVS_FENCE_USER", &node.user)
+                .output(),
+        )
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "Fencing command timed out after {}s",
+                FENCING_COMMAND_TIMEOUT_SECONDS
+            )
+        })?
+        .map_err(|e| anyhow!("Failed to run fencing command: {}", e))?;
 
-        if !dry_run {
-            let spinner =
-                ConditionalSpinner::new("Switching active validator to unfunded identity...");
-            {
-                let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
-                let pool = self.ssh_pool.clone();
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Fencing command failed ({}): {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
 
-                // Execute the switch command based on validator type
-                match self.active_node_with_status.validator_type {
-                    crate::types::ValidatorType::Firedancer => {
-                        // Firedancer: fdctl set-identity --config <config> <identity>
-                        let fdctl_path =
-                            crate::executable_utils::get_fdctl_path(&self.active_node_with_status)?;
-                        let config_path = self
-                            .get_firedancer_config_path(&self.active_node_with_status)
-                            .await?;
-
-                        let args = vec![
-                            "set-identity",
-                            "--config",
-                            &config_path,
-                            &self.active_node_with_status.node.paths.unfunded_identity,
-                        ];
-
-                        pool.execute_command_with_args(
-                            &self.active_node_with_status.node,
-                            &ssh_key,
-                            &fdctl_path,
-                            &args,
-                        )
-                        .await?;
-                    }
-                    crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
-                        // Agave: agave-validator -l <ledger> set-identity <identity>
-                        let agave_path = self
-                            .active_node_with_status
-                            .agave_validator_executable
-                            .as_ref()
-                            .unwrap();
-                        let ledger_path = self.active_node_with_status.ledger_path.as_ref().unwrap();
-
-                        let args = vec![
-                            "-l",
-                            ledger_path,
-                            "set-identity",
-                            &self.active_node_with_status.node.paths.unfunded_identity,
-                        ];
-
-                        pool.execute_command_with_args(
-                            &self.active_node_with_status.node,
-                            &ssh_key,
-                            agave_path,
-                            &args,
-                        )
-                        .await?;
-                    }
-                    _ => {
-                        return Err(anyhow!("Unsupported validator type for set-identity"));
-                    }
+        println_if_not_silent!("  ✅ Fencing command succeeded - old primary fenced");
+        Ok(())
+    }
+
+    /// Roll Step 1 back after a later step failed and return the error to report:
+    /// the classified step error, or a partial switch if the rollback failed too
+    async fn roll_back_demotion(
+        &mut self,
+        e: anyhow::Error,
+        dry_run: bool,
+        headline: &str,
+        operation: &str,
+        host: &str,
+    ) -> anyhow::Error {
+        if !dry_run {
+            println_if_not_silent!("\n{}", headline.bright_red().bold());
+            self.record_journal_step(SwitchStep::RollingBack, Some(e.to_string()));
+            if let Err(rollback_err) = self.rollback_primary_to_funded().await {
+                self.record_journal_step(
+                    SwitchStep::RollingBack,
+                    Some(format!("Rollback failed: {}", rollback_err)),
+                );
+                // CRITICAL: Both forward and rollback failed
+                eprintln!(
+                    "\n{}",
+                    "🚨 CRITICAL: Rollback failed! Validator may be in inconsistent state!"
+                        .bright_red()
+                        .bold()
+                );
+                eprintln!("   Original error: {}", e);
+                eprintln!("   Rollback error: {}", rollback_err);
+                eprintln!(
+                    "   ⚠️  MANUAL INTERVENTION REQUIRED: Check validator status on both nodes!"
+                );
+                return SwitchError::PartialSwitch {
+                    active_status: format!(
+                        "{} left on unfunded identity (rollback failed: {})",
+                        self.active_node_with_status.node.label, rollback_err
+                    ),
+                    standby_status: format!(
+                        "{} not promoted ({})",
+                        self.standby_node_with_status.node.label, e
+                    ),
                 }
+                .into();
             }
-            // No sleep - move immediately to next step!
-            spinner.stop_with_message("✅ Active validator switched to unfunded identity");
+            println_if_not_silent!(
+                "{}",
+                "   ✓ Rollback successful - active node restored to funded identity"
+                    .bright_green()
+            );
+            self.finish_journal();
         }
-
-        Ok(())
+        classify_step_error(e, operation, host)
     }
 
     /// Rollback method: Switch the active node back to funded identity
@@ -980,71 +2151,99 @@ impl SwitchManager {
             "   Rollback: Switching {} back to funded identity...",
             self.active_node_with_status.node.label
         );
-        let pool = self.ssh_pool.clone();
+        let command = self
+            .strategy_command(&self.active_node_with_status, IdentityRole::Funded)
+            .await?;
+        self.run_strategy_command(&self.active_node_with_status.node, &ssh_key, &command)
+            .await
+    }
 
-        // Use already-detected validator type instead of re-parsing ps aux
-        match self.active_node_with_status.validator_type {
-            crate::types::ValidatorType::Firedancer => {
-                // Firedancer: fdctl set-identity --config <config> <identity>
-                let fdctl_path =
-                    crate::executable_utils::get_fdctl_path(&self.active_node_with_status)?;
-                let config_path = self
-                    .get_firedancer_config_path(&self.active_node_with_status)
-                    .await?;
-
-                let args = vec![
-                    "set-identity",
-                    "--config",
-                    &config_path,
-                    &self.active_node_with_status.node.paths.funded_identity,
-                ];
-
-                pool.execute_command_with_args(
-                    &self.active_node_with_status.node,
-                    &ssh_key,
-                    &fdctl_path,
-                    &args,
-                )
-                .await?;
-            }
-            crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
-                // Agave: agave-validator -l <ledger> set-identity <identity>
-                let agave_path = self
-                    .active_node_with_status
-                    .agave_validator_executable
-                    .as_ref()
-                    .ok_or_else(|| {
-                        anyhow!("Agave validator executable path not found for rollback")
-                    })?;
-                let ledger_path = self
-                    .active_node_with_status
-                    .ledger_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Ledger path not found for rollback"))?;
-
-                let args = vec![
-                    "-l",
-                    ledger_path,
-                    "set-identity",
-                    &self.active_node_with_status.node.paths.funded_identity,
-                ];
-
-                pool.execute_command_with_args(
-                    &self.active_node_with_status.node,
-                    &ssh_key,
-                    agave_path,
-                    &args,
-                )
-                .await?;
+    /// Switch the standby node back to its unfunded identity
+    /// Used when rolling back a switch that may have already promoted the standby
+    async fn demote_standby_to_unfunded(&mut self) -> Result<()> {
+        let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+
+        println_if_not_silent!(
+            "   Demoting {} to unfunded identity...",
+            self.standby_node_with_status.node.label
+        );
+
+        let command = self
+            .strategy_command(&self.standby_node_with_status, IdentityRole::Unfunded)
+            .await?;
+        self.run_strategy_command(&self.standby_node_with_status.node, &ssh_key, &command)
+            .await
+    }
+
+    /// Continue an interrupted switch from the step recorded in its journal,
+    /// either finishing it (`forward`) or restoring the original active node
+    async fn resume_from_journal(&mut self, journal: SwitchJournal, forward: bool) -> Result<()> {
+        let step = journal.step;
+        self.journal = Some(journal);
+
+        let result = if forward {
+            self.resume_forward(step).await
+        } else {
+            self.resume_rollback(step).await
+        };
+
+        match result {
+            Ok(()) => {
+                self.finish_journal();
+                Ok(())
             }
-            _ => {
-                return Err(anyhow!(
-                    "Unsupported validator type for rollback set-identity"
-                ));
+            Err(e) => {
+                let current_step = self.journal.as_ref().map(|j| j.step).unwrap_or(step);
+                self.record_journal_step(current_step, Some(e.to_string()));
+                Err(e)
             }
         }
+    }
 
-        Ok(())
+    async fn resume_forward(&mut self, step: SwitchStep) -> Result<()> {
+        if step.needs_demotion_on_resume() {
+            println_if_not_silent!(
+                "\n{}",
+                "🔄 Step 1: Switch Active Node to Unfunded Identity"
+                    .bright_blue()
+                    .bold()
+            );
+            self.record_journal_step(SwitchStep::DemotingPrimary, None);
+            self.switch_primary_to_unfunded(false).await?;
+            self.record_journal_step(SwitchStep::PrimaryDemoted, None);
+        }
+
+        // The old primary may have come back with the funded identity since the crash
+        self.fence_old_primary(false).await?;
+
+        if step.needs_tower_on_resume() {
+            println_if_not_silent!(
+                "\n{}",
+                "📤 Step 2: Transfer Tower File".bright_blue().bold()
+            );
+            self.record_journal_step(SwitchStep::TransferringTower, None);
+            self.transfer_tower_file(false).await?;
+            self.record_journal_step(SwitchStep::TowerTransferred, None);
+        }
+
+        println_if_not_silent!(
+            "\n{}",
+            "🚀 Step 3: Switch Standby Node to Funded Identity"
+                .bright_blue()
+                .bold()
+        );
+        self.record_journal_step(SwitchStep::PromotingStandby, None);
+        self.switch_backup_to_funded(false).await
+    }
+
+    async fn resume_rollback(&mut self, step: SwitchStep) -> Result<()> {
+        self.record_journal_step(SwitchStep::RollingBack, None);
+
+        // Never leave two nodes on the funded identity
+        if step.standby_may_be_funded() {
+            self.demote_standby_to_unfunded().await?;
+        }
+        self.rollback_primary_to_funded().await
     }
 
     /// Force a fresh SSH connection by removing existing session from pool
@@ -1070,9 +2269,12 @@ impl SwitchManager {
             purpose
         );
 
-        let _ = pool
-            .get_session(&self.standby_node_with_status.node, &standby_ssh_key)
-            .await?;
+        pool.get_session(&self.standby_node_with_status.node, &standby_ssh_key)
+            .await
+            .map_err(|e| SwitchError::SshConnectionFailed {
+                host: self.standby_node_with_status.node.host.clone(),
+                details: e.to_string(),
+            })?;
 
         Ok(())
     }
@@ -1085,9 +2287,19 @@ impl SwitchManager {
             .as_ref()
             .ok_or_else(|| anyhow!("Tower path not available for active node"))?;
 
+        // A pipelined Step 1 already checked for and read the tower
+        let pipelined_tower = if dry_run {
+            None
+        } else {
+            self.pipelined_tower.take().transpose()?
+        };
+        let read_in_step_one = pipelined_tower.is_some();
+
         // Verify the tower file exists
         let check_tower_cmd = format!("test -f {} && echo 'exists' || echo 'missing'", tower_path);
-        let tower_exists = {
+        let tower_exists = if pipelined_tower.is_some() {
+            "exists".to_string()
+        } else {
             let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
             let pool = self.ssh_pool.clone();
             pool.execute_command(
@@ -1099,10 +2311,10 @@ impl SwitchManager {
         };
 
         if tower_exists.trim() != "exists" {
-            return Err(anyhow!(
-                "Tower file not found on active node: {}",
-                tower_path
-            ));
+            return Err(SwitchError::TowerFileNotFound {
+                path: tower_path.clone(),
+            }
+            .into());
         }
 
         let tower_filename = tower_path.split('/').last().unwrap_or("tower.bin");
@@ -1127,14 +2339,54 @@ impl SwitchManager {
 
         let start_time = Instant::now();
 
+        // Relay mode: read the tower from the active node once, check it and
+        // write it to the standby with one command, skipping the base64 round trips
+        let relay_mode = !dry_run
+            && self.switch_config.tower_transfer_mode == crate::types::TowerTransferMode::Relay;
+        let relayed = if relay_mode {
+            let ssh_key_active =
+                self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+            let ssh_key_standby =
+                self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+            let relay_start = Instant::now();
+            // A refused tower is never written to the standby
+            let mut tower_last_vote = None;
+            let (copied_bytes, checksum) = self
+                .ssh_pool
+                .copy_checked_file_between_nodes(
+                    &self.active_node_with_status.node,
+                    &ssh_key_active,
+                    tower_path,
+                    &self.standby_node_with_status.node,
+                    &ssh_key_standby,
+                    &dest_path,
+                    crate::tower_file::MAX_TOWER_FILE_BYTES,
+                    |tower_bytes| {
+                        tower_last_vote = self.check_tower_contents(tower_bytes)?;
+                        Ok(())
+                    },
+                )
+                .await
+                .map_err(|e| anyhow!("Failed to copy tower file: {}", e))?;
+            self.tower_last_vote = tower_last_vote;
+            let relay_ms = relay_start.elapsed().as_secs_f64() * 1000.0;
+            Some((copied_bytes as u64, checksum, relay_ms))
+        } else {
+            None
+        };
+
         // Execute the streaming transfer using base64 encoding
             // Read base64 from source and transfer separately, measuring each phase
-            let (encoded_data, read_ms, transfer_ms, decoded_bytes) = if !dry_run {
+            let (encoded_data, read_ms, transfer_ms, decoded_bytes) = if let Some((bytes, _, relay_ms)) = &relayed {
+                (String::new(), 0.0, *relay_ms, *bytes)
+            } else if !dry_run {
                 // Read base64 from active
                 let read_start = Instant::now();
                 let ssh_key_active =
                     self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
-                let data = {
+                let data = if let Some(data) = pipelined_tower {
+                    data
+                } else {
                     let pool = self.ssh_pool.clone();
                     let base64_args = vec![tower_path.as_str()];
                     match pool
@@ -1154,6 +2406,9 @@ impl SwitchManager {
                 };
                 let read_duration = read_start.elapsed();
 
+                // Refuse to copy a tower for the wrong identity or a stale one
+                self.tower_last_vote = self.check_tower_contents(&decode_base64_payload(&data)?)?;
+
                 // Transfer to standby and measure
                 let transfer_start = Instant::now();
                 let ssh_key_standby =
@@ -1183,13 +2438,19 @@ impl SwitchManager {
         self.tower_transfer_time = Some(transfer_duration);
 
         // Calculate transfer speed
-        let file_size = encoded_data.len() as u64 * 3 / 4; // approximate original size from base64
+        let file_size = match &relayed {
+            Some((bytes, _, _)) => *bytes,
+            None => encoded_data.len() as u64 * 3 / 4, // approximate original size from base64
+        };
         let speed_mbps = (file_size as f64 / 1024.0 / 1024.0) / transfer_duration.as_secs_f64();
 
         if !dry_run {
             let spinner = ConditionalSpinner::new("Verifying tower file integrity...");
             // Calculate SHA256 checksum from the exact bytes that were transferred.
-            let source_checksum = sha256_hex(&decode_base64_payload(&encoded_data)?);
+            let source_checksum = match &relayed {
+                Some((_, checksum, _)) => checksum.clone(),
+                None => sha256_hex(&decode_base64_payload(&encoded_data)?),
+            };
 
             // Calculate SHA256 checksum on destination (standby node)
             let dest_checksum = {
@@ -1236,171 +2497,197 @@ impl SwitchManager {
         }
 
         // Print detailed per-phase timings for debugging (visible in both dry-run and live modes)
-        println_if_not_silent!(
-            "   ▸ Read: {:.1}ms, Transfer: {:.1}ms, Total: {}ms, Bytes: {}",
-            read_ms,
-            transfer_ms,
-            transfer_duration.as_millis(),
-            decoded_bytes
-        );
+        if relayed.is_some() {
+            println_if_not_silent!(
+                "   ▸ Relay: {:.1}ms, Total: {}ms, Bytes: {}",
+                transfer_ms,
+                transfer_duration.as_millis(),
+                decoded_bytes
+            );
+        } else if read_in_step_one {
+            println_if_not_silent!(
+                "   ▸ Read: with Step 1, Transfer: {:.1}ms, Total: {}ms, Bytes: {}",
+                transfer_ms,
+                transfer_duration.as_millis(),
+                decoded_bytes
+            );
+        } else {
+            println_if_not_silent!(
+                "   ▸ Read: {:.1}ms, Transfer: {:.1}ms, Total: {}ms, Bytes: {}",
+                read_ms,
+                transfer_ms,
+                transfer_duration.as_millis(),
+                decoded_bytes
+            );
+        }
 
         Ok(())
     }
 
-    pub(crate) async fn switch_backup_to_funded(&mut self, dry_run: bool) -> Result<()> {
-        // Use already-detected validator type instead of re-parsing ps aux
-        let (subtitle, switch_command) = match self.standby_node_with_status.validator_type {
-            crate::types::ValidatorType::Firedancer => {
-                // Get fdctl executable path from config
-                let fdctl_path =
-                    crate::executable_utils::get_fdctl_path(&self.standby_node_with_status)?;
-                let config_path = self
-                    .get_firedancer_config_path(&self.standby_node_with_status)
-                    .await?;
-
-                (
-                    "Using Firedancer fdctl set-identity",
-                    format!(
-                        "{} set-identity --config \"{}\" \"{}\"",
-                        fdctl_path,
-                        config_path,
-                        self.standby_node_with_status.node.paths.funded_identity
-                    ),
-                )
-            }
-            crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
-                // Use detected agave executable path if available
-                let agave_path = self
-                    .standby_node_with_status
-                    .agave_validator_executable
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Agave validator executable path not found"))?;
-
-                // Use detected ledger path if available, otherwise error
-                let ledger_path = self
-                    .standby_node_with_status
-                    .ledger_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Ledger path not detected for standby node"))?;
-
-                (
-                    "Using Agave validator set-identity",
-                    format!(
-                        "{} -l \"{}\" set-identity \"{}\"",
-                        agave_path,
-                        ledger_path,
-                        self.standby_node_with_status.node.paths.funded_identity
-                    ),
+    /// Parse the tower and check it belongs to the funded identity and is not
+    /// stale compared to the on-chain last vote captured before Step 1. Returns
+    /// the tower's last voted slot when it could be parsed.
+    fn check_tower_contents(&self, tower_bytes: &[u8]) -> Result<Option<u64>> {
+        if !self.switch_config.validate_tower {
+            // Step 5 still uses the old primary's last vote as its floor
+            return Ok(crate::tower_file::parse_tower_file(tower_bytes)
+                .ok()
+                .and_then(|summary| summary.last_voted_slot));
+        }
+
+        match crate::tower_file::parse_tower_file(tower_bytes) {
+            Ok(summary) => {
+                println_if_not_silent!(
+                    "   Tower: last voted slot {}, root {}",
+                    summary
+                        .last_voted_slot
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| "none".to_string()),
+                    summary
+                        .root_slot
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| "none".to_string())
+                );
+                crate::tower_file::validate_tower(
+                    &summary,
+                    &self.validator_pair.identity_pubkey,
+                    self.onchain_last_vote,
+                    self.switch_config.tower_max_staleness_slots,
                 )
+                .map_err(|e| anyhow!("Refusing tower transfer: {}", e))?;
+                Ok(summary.last_voted_slot)
             }
-            _ => {
-                // Use detected ledger path if available, otherwise error
-                let ledger_path = self
-                    .standby_node_with_status
-                    .ledger_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Ledger path not detected for standby node"))?;
-
-                (
-                    "Using Solana validator restart",
-                    format!("{} exit && solana-validator --identity {} --vote-account {} --ledger {} --limit-ledger-size 100000000 --log - &",
-                        "solana-validator",
-                        self.standby_node_with_status.node.paths.funded_identity,
-                        self.standby_node_with_status.node.paths.vote_keypair,
-                        ledger_path)
-                )
+            Err(e) => {
+                // Unknown formats (e.g. newer clients) are still copied and checksummed
+                println_if_not_silent!(
+                    "   ⚠️  Could not parse tower file ({}) - skipping content validation",
+                    e
+                );
+                Ok(None)
             }
-        };
+        }
+    }
+
+    pub(crate) async fn switch_backup_to_funded(&mut self, dry_run: bool) -> Result<()> {
+        let command = self
+            .strategy_command(&self.standby_node_with_status, IdentityRole::Funded)
+            .await?;
 
-        println_if_not_silent!("{}", subtitle.dimmed());
+        println_if_not_silent!("{}", command.subtitle.dimmed());
         println_if_not_silent!(
             "ssh {}@{} '{}'",
             self.standby_node_with_status.node.user,
             self.standby_node_with_status.node.host,
-            switch_command
+            command.display
         );
 
+        // Tower enforcement only makes sense when the identity (and its tower) moves
+        let require_tower = self.switch_config.require_tower
+            && resolve_strategy(&self.standby_node_with_status)
+                == crate::types::SwitchStrategy::SetIdentity;
+        let firedancer = self.standby_node_with_status.validator_type
+            == crate::types::ValidatorType::Firedancer;
+        if require_tower && firedancer && dry_run {
+            println_if_not_silent!(
+                "  Would require {} on the standby before set-identity",
+                self.standby_tower_path()
+            );
+        }
+
         if !dry_run {
+            if require_tower && firedancer {
+                self.check_standby_tower().await?;
+            }
+
             let spinner =
                 ConditionalSpinner::new("Switching standby validator to funded identity...");
+            let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+            let cmd_start = Instant::now();
+            if let Err(e) = self
+                .run_strategy_command(&self.standby_node_with_status.node, &ssh_key, &command)
+                .await
             {
-                let ssh_key =
-                    self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
-                let pool = self.ssh_pool.clone();
-
-                // Execute the switch command based on validator type
-                match self.standby_node_with_status.validator_type {
-                    crate::types::ValidatorType::Firedancer => {
-                        // Firedancer: fdctl set-identity --config <config> <identity>
-                        let fdctl_path =
-                            crate::executable_utils::get_fdctl_path(&self.standby_node_with_status)?;
-                        let config_path = self
-                            .get_firedancer_config_path(&self.standby_node_with_status)
-                            .await?;
-
-                        let args = vec![
-                            "set-identity",
-                            "--config",
-                            &config_path,
-                            &self.standby_node_with_status.node.paths.funded_identity,
-                        ];
-
-                        let cmd_start = Instant::now();
-                        pool.execute_command_with_args(
-                            &self.standby_node_with_status.node,
-                            &ssh_key,
-                            &fdctl_path,
-                            &args,
-                        )
-                        .await?;
-                        let cmd_elapsed = cmd_start.elapsed();
-                        println_if_not_silent!(
-                            "   ▸ standby set-identity command took {:.1}ms",
-                            cmd_elapsed.as_secs_f64() * 1000.0
-                        );
-                    }
-                    crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
-                        // Agave: agave-validator -l <ledger> set-identity --require-tower <identity>
-                        let agave_path = self
-                            .standby_node_with_status
-                            .agave_validator_executable
-                            .as_ref()
-                            .unwrap();
-                        let ledger_path = self.standby_node_with_status.ledger_path.as_ref().unwrap();
-
-                        let args = vec![
-                            "-l",
-                            ledger_path,
-                            "set-identity",
-                            &self.standby_node_with_status.node.paths.funded_identity,
-                        ];
-
-                        let cmd_start = Instant::now();
-                        pool.execute_command_with_args(
-                            &self.standby_node_with_status.node,
-                            &ssh_key,
-                            agave_path,
-                            &args,
-                        )
-                        .await?;
-                        let cmd_elapsed = cmd_start.elapsed();
-                        println_if_not_silent!(
-                            "   ▸ standby set-identity command took {:.1}ms",
-                            cmd_elapsed.as_secs_f64() * 1000.0
-                        );
-                    }
-                    _ => {
-                        return Err(anyhow!("Unsupported validator type for set-identity"));
+                // The --require-tower promotion reports its exit status; a refusal
+                // is a tower problem when the transferred tower is not on the standby
+                if require_tower && !firedancer && command.reports_exit_code {
+                    println_if_not_silent!("   ❌ Standby refused the promotion: {}", e);
+                    if let Err(tower_err) = self.check_standby_tower().await {
+                        if find_switch_error(&tower_err).is_some() {
+                            return Err(tower_err);
+                        }
                     }
                 }
+                return Err(e);
+            }
+            println_if_not_silent!(
+                "   ▸ standby switch command took {:.1}ms",
+                cmd_start.elapsed().as_secs_f64() * 1000.0
+            );
+
+            // Only set-identity changes what getIdentity reports
+            if resolve_strategy(&self.standby_node_with_status)
+                == crate::types::SwitchStrategy::SetIdentity
+            {
+                self.confirm_standby_identity().await?;
             }
-            // No sleep - switch is complete!
             spinner.stop_with_message("✅ Standby validator switched to funded identity");
         }
 
         Ok(())
     }
 
+    /// Require getIdentity on the promoted standby to report the funded identity.
+    /// If it cannot be queried the standby is demoted again, so that rolling Step 1
+    /// back cannot leave two nodes on the funded identity.
+    async fn confirm_standby_identity(&mut self) -> Result<()> {
+        let node = self.standby_node_with_status.clone();
+        let funded = self.validator_pair.identity_pubkey.clone();
+        let ssh_key = self.get_ssh_key_for_node(&node.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(node.validator_type.clone(), None);
+        let deadline = Instant::now() + Duration::from_secs(PROMOTION_CONFIRM_TIMEOUT_SECONDS);
+
+        let reason = loop {
+            let check =
+                crate::validator_rpc::get_identity(&self.ssh_pool, &node.node, &ssh_key, rpc_port)
+                    .await;
+            match check_promoted_identity(&check, &funded) {
+                PromotionCheck::Confirmed => {
+                    println_if_not_silent!("   ✓ {} reports the funded identity", node.node.label);
+                    return Ok(());
+                }
+                PromotionCheck::Unconfirmed(reason) if Instant::now() >= deadline => break reason,
+                PromotionCheck::Unconfirmed(_) => {}
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        };
+
+        // Whatever it reports now, a late set-identity must not leave the standby
+        // funded once the rollback restores the old primary
+        println_if_not_silent!(
+            "   ⚠️  Could not confirm {} took the funded identity ({}) - demoting it again",
+            node.node.label,
+            reason
+        );
+        if let Err(demote_err) = self.demote_standby_to_unfunded().await {
+            return Err(SwitchError::PartialSwitch {
+                active_status: format!(
+                    "{} on unfunded identity",
+                    self.active_node_with_status.node.label
+                ),
+                standby_status: format!(
+                    "{} may hold the funded identity ({}; demotion failed: {})",
+                    node.node.label, reason, demote_err
+                ),
+            }
+            .into());
+        }
+        Err(anyhow!(
+            "Could not confirm {} took the funded identity: {}",
+            node.node.label,
+            reason
+        ))
+    }
+
     async fn verify_backup_catchup(&mut self, dry_run: bool) -> Result<()> {
         println_if_not_silent!("Verifying health status of new active validator...");
 
@@ -1450,6 +2737,274 @@ impl SwitchManager {
         Ok(())
     }
 
+    /// The vote account's confirmed `last_vote` at the end of Step 3, when Step 5 will run
+    async fn vote_landing_baseline(&self, dry_run: bool) -> Option<u64> {
+        if dry_run || !self.switch_config.verify_vote_landing {
+            return None;
+        }
+        crate::solana_rpc::fetch_last_vote(
+            &self.validator_pair.rpc,
+            &self.validator_pair.vote_pubkey,
+            CommitmentConfig::confirmed(),
+        )
+        .await
+        .ok()
+    }
+
+    /// Poll the cluster (confirmed commitment) until the vote account's
+    /// `last_vote` passes the old primary's last vote, recording the time to
+    /// first landed vote from `promoted_at`
+    async fn verify_vote_landing(
+        &mut self,
+        dry_run: bool,
+        promoted_at: Instant,
+        baseline: Option<u64>,
+    ) -> Result<()> {
+        let timeout = Duration::from_secs(self.switch_config.vote_landing_timeout_seconds);
+        let rpc_url = self.validator_pair.rpc.clone();
+        let vote_pubkey = self.validator_pair.vote_pubkey.clone();
+
+        if dry_run {
+            println_if_not_silent!(
+                "Would poll vote account {} via {} for up to {}s",
+                vote_pubkey,
+                rpc_url,
+                timeout.as_secs()
+            );
+            return Ok(());
+        }
+
+        let spinner = ConditionalSpinner::new("Waiting for a new vote to land on-chain...");
+
+        // Votes up to the old primary's last one (from the tower or captured
+        // before Step 1) may still land after promotion and must not count;
+        // if none is known, the first successful poll becomes the floor
+        let mut baseline =
+            vote_landing_floor(&[self.tower_last_vote, self.pre_switch_last_vote, baseline]);
+        let mut last_error: Option<String> = None;
+
+        while promoted_at.elapsed() < timeout {
+            match crate::solana_rpc::fetch_last_vote(
+                &rpc_url,
+                &vote_pubkey,
+                CommitmentConfig::confirmed(),
+            )
+            .await
+            {
+                Ok(last_vote) => match baseline {
+                    None => baseline = Some(last_vote),
+                    Some(previous) if last_vote > previous => {
+                        let elapsed = promoted_at.elapsed();
+                        self.first_vote_time = Some(elapsed);
+                        spinner.stop_with_message(&format!(
+                            "✅ New vote landed on-chain (slot {}) {} after promotion",
+                            last_vote,
+                            format!("{}ms", elapsed.as_millis()).bright_green().bold()
+                        ));
+                        return Ok(());
+                    }
+                    Some(_) => {}
+                },
+                Err(e) => last_error = Some(e.to_string()),
+            }
+
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+
+        spinner.stop_with_message(&format!(
+            "❌ No new vote landed within {}s",
+            timeout.as_secs()
+        ));
+
+        let detail = match (baseline, last_error) {
+            (Some(slot), _) => format!("no vote past slot {}", slot),
+            (None, Some(e)) => format!("vote account could not be fetched: {}", e),
+            (None, None) => "vote account could not be fetched".to_string(),
+        };
+        self.vote_landing_failure = Some(detail.clone());
+        Err(anyhow!(
+            "Vote verification failed: {} did not land a vote within {}s after promotion ({})",
+            self.standby_node_with_status.node.label,
+            timeout.as_secs(),
+            detail
+        ))
+    }
+
+    /// Poll getHealth on the new active node until it reports healthy or the
+    /// failback deadline (measured from promotion) passes
+    async fn wait_for_new_active_health(&self, promoted_at: Instant) -> Result<()> {
+        let deadline = Duration::from_secs(self.switch_config.failback_deadline_seconds);
+        let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(
+            self.standby_node_with_status.validator_type.clone(),
+            None,
+        );
+
+        let spinner = ConditionalSpinner::new(&format!(
+            "Auto-failback armed: waiting up to {}s for new active node to report healthy...",
+            deadline.as_secs()
+        ));
+
+        let mut last_error: Option<String>;
+        loop {
+            match crate::validator_rpc::get_health(
+                &self.ssh_pool,
+                &self.standby_node_with_status.node,
+                &ssh_key,
+                rpc_port,
+            )
+            .await
+            {
+                Ok(true) => {
+                    spinner.stop_with_message("✅ New active validator reports healthy");
+                    return Ok(());
+                }
+                Ok(false) => last_error = None,
+                Err(e) => last_error = Some(e.to_string()),
+            }
+
+            if promoted_at.elapsed() >= deadline {
+                break;
+            }
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+
+        spinner.stop_with_message(&format!(
+            "❌ New active validator not healthy after {}s",
+            deadline.as_secs()
+        ));
+        Err(anyhow!(
+            "Health verification failed: {} did not report healthy within {}s{}",
+            self.standby_node_with_status.node.label,
+            deadline.as_secs(),
+            last_error
+                .map(|e| format!(" (last error: {})", e))
+                .unwrap_or_default()
+        ))
+    }
+
+    /// Undo a completed switch whose post-switch verification failed: demote the
+    /// new active node, copy its tower back and re-promote the original node.
+    /// Returns the error to report for the (failed) switch.
+    async fn failback_to_original(&mut self, reason: anyhow::Error) -> anyhow::Error {
+        println_if_not_silent!(
+            "\n{}",
+            format!(
+                "⏪ Auto-failback: {} failed verification, restoring {}",
+                self.standby_node_with_status.node.label, self.active_node_with_status.node.label
+            )
+            .bright_red()
+            .bold()
+        );
+
+        // Journal the failback as a switch in the opposite direction
+        self.journal = Some(SwitchJournal::new(
+            &self.validator_pair,
+            &self.standby_node_with_status.node,
+            &self.active_node_with_status.node,
+        ));
+        self.record_journal_step(SwitchStep::DemotingPrimary, Some(reason.to_string()));
+
+        if let Err(e) = self.demote_standby_to_unfunded().await {
+            return self.failback_failed(reason, "demote new active node", e);
+        }
+        self.record_journal_step(SwitchStep::PrimaryDemoted, None);
+
+        // Reverse direction for fencing and the tower copy, keeping the forward
+        // switch timings. The resolved unfunded pubkey belongs to the original
+        // node, so fencing only checks the funded identity is gone.
+        let forward_tower_time = self.tower_transfer_time;
+        let forward_unfunded_pubkey = self.unfunded_pubkey.take();
+        std::mem::swap(
+            &mut self.active_node_with_status,
+            &mut self.standby_node_with_status,
+        );
+        let fence_result = self.fence_old_primary(false).await;
+        let tower_result = if fence_result.is_ok() {
+            self.record_journal_step(SwitchStep::TransferringTower, None);
+            self.transfer_tower_file(false).await
+        } else {
+            Ok(())
+        };
+        std::mem::swap(
+            &mut self.active_node_with_status,
+            &mut self.standby_node_with_status,
+        );
+        self.tower_transfer_time = forward_tower_time;
+        self.unfunded_pubkey = forward_unfunded_pubkey;
+        if let Err(e) = fence_result {
+            return self.failback_failed(reason, "fence new active node", e);
+        }
+        if let Err(e) = tower_result {
+            return self.failback_failed(reason, "copy tower back", e);
+        }
+        self.record_journal_step(SwitchStep::TowerTransferred, None);
+
+        self.record_journal_step(SwitchStep::PromotingStandby, None);
+        if let Err(e) = self.rollback_primary_to_funded().await {
+            return self.failback_failed(reason, "re-promote original node", e);
+        }
+        self.finish_journal();
+        self.failed_back = true;
+
+        println_if_not_silent!(
+            "{}",
+            format!(
+                "   ✓ Failback complete - {} is active again",
+                self.active_node_with_status.node.label
+            )
+            .bright_green()
+        );
+
+        anyhow!(
+            "{}. Auto-failback restored {} as active",
+            reason,
+            self.active_node_with_status.node.label
+        )
+    }
+
+    fn failback_failed(
+        &mut self,
+        reason: anyhow::Error,
+        phase: &str,
+        error: anyhow::Error,
+    ) -> anyhow::Error {
+        let current_step = self
+            .journal
+            .as_ref()
+            .map(|j| j.step)
+            .unwrap_or(SwitchStep::DemotingPrimary);
+        self.record_journal_step(current_step, Some(error.to_string()));
+
+        eprintln!(
+            "\n{}",
+            "🚨 CRITICAL: Auto-failback failed! Validator may be in inconsistent state!"
+                .bright_red()
+                .bold()
+        );
+        eprintln!("   Verification error: {}", reason);
+        eprintln!("   Failback error ({}): {}", phase, error);
+        eprintln!("   ⚠️  MANUAL INTERVENTION REQUIRED: Check validator status on both nodes!");
+
+        SwitchError::PartialSwitch {
+            active_status: format!(
+                "{} failed verification ({}), auto-failback failed to {}: {}",
+                self.standby_node_with_status.node.label, reason, phase, error
+            ),
+            standby_status: format!(
+                "{} may still be on its unfunded identity",
+                self.active_node_with_status.node.label
+            ),
+        }
+        .into()
+    }
+
+    /// True once Step 3 completed on a live switch and was not failed back,
+    /// i.e. the identities are swapped
+    fn identities_swapped(&self) -> bool {
+        self.identity_switch_time.is_some() && !self.failed_back
+    }
+
     fn print_summary(&self, dry_run: bool) {
         println_if_not_silent!();
         if dry_run {
@@ -1457,15 +3012,7 @@ impl SwitchManager {
             println_if_not_silent!();
             println_if_not_silent!("{}", "Press any key to continue...".dimmed());
             if !is_silent_mode() {
-                // Actually wait for ANY key press, not just Enter
-                use crossterm::event::{self, Event};
-                crossterm::terminal::enable_raw_mode().ok();
-                loop {
-                    if let Ok(Event::Key(_)) = event::read() {
-                        break;
-                    }
-                }
-                crossterm::terminal::disable_raw_mode().ok();
+                crate::terminal::wait_for_any_key();
             }
         } else {
             println_if_not_silent!("✅ Validator identity switch completed successfully");
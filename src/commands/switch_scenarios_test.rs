@@ -165,7 +165,7 @@ mod scenario_tests {
             let code = error.exit_code();
             assert!(exit_codes.insert(code), "Duplicate exit code: {}", code);
             assert!(
-                code >= 10 && code <= 20,
+                (10..=20).contains(&code),
                 "Exit code out of expected range: {}",
                 code
             );
@@ -212,4 +212,43 @@ mod scenario_tests {
             thread::sleep(Duration::from_millis(50));
         } // Should clean up automatically
     }
+
+    #[test]
+    fn test_exit_code_survives_error_context() {
+        use crate::commands::error_handler::{exit_code_for, SwitchError};
+
+        let error = anyhow::Error::new(SwitchError::TowerFileNotFound {
+            path: "/mnt/ledger/tower-1_9-abc.bin".to_string(),
+        })
+        .context("Switch failed");
+        assert_eq!(exit_code_for(&error), 11);
+        assert_eq!(exit_code_for(&anyhow::anyhow!("something else")), 1);
+    }
+
+    #[test]
+    fn test_step_errors_are_classified() {
+        use crate::commands::error_handler::{classify_step_error, exit_code_for};
+
+        let error = classify_step_error(
+            anyhow::anyhow!("Command failed: open /home/solana/funded.json: Permission denied"),
+            "switching identity",
+            "validator1-2.example.com",
+        );
+        assert_eq!(exit_code_for(&error), 13);
+        assert!(error.to_string().contains("/home/solana/funded.json"));
+
+        let error = classify_step_error(
+            anyhow::anyhow!("Failed to connect to solana@validator1-2.example.com: timed out"),
+            "switching identity",
+            "validator1-2.example.com",
+        );
+        assert_eq!(exit_code_for(&error), 10);
+
+        let error = classify_step_error(
+            anyhow::anyhow!("Unsupported validator type for set-identity"),
+            "switching identity",
+            "validator1-2.example.com",
+        );
+        assert_eq!(exit_code_for(&error), 1);
+    }
 }
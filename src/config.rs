@@ -69,6 +69,7 @@ impl ConfigManager {
             validators: Vec::new(),
             verbose_logging: false,
             alert_config: None,
+            switch_config: None,
         }
     }
 }
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::ssh::AsyncSshPool;
+use crate::types::{NodeWithStatus, ValidatorPair};
+
+/// Per-pair state of the double-signing guard
+#[derive(Debug, Clone, Default)]
+pub struct DoubleSignGuard {
+    /// The current incident has already been reported
+    pub alerted: bool,
+}
+
+/// More than one node reported the funded identity on the same tick
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoubleSignIncident {
+    pub funded_nodes: Vec<String>,
+}
+
+impl DoubleSignGuard {
+    /// Record which nodes reported the funded identity this tick. Returns the
+    /// incident when a double-signing state starts; it is reported once and
+    /// re-armed when at most one node holds the identity again.
+    pub fn observe(&mut self, funded_nodes: &[String]) -> Option<DoubleSignIncident> {
+        match funded_nodes {
+            [] | [_] => {
+                self.alerted = false;
+                None
+            }
+            _ if self.alerted => None,
+            _ => {
+                self.alerted = true;
+                Some(DoubleSignIncident {
+                    funded_nodes: funded_nodes.to_vec(),
+                })
+            }
+        }
+    }
+}
+
+impl DoubleSignIncident {
+    /// Nodes to demote. Which node last held the identity alone says nothing
+    /// about who is voting now (it may be the stalled old primary), so a node is
+    /// only kept when the vote account is advancing on-chain and it is the one
+    /// funded node that is healthy; the others cannot be landing those votes.
+    /// Otherwise nobody is demoted and the alert is left to the operator.
+    pub fn demotion_targets(&self, healthy_nodes: &[String], votes_landing: bool) -> Vec<String> {
+        if !votes_landing {
+            return Vec::new();
+        }
+        let healthy: Vec<&String> = self
+            .funded_nodes
+            .iter()
+            .filter(|label| healthy_nodes.contains(label))
+            .collect();
+        let [keep] = healthy.as_slice() else {
+            return Vec::new();
+        };
+        self.funded_nodes
+            .iter()
+            .filter(|label| label != keep)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Labels of the nodes whose `getIdentity` reports the funded identity.
+/// Nodes that cannot be queried are left out.
+pub async fn funded_nodes(
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+    validator_pair: &ValidatorPair,
+    nodes: &[NodeWithStatus],
+) -> Vec<String> {
+    let queries = nodes.iter().map(|node| async move {
+        let ssh_key = detected_ssh_keys.get(&node.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(node.validator_type.clone(), None);
+        let identity = crate::validator_rpc::get_identity(ssh_pool, &node.node, ssh_key, rpc_port)
+            .await
+            .ok()?;
+        (identity == validator_pair.identity_pubkey).then(|| node.node.label.clone())
+    });
+    futures::future::join_all(queries)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Labels of `nodes` whose `getHealth` reports ok
+pub async fn healthy_nodes(
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+    nodes: &[&NodeWithStatus],
+) -> Vec<String> {
+    let queries = nodes.iter().map(|node| async move {
+        let ssh_key = detected_ssh_keys.get(&node.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(node.validator_type.clone(), None);
+        crate::validator_rpc::get_health(ssh_pool, &node.node, ssh_key, rpc_port)
+            .await
+            .ok()
+            .filter(|healthy| *healthy)
+            .map(|_| node.node.label.clone())
+    });
+    futures::future::join_all(queries)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoubleSignGuard, DoubleSignIncident};
+
+    fn labels(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|label| label.to_string()).collect()
+    }
+
+    #[test]
+    fn reports_each_incident_once() {
+        let mut guard = DoubleSignGuard::default();
+        assert_eq!(guard.observe(&labels(&["a"])), None);
+
+        let incident = guard.observe(&labels(&["a", "b"])).unwrap();
+        assert_eq!(incident.funded_nodes, labels(&["a", "b"]));
+        assert_eq!(guard.observe(&labels(&["a", "b"])), None);
+
+        // Resolved, then a new incident is reported again
+        assert_eq!(guard.observe(&labels(&["a"])), None);
+        assert!(guard.observe(&labels(&["a", "b"])).is_some());
+    }
+
+    #[test]
+    fn keeps_the_only_healthy_node_while_votes_land() {
+        let incident = DoubleSignIncident {
+            funded_nodes: labels(&["a", "b", "c"]),
+        };
+        assert_eq!(
+            incident.demotion_targets(&labels(&["b"]), true),
+            labels(&["a", "c"])
+        );
+        assert!(incident
+            .demotion_targets(&labels(&["a", "b"]), true)
+            .is_empty());
+        assert!(incident.demotion_targets(&[], true).is_empty());
+    }
+
+    #[test]
+    fn demotes_nobody_when_no_votes_land() {
+        // The node that held the identity before may be the stalled one
+        let incident = DoubleSignIncident {
+            funded_nodes: labels(&["a", "b"]),
+        };
+        assert!(incident.demotion_targets(&labels(&["b"]), false).is_empty());
+    }
+}
@@ -6,7 +6,142 @@ use tokio::time::timeout;
 use crate::alert::AlertManager;
 use crate::commands::switch::SwitchManager;
 use crate::ssh::AsyncSshPool;
-use crate::types::{NodeWithStatus, ValidatorPair};
+use crate::switch_hooks::{run_hooks, HookContext};
+use crate::types::{HookPoint, NodeStatus, NodeWithStatus, SwitchConfig, ValidatorPair};
+
+/// Standbys within this many slots of the cluster are treated as equally caught up,
+/// so configured priority decides between them
+const CAUGHT_UP_SLOT_TOLERANCE: u64 = 10;
+
+/// A standby further behind the cluster than this is not promoted by an
+/// emergency takeover: it would not vote until it caught up anyway
+pub const MAX_TAKEOVER_SLOT_LAG: u64 = 150;
+
+/// Live snapshot of a standby node used to rank failover targets
+#[derive(Debug, Clone)]
+pub struct StandbyCandidate {
+    pub node_idx: usize,
+    pub healthy: bool,
+    pub slot_lag: Option<u64>,
+    pub priority: Option<u32>,
+}
+
+/// Order standby candidates from best to worst: healthy nodes first, then the
+/// smallest slot lag (unknown lag last), then the lowest configured priority,
+/// then config order. Returns node indices.
+pub fn rank_standby_candidates(candidates: &[StandbyCandidate]) -> Vec<usize> {
+    let mut ranked: Vec<&StandbyCandidate> = candidates.iter().collect();
+    ranked.sort_by_key(|c| {
+        let lag = match c.slot_lag {
+            Some(lag) if lag <= CAUGHT_UP_SLOT_TOLERANCE => 0,
+            Some(lag) => lag,
+            None => u64::MAX,
+        };
+        (!c.healthy, lag, c.priority.unwrap_or(u32::MAX), c.node_idx)
+    });
+    ranked.into_iter().map(|c| c.node_idx).collect()
+}
+
+/// Why a freshly probed standby must not be promoted; empty when it is fit
+pub fn standby_fitness_issues(
+    candidate: &StandbyCandidate,
+    swap_ready: bool,
+    swap_issues: &[String],
+) -> Vec<String> {
+    let mut issues = Vec::new();
+    if !candidate.healthy {
+        issues.push("getHealth is failing".to_string());
+    }
+    match candidate.slot_lag {
+        Some(lag) if lag > MAX_TAKEOVER_SLOT_LAG => issues.push(format!(
+            "{} slots behind the cluster (max {})",
+            lag, MAX_TAKEOVER_SLOT_LAG
+        )),
+        Some(_) => {}
+        None => issues.push("slot lag could not be determined".to_string()),
+    }
+    if !swap_ready {
+        if swap_issues.is_empty() {
+            issues.push("not swap ready".to_string());
+        } else {
+            issues.push(format!("not swap ready: {}", swap_issues.join("; ")));
+        }
+    }
+    issues
+}
+
+/// Probe one standby: getHealth and getSlot over SSH, slot lag against `cluster_slot`
+async fn probe_standby(
+    node_idx: usize,
+    node: &NodeWithStatus,
+    cluster_slot: Option<u64>,
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &std::collections::HashMap<String, String>,
+) -> StandbyCandidate {
+    let Some(ssh_key) = detected_ssh_keys.get(&node.node.host) else {
+        return StandbyCandidate {
+            node_idx,
+            healthy: false,
+            slot_lag: None,
+            priority: node.node.priority,
+        };
+    };
+    let rpc_port = crate::validator_rpc::get_rpc_port(node.validator_type.clone(), None);
+    let (healthy, slot) = tokio::join!(
+        crate::validator_rpc::get_health(ssh_pool, &node.node, ssh_key, rpc_port),
+        crate::validator_rpc::get_slot(ssh_pool, &node.node, ssh_key, rpc_port),
+    );
+    StandbyCandidate {
+        node_idx,
+        healthy: healthy.unwrap_or(false),
+        slot_lag: match (cluster_slot, slot) {
+            (Some(cluster), Ok(node_slot)) => Some(cluster.saturating_sub(node_slot)),
+            _ => None,
+        },
+        priority: node.node.priority,
+    }
+}
+
+/// Indices of the standbys that can be a failover target, skipping `exclude`
+/// (the source of a recovery-mode switch, which is itself a standby)
+pub fn standby_indices(nodes_with_status: &[NodeWithStatus], exclude: Option<usize>) -> Vec<usize> {
+    nodes_with_status
+        .iter()
+        .enumerate()
+        .filter(|(idx, n)| n.status == NodeStatus::Standby && Some(*idx) != exclude)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Probe every standby of a validator (getHealth and getSlot over SSH) and
+/// return the index of the best failover target in `nodes_with_status`
+pub async fn select_best_standby(
+    nodes_with_status: &[NodeWithStatus],
+    exclude: Option<usize>,
+    cluster_rpc: &str,
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &std::collections::HashMap<String, String>,
+) -> Option<usize> {
+    let standbys = standby_indices(nodes_with_status, exclude);
+    if standbys.len() <= 1 {
+        return standbys.first().copied();
+    }
+
+    let cluster_slot = crate::solana_rpc::fetch_current_slot(cluster_rpc).await.ok();
+
+    let probes = standbys.iter().map(|node_idx| {
+        probe_standby(
+            *node_idx,
+            &nodes_with_status[*node_idx],
+            cluster_slot,
+            ssh_pool,
+            detected_ssh_keys,
+        )
+    });
+    let candidates = futures::future::join_all(probes).await;
+
+    rank_standby_candidates(&candidates).first().copied()
+}
 
 pub struct EmergencyFailover {
     active_node: NodeWithStatus,
@@ -15,6 +150,7 @@ pub struct EmergencyFailover {
     ssh_pool: Arc<AsyncSshPool>,
     detected_ssh_keys: std::collections::HashMap<String, String>,
     alert_manager: AlertManager,
+    switch_config: SwitchConfig,
     // Track results
     primary_switch_success: bool,
     tower_copy_success: bool,
@@ -38,6 +174,7 @@ impl EmergencyFailover {
             ssh_pool,
             detected_ssh_keys,
             alert_manager,
+            switch_config: SwitchConfig::default(),
             primary_switch_success: false,
             tower_copy_success: false,
             standby_switch_success: false,
@@ -45,15 +182,111 @@ impl EmergencyFailover {
         }
     }
 
+    /// Use the pair's switch settings (fencing, tower checks) for the takeover
+    pub fn with_switch_config(mut self, switch_config: SwitchConfig) -> Self {
+        self.switch_config = switch_config;
+        self
+    }
+
+    /// Fresh fitness check of the standby right before promotion: health, slot
+    /// lag against the cluster and swap readiness. Returns the reasons it is unfit.
+    async fn check_standby_fitness(&self) -> Vec<String> {
+        let cluster_slot = crate::solana_rpc::fetch_current_slot(&self.validator_pair.rpc)
+            .await
+            .ok();
+        let probe = probe_standby(
+            0,
+            &self.standby_node,
+            cluster_slot,
+            &self.ssh_pool,
+            &self.detected_ssh_keys,
+        );
+        let swap_readiness = async {
+            match self.detected_ssh_keys.get(&self.standby_node.node.host) {
+                Some(ssh_key) => {
+                    crate::startup::check_node_swap_readiness(
+                        &self.ssh_pool,
+                        &self.standby_node.node,
+                        ssh_key,
+                        self.standby_node.ledger_path.as_ref(),
+                        Some(true),
+                    )
+                    .await
+                }
+                None => (false, vec!["no SSH key detected".to_string()]),
+            }
+        };
+        let (candidate, (swap_ready, swap_issues)) = tokio::join!(probe, swap_readiness);
+
+        standby_fitness_issues(&candidate, swap_ready, &swap_issues)
+    }
+
+    /// Run the configured hooks for `point`. An emergency takeover never waits
+    /// on a hook's verdict, so `abort` hooks only warn here.
+    async fn run_takeover_hooks(&self, point: HookPoint, error: Option<String>) {
+        let ctx = HookContext {
+            validator_pair: &self.validator_pair,
+            source: &self.active_node.node,
+            target: &self.standby_node.node,
+            error,
+        };
+        let outcomes = run_hooks(point, &ctx, &self.ssh_pool, &self.detected_ssh_keys, true).await;
+        for outcome in &outcomes {
+            match &outcome.error {
+                None => eprintln!(
+                    "   🪝 {} hook {} ({}) done in {}ms",
+                    point,
+                    outcome.name,
+                    outcome.location,
+                    outcome.elapsed.as_millis()
+                ),
+                Some(e) => eprintln!(
+                    "   ⚠️  {} hook {} ({}) failed: {}",
+                    point, outcome.name, outcome.location, e
+                ),
+            }
+        }
+    }
+
     pub async fn execute_emergency_takeover(&mut self) -> Result<()> {
         let start_time = Instant::now();
 
+        // Promoting an unhealthy or lagging standby would not restore voting, and
+        // demoting the primary first could turn a recoverable stall into an outage
+        eprintln!(
+            "🩺 Checking standby fitness ({})...",
+            self.standby_node.node.label
+        );
+        let fitness_issues = self.check_standby_fitness().await;
+        if !fitness_issues.is_empty() {
+            for issue in &fitness_issues {
+                eprintln!("   ❌ {}", issue);
+            }
+            eprintln!("   Promotion skipped - primary left untouched");
+            let _ = self
+                .alert_manager
+                .send_standby_unfit_alert(
+                    &self.validator_pair.identity_pubkey,
+                    &self.active_node.node.label,
+                    &self.standby_node.node.label,
+                    &fitness_issues,
+                )
+                .await;
+            return Err(anyhow!(
+                "Emergency takeover skipped: standby {} is unfit ({})",
+                self.standby_node.node.label,
+                fitness_issues.join("; ")
+            ));
+        }
+        eprintln!("   ✅ Standby is healthy, caught up and swap ready");
+
         // Log the emergency takeover
         eprintln!("🚨 EMERGENCY TAKEOVER INITIATED");
         eprintln!(
             "   Active node ({}) not voting, attempting failover to standby ({})",
             self.active_node.node.label, self.standby_node.node.label
         );
+        self.run_takeover_hooks(HookPoint::PreSwitch, None).await;
 
         // Create switch manager for the operations
         let mut switch_manager = SwitchManager::new(
@@ -62,7 +295,8 @@ impl EmergencyFailover {
             self.validator_pair.clone(),
             self.ssh_pool.clone(),
             self.detected_ssh_keys.clone(),
-        );
+        )
+        .with_switch_config(self.switch_config.clone());
 
         // Step 1: Try to switch primary to unfunded (optional, best-effort)
         eprintln!("📤 Switching primary to unfunded...");
@@ -89,6 +323,35 @@ impl EmergencyFailover {
         };
         self.primary_switch_success = primary_result.is_ok();
 
+        // Promoting while the old primary may still vote with the funded identity
+        // risks double signing; a stalled node is often still running
+        if let Err(e) = switch_manager.fence_old_primary(false).await {
+            eprintln!("   ❌ Fencing failed: {} - standby not promoted", e);
+            self.run_takeover_hooks(
+                HookPoint::OnFailure,
+                Some(format!("Fencing the old primary failed: {}", e)),
+            )
+            .await;
+            self.total_time = Some(start_time.elapsed());
+            let _ = self
+                .alert_manager
+                .send_emergency_takeover_alert(
+                    &self.validator_pair.identity_pubkey,
+                    &self.active_node.node.label,
+                    &self.standby_node.node.label,
+                    self.primary_switch_success,
+                    false, // tower not copied
+                    false, // standby not promoted
+                    self.total_time.unwrap(),
+                    Some(&format!("Fencing the old primary failed: {}", e)),
+                )
+                .await;
+            return Err(anyhow!(
+                "Emergency takeover aborted: could not fence the old primary ({})",
+                e
+            ));
+        }
+
         // Step 2: Try to copy tower file (optional, best-effort)
         eprintln!("📤 Copying tower file...");
         let tower_result = match timeout(
@@ -111,6 +374,7 @@ impl EmergencyFailover {
             }
         };
         self.tower_copy_success = tower_result.is_ok();
+        self.run_takeover_hooks(HookPoint::PostTower, None).await;
 
         // Step 3: Switch standby to funded (REQUIRED - must succeed)
         eprintln!("🚀 Switching standby to funded identity...");
@@ -118,9 +382,16 @@ impl EmergencyFailover {
             Ok(_) => {
                 self.standby_switch_success = true;
                 eprintln!("   ✅ Standby switched to funded identity successfully");
+                self.run_takeover_hooks(HookPoint::PostPromotion, None)
+                    .await;
             }
             Err(e) => {
                 eprintln!("   ❌ CRITICAL: Failed to switch standby to funded: {}", e);
+                self.run_takeover_hooks(
+                    HookPoint::OnFailure,
+                    Some(format!("Failed to activate standby: {}", e)),
+                )
+                .await;
                 self.total_time = Some(start_time.elapsed());
 
                 // Send failure notification
@@ -0,0 +1,319 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration as ChronoDuration, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::switch_schedule::format_duration;
+use crate::types::AlertConfig;
+
+/// Auto-failover history for one validator pair
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PairFailoverHistory {
+    /// Automatic takeovers still inside the rolling window
+    #[serde(default)]
+    pub takeovers: Vec<DateTime<Utc>>,
+    /// Last completed switch of any kind (manual, scheduled or automatic)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_switch_at: Option<DateTime<Utc>>,
+    /// Set once the budget is used up; auto-failover stays off until `svs failover reset`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_out_at: Option<DateTime<Utc>>,
+}
+
+/// Whether an automatic takeover may run now
+#[derive(Debug, Clone, PartialEq)]
+pub enum FailoverDecision {
+    Allowed,
+    /// The last switch was too recent; flapping between nodes is worse than waiting
+    Dwell {
+        remaining_seconds: u64,
+    },
+    /// The budget was used up earlier and auto-failover is off
+    LockedOut {
+        since: DateTime<Utc>,
+    },
+    /// This takeover would exceed the budget; the caller locks auto-failover out
+    BudgetExhausted {
+        takeovers_in_window: usize,
+    },
+}
+
+/// Auto-failover budget state for all validators, persisted in
+/// `~/.solana-validator-switch/failover_budget.json` so restarts do not reset it
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FailoverBudget {
+    /// Keyed by validator identity pubkey
+    #[serde(default)]
+    pub pairs: BTreeMap<String, PairFailoverHistory>,
+}
+
+impl FailoverBudget {
+    pub fn path() -> Result<PathBuf> {
+        Ok(dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find home directory"))?
+            .join(".solana-validator-switch")
+            .join("failover_budget.json"))
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Corrupt failover budget {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temp file and rename so a crash never leaves a half-written budget
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    pub fn history(&self, identity_pubkey: &str) -> Option<&PairFailoverHistory> {
+        self.pairs.get(identity_pubkey)
+    }
+
+    /// Decide whether an automatic takeover may run for `identity_pubkey` at `now`
+    pub fn check(
+        &self,
+        identity_pubkey: &str,
+        alert_config: &AlertConfig,
+        now: DateTime<Utc>,
+    ) -> FailoverDecision {
+        let Some(history) = self.pairs.get(identity_pubkey) else {
+            return FailoverDecision::Allowed;
+        };
+
+        if let Some(since) = history.locked_out_at {
+            return FailoverDecision::LockedOut { since };
+        }
+
+        if let Some(last_switch) = history.last_switch_at {
+            let dwell_end = last_switch
+                + ChronoDuration::seconds(alert_config.auto_failover_min_dwell_seconds as i64);
+            if now < dwell_end {
+                return FailoverDecision::Dwell {
+                    remaining_seconds: (dwell_end - now).num_seconds().max(1) as u64,
+                };
+            }
+        }
+
+        let takeovers_in_window = history
+            .takeovers
+            .iter()
+            .filter(|&&at| in_window(at, alert_config, now))
+            .count();
+        if takeovers_in_window >= alert_config.max_auto_failovers as usize {
+            return FailoverDecision::BudgetExhausted {
+                takeovers_in_window,
+            };
+        }
+
+        FailoverDecision::Allowed
+    }
+
+    /// Record a switch of any kind; it starts the dwell time
+    pub fn record_switch(&mut self, identity_pubkey: &str, at: DateTime<Utc>) {
+        self.pairs
+            .entry(identity_pubkey.to_string())
+            .or_default()
+            .last_switch_at = Some(at);
+    }
+
+    /// Record an automatic takeover against the budget, dropping ones outside the window
+    pub fn record_takeover(
+        &mut self,
+        identity_pubkey: &str,
+        alert_config: &AlertConfig,
+        at: DateTime<Utc>,
+    ) {
+        let history = self.pairs.entry(identity_pubkey.to_string()).or_default();
+        history
+            .takeovers
+            .retain(|&taken| in_window(taken, alert_config, at));
+        history.takeovers.push(at);
+        history.last_switch_at = Some(at);
+    }
+
+    pub fn lock_out(&mut self, identity_pubkey: &str, at: DateTime<Utc>) {
+        self.pairs
+            .entry(identity_pubkey.to_string())
+            .or_default()
+            .locked_out_at = Some(at);
+    }
+
+    /// Clear the lockout and the takeover history; returns whether anything changed
+    pub fn reset(&mut self, identity_pubkey: &str) -> bool {
+        match self.pairs.get_mut(identity_pubkey) {
+            Some(history) if history.locked_out_at.is_some() || !history.takeovers.is_empty() => {
+                history.locked_out_at = None;
+                history.takeovers.clear();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn in_window(at: DateTime<Utc>, alert_config: &AlertConfig, now: DateTime<Utc>) -> bool {
+    now - at < ChronoDuration::seconds(alert_config.auto_failover_window_seconds as i64)
+}
+
+/// Record a completed switch so automatic takeovers respect the dwell time.
+/// Failures only warn: the switch itself already succeeded.
+pub fn record_completed_switch(identity_pubkey: &str) {
+    let result = FailoverBudget::load().and_then(|mut budget| {
+        budget.record_switch(identity_pubkey, Utc::now());
+        budget.save()
+    });
+    if let Err(e) = result {
+        eprintln!("⚠️  Failed to record switch in failover budget: {}", e);
+    }
+}
+
+/// Count an automatic takeover against the budget once it has promoted a standby.
+/// Failures only warn: the takeover itself already happened.
+pub fn record_automatic_takeover(identity_pubkey: &str, alert_config: &AlertConfig) {
+    let result = FailoverBudget::load().and_then(|mut budget| {
+        budget.record_takeover(identity_pubkey, alert_config, Utc::now());
+        budget.save()
+    });
+    if let Err(e) = result {
+        eprintln!("⚠️  Failed to record takeover in failover budget: {}", e);
+    }
+}
+
+/// Turn auto-failover off for a pair until `svs failover reset`, so a deliberate
+/// demotion of every node is not undone by an automatic takeover
+pub fn lock_out_auto_failover(identity_pubkey: &str) -> Result<()> {
+    let mut budget = FailoverBudget::load()?;
+    budget.lock_out(identity_pubkey, Utc::now());
+    budget.save()
+}
+
+impl std::fmt::Display for FailoverDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailoverDecision::Allowed => write!(f, "allowed"),
+            FailoverDecision::Dwell { remaining_seconds } => write!(
+                f,
+                "last switch too recent, dwell time has {} left",
+                format_duration(*remaining_seconds)
+            ),
+            FailoverDecision::LockedOut { since } => write!(
+                f,
+                "auto-failover locked out since {}",
+                since.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S %Z")
+            ),
+            FailoverDecision::BudgetExhausted {
+                takeovers_in_window,
+            } => write!(
+                f,
+                "failover budget used up ({} automatic takeovers in the window)",
+                takeovers_in_window
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod failover_budget_tests {
+    use super::{FailoverBudget, FailoverDecision};
+    use crate::types::AlertConfig;
+    use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+
+    fn alert_config() -> AlertConfig {
+        serde_yaml::from_str(
+            "max_auto_failovers: 2\nauto_failover_window_seconds: 3600\nauto_failover_min_dwell_seconds: 600\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn dwell_time_follows_any_switch() {
+        let config = alert_config();
+        let now = Utc.with_ymd_and_hms(2026, 10, 20, 3, 0, 0).unwrap();
+        let mut budget = FailoverBudget::default();
+        assert_eq!(budget.check("id", &config, now), FailoverDecision::Allowed);
+
+        budget.record_switch("id", now);
+        assert_eq!(
+            budget.check("id", &config, now + ChronoDuration::seconds(540)),
+            FailoverDecision::Dwell {
+                remaining_seconds: 60
+            }
+        );
+        assert_eq!(
+            budget.check("id", &config, now + ChronoDuration::seconds(600)),
+            FailoverDecision::Allowed
+        );
+        // Other validators are unaffected
+        assert_eq!(
+            budget.check("other", &config, now),
+            FailoverDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn budget_is_a_rolling_window() {
+        let config = alert_config();
+        let start = Utc.with_ymd_and_hms(2026, 10, 20, 3, 0, 0).unwrap();
+        let mut budget = FailoverBudget::default();
+        budget.record_takeover("id", &config, start);
+        budget.record_takeover("id", &config, start + ChronoDuration::minutes(20));
+
+        let third = start + ChronoDuration::minutes(40);
+        assert_eq!(
+            budget.check("id", &config, third),
+            FailoverDecision::BudgetExhausted {
+                takeovers_in_window: 2
+            }
+        );
+
+        // The first takeover leaves the window after an hour
+        let later = start + ChronoDuration::minutes(61);
+        assert_eq!(
+            budget.check("id", &config, later),
+            FailoverDecision::Allowed
+        );
+        budget.record_takeover("id", &config, later);
+        assert_eq!(budget.pairs["id"].takeovers.len(), 2);
+    }
+
+    #[test]
+    fn lockout_persists_until_reset() {
+        let config = alert_config();
+        let now = Utc.with_ymd_and_hms(2026, 10, 20, 3, 0, 0).unwrap();
+        let mut budget = FailoverBudget::default();
+        budget.record_takeover("id", &config, now);
+        budget.lock_out("id", now);
+
+        let json = serde_json::to_string(&budget).unwrap();
+        let restored: FailoverBudget = serde_json::from_str(&json).unwrap();
+        let much_later = now + ChronoDuration::days(7);
+        assert_eq!(
+            restored.check("id", &config, much_later),
+            FailoverDecision::LockedOut { since: now }
+        );
+
+        let mut restored = restored;
+        assert!(restored.reset("id"));
+        assert_eq!(
+            restored.check("id", &config, much_later),
+            FailoverDecision::Allowed
+        );
+        assert!(!restored.reset("id"));
+    }
+}
@@ -10,7 +10,9 @@
 #![allow(clippy::new_without_default)]
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 mod alert;
@@ -22,10 +24,14 @@ mod alert_logic_tests;
 mod alert_tests;
 #[cfg(test)]
 mod auto_failover_tests;
+mod cluster_health;
 mod commands;
 mod config;
+mod double_sign_guard;
 mod emergency_failover;
 mod executable_utils;
+mod failover_budget;
+mod pipelined_demotion;
 mod solana_rpc;
 mod ssh;
 mod ssh_key_detector;
@@ -36,13 +42,26 @@ mod startup_logger;
 mod startup_validation_tests;
 #[cfg(test)]
 mod status_ui_alert_tests;
+mod switch_hooks;
+mod switch_journal;
+mod switch_plan;
+mod switch_preflight;
+mod switch_schedule;
+mod switch_strategy;
 #[cfg(test)]
 mod switch_validation_tests;
+mod terminal;
+mod tower_file;
 mod types;
 mod validator_metadata;
 mod validator_rpc;
+mod vote_quorum;
 
-use commands::{status_command, switch_command, test_alert_command};
+use commands::{
+    apply_command, demote_command, failover_reset_command, failover_status_command,
+    monitor_command, plan_command, schedule_add_command, schedule_list_command,
+    schedule_remove_command, status_command, switch_command, test_alert_command,
+};
 use ssh::AsyncSshPool;
 
 #[derive(Parser)]
@@ -66,6 +85,12 @@ enum Commands {
         #[arg(short, long)]
         validator: Option<String>,
     },
+    /// Run alerting, auto-failover and scheduled switches without the terminal UI
+    Monitor {
+        /// Append log messages here (default: ~/.solana-validator-switch/logs/latest.log)
+        #[arg(long, value_name = "FILE")]
+        log_file: Option<PathBuf>,
+    },
     /// Switch between primary and backup validators
     Switch {
         /// Preview switch without executing
@@ -74,11 +99,91 @@ enum Commands {
         /// Select validator by index (0-based) or identity prefix
         #[arg(short, long)]
         validator: Option<String>,
+        /// Require at least this many slots before our next leader slot (0 disables)
+        #[arg(long, value_name = "SLOTS")]
+        wait_for_leader_gap: Option<u64>,
+        /// Label of the node to make active (default: best available standby)
+        #[arg(long, value_name = "LABEL")]
+        to: Option<String>,
+        /// Write the resolved switch to a reviewable plan file instead of executing it
+        #[arg(long, value_name = "FILE", conflicts_with = "dry_run")]
+        plan: Option<PathBuf>,
+        /// Skip confirmation prompts and pauses (for automation)
+        #[arg(short, long)]
+        yes: bool,
+        /// Output format; json prints a machine-readable report on stdout
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text, requires_if("json", "yes"))]
+        output: OutputFormat,
+    },
+    /// Execute a plan written by `svs switch --plan`
+    Apply {
+        /// Plan file to execute
+        plan: PathBuf,
+    },
+    /// Plan maintenance switches, executed by a running `svs status`
+    Schedule {
+        #[command(subcommand)]
+        action: ScheduleAction,
+    },
+    /// Emergency stop: move every node of a validator to its unfunded identity
+    Demote {
+        /// Select validator by index (0-based) or identity prefix
+        #[arg(short, long)]
+        validator: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Inspect or reset the auto-failover budget
+    Failover {
+        #[command(subcommand)]
+        action: FailoverAction,
     },
     /// Test alert configuration
     TestAlert,
 }
 
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum ScheduleAction {
+    /// Schedule a switch for a validator
+    Add {
+        /// Select validator by index (0-based) or identity prefix
+        #[arg(short, long)]
+        validator: String,
+        /// When to switch: RFC 3339 or local 'YYYY-MM-DD HH:MM'
+        #[arg(long)]
+        at: String,
+        /// Switch back to the current active node after this long (e.g. 2h, 1h30m)
+        #[arg(long, value_name = "DURATION")]
+        switch_back_after: Option<String>,
+        /// Label of the node to make active (default: best available standby)
+        #[arg(long, value_name = "LABEL")]
+        to: Option<String>,
+    },
+    /// List scheduled switches
+    List,
+    /// Remove a scheduled switch by id
+    Remove { id: u64 },
+}
+
+#[derive(Subcommand)]
+enum FailoverAction {
+    /// Show automatic takeovers in the budget window, dwell time and lockouts
+    Status,
+    /// Clear the lockout and takeover history so auto-failover is re-enabled
+    Reset {
+        /// Select validator by index (0-based) or identity prefix
+        #[arg(short, long)]
+        validator: String,
+    },
+}
+
 /// Application state that persists throughout the CLI session
 #[derive(Clone)]
 pub struct AppState {
@@ -88,6 +193,7 @@ pub struct AppState {
     pub metadata_cache: Arc<tokio::sync::Mutex<validator_metadata::MetadataCache>>,
     pub detected_ssh_keys: std::collections::HashMap<String, String>, // host -> key_path mapping
     pub selected_validator_index: usize, // Currently selected validator pair
+    pub switch_target_label: Option<String>, // Node to promote on the next switch (None = best standby)
 }
 
 #[derive(Debug, Clone)]
@@ -109,6 +215,30 @@ impl AppState {
         startup::run_startup_checklist_with_config(config_path).await
     }
 
+    /// Select the node to promote on the next switch of the selected validator
+    fn select_switch_target_from_arg(&mut self, label: &str) -> Result<()> {
+        let validator_status = &self.validator_statuses[self.selected_validator_index];
+        if !validator_status
+            .nodes_with_status
+            .iter()
+            .any(|n| n.node.label == label)
+        {
+            let labels: Vec<&str> = validator_status
+                .nodes_with_status
+                .iter()
+                .map(|n| n.node.label.as_str())
+                .collect();
+            return Err(anyhow::anyhow!(
+                "No node labelled '{}' in this validator pair (available: {})",
+                label,
+                labels.join(", ")
+            ));
+        }
+
+        self.switch_target_label = Some(label.to_string());
+        Ok(())
+    }
+
     /// Parse validator selection from CLI argument
     fn select_validator_from_arg(&mut self, validator_arg: &str) -> Result<()> {
         // Try parsing as index first
@@ -150,12 +280,61 @@ impl AppState {
     }
 }
 
+/// Point stdout at stderr and return a handle to the original stdout
+fn redirect_stdout_to_stderr() -> Result<std::fs::File> {
+    use std::os::unix::io::FromRawFd;
+
+    // SAFETY: plain descriptor duplication; the duplicate is owned by the returned File
+    unsafe {
+        let original = libc::dup(libc::STDOUT_FILENO);
+        if original < 0 || libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(std::fs::File::from_raw_fd(original))
+    }
+}
+
+/// Report a failed switch and exit with the code `SwitchError` defines for it
+fn exit_with_switch_error(error: anyhow::Error) -> ! {
+    use commands::error_handler::{exit_code_for, find_switch_error};
+
+    match find_switch_error(&error) {
+        Some(switch_error) => eprintln!("\n{}", switch_error.to_user_message()),
+        None => eprintln!("Error: {:?}", error),
+    }
+    std::process::exit(exit_code_for(&error));
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    // Keep stdout for the JSON report; everything human-readable goes to stderr
+    let mut json_stdout = match &cli.command {
+        Some(Commands::Switch {
+            output: OutputFormat::Json,
+            ..
+        }) => Some(redirect_stdout_to_stderr()?),
+        _ => None,
+    };
+
+    // Nobody answers prompts or presses keys for the monitor (run by systemd),
+    // `switch --yes` or when stdin is not a terminal
+    if matches!(
+        cli.command,
+        Some(Commands::Monitor { .. }) | Some(Commands::Switch { yes: true, .. })
+    ) || !std::io::stdin().is_terminal()
+    {
+        terminal::set_non_interactive();
+    }
+
     // Initialize app state with persistent SSH connections
-    let app_state = AppState::new_with_config(cli.config).await?;
+    let mut app_state = AppState::new_with_config(cli.config).await?;
+
+    // Offer to finish or roll back a switch interrupted by a previous run
+    if let Some(state) = app_state.as_mut() {
+        commands::switch::recover_unfinished_switches(state).await?;
+    }
 
     match cli.command {
         Some(Commands::Status { validator }) => {
@@ -170,15 +349,128 @@ async fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Some(Commands::Switch { dry_run, validator }) => {
+        Some(Commands::Monitor { log_file }) => {
+            if let Some(state) = app_state {
+                monitor_command(&state, log_file).await?;
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Switch {
+            dry_run,
+            validator,
+            wait_for_leader_gap,
+            to,
+            plan,
+            yes,
+            output: _,
+        }) => {
             if let Some(mut state) = app_state {
-                // Apply validator selection if provided
-                if let Some(validator_arg) = validator {
-                    state.select_validator_from_arg(&validator_arg)?;
+                // Apply validator and target selection if provided; a bad argument
+                // is reported like a failed switch, JSON report included
+                let selection = validator
+                    .map_or(Ok(()), |validator_arg| {
+                        state.select_validator_from_arg(&validator_arg)
+                    })
+                    .and_then(|()| {
+                        to.map_or(Ok(()), |label| state.select_switch_target_from_arg(&label))
+                    });
+                // CLI flag overrides the configured leader gap for this run
+                if let Some(slots) = wait_for_leader_gap {
+                    state
+                        .config
+                        .switch_config
+                        .get_or_insert_with(types::SwitchConfig::default)
+                        .min_leader_gap_slots = slots;
+                }
+                if let (Ok(()), Some(plan_path)) = (&selection, plan) {
+                    plan_command(&mut state, &plan_path).await?;
+                    return Ok(());
                 }
-                let show_status = switch_command(dry_run, &mut state).await?;
-                if show_status && !dry_run {
-                    status_command(&state).await?;
+                // Recovery was skipped without an operator; refuse to switch over it
+                let selection = selection.and_then(|()| {
+                    if dry_run || terminal::is_interactive() {
+                        Ok(())
+                    } else {
+                        commands::switch::ensure_no_unfinished_switch(&state)
+                    }
+                });
+                let mut report = commands::switch::SwitchReport::default();
+                let result = match selection {
+                    Err(e) => Err(e),
+                    Ok(()) if yes => {
+                        commands::switch::run_switch(dry_run, &mut state, false, true, &mut report)
+                            .await
+                    }
+                    Ok(()) => switch_command(dry_run, &mut state).await,
+                };
+
+                if let Some(json_stdout) = json_stdout.as_mut() {
+                    report.finish(&state, &result);
+                    writeln!(json_stdout, "{}", serde_json::to_string_pretty(&report)?)?;
+                }
+
+                match result {
+                    Ok(show_status) => {
+                        if show_status && !dry_run && !yes {
+                            status_command(&state).await?;
+                        }
+                    }
+                    Err(e) => exit_with_switch_error(e),
+                }
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Apply { plan }) => {
+            if let Some(mut state) = app_state {
+                match apply_command(&mut state, &plan).await {
+                    Ok(true) => status_command(&state).await?,
+                    Ok(false) => {}
+                    Err(e) => exit_with_switch_error(e),
+                }
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Schedule { action }) => match action {
+            ScheduleAction::Add {
+                validator,
+                at,
+                switch_back_after,
+                to,
+            } => {
+                if let Some(mut state) = app_state {
+                    state.select_validator_from_arg(&validator)?;
+                    schedule_add_command(&state, &at, switch_back_after.as_deref(), to)?;
+                } else {
+                    // Startup validation already showed detailed error messages
+                    std::process::exit(1);
+                }
+            }
+            ScheduleAction::List => schedule_list_command()?,
+            ScheduleAction::Remove { id } => schedule_remove_command(id)?,
+        },
+        Some(Commands::Demote { validator, yes }) => {
+            if let Some(mut state) = app_state {
+                state.select_validator_from_arg(&validator)?;
+                demote_command(&state, yes).await?;
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Failover { action }) => {
+            if let Some(mut state) = app_state {
+                match action {
+                    FailoverAction::Status => failover_status_command(&state)?,
+                    FailoverAction::Reset { validator } => {
+                        state.select_validator_from_arg(&validator)?;
+                        failover_reset_command(&state)?;
+                    }
                 }
             } else {
                 // Startup validation already showed detailed error messages
@@ -0,0 +1,196 @@
+use anyhow::{anyhow, Result};
+
+use crate::commands::error_handler::SwitchError;
+use crate::switch_hooks::shell_quote;
+use crate::switch_strategy::StrategyCommand;
+
+const DEMOTION_EXIT_MARKER: &str = "__SVS_DEMOTION_EXIT=";
+const TOWER_BEGIN_MARKER: &str = "__SVS_TOWER_BEGIN";
+const TOWER_MISSING_MARKER: &str = "__SVS_TOWER_MISSING";
+const TOWER_EXIT_MARKER: &str = "__SVS_TOWER_EXIT=";
+
+/// Script for the `pipelined` tower transfer mode: run the demotion and, only
+/// if it succeeded, print the tower as base64 in the same SSH invocation. Each
+/// phase ends with a marker so failures can be attributed to Step 1 or Step 2.
+pub fn pipelined_demotion_script(demotion: &StrategyCommand, tower_path: &str) -> String {
+    let invocation = std::iter::once(demotion.program.as_str())
+        .chain(demotion.args.iter().map(String::as_str))
+        .map(shell_quote)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let tower = shell_quote(tower_path);
+    format!(
+        "{invocation} 2>&1; rc=$?; echo \"{DEMOTION_EXIT_MARKER}$rc\"; [ $rc -eq 0 ] || exit 0; \
+         if [ -f {tower} ]; then echo {TOWER_BEGIN_MARKER}; base64 {tower} 2>&1; \
+         echo \"{TOWER_EXIT_MARKER}$?\"; else echo {TOWER_MISSING_MARKER}; fi"
+    )
+}
+
+/// Whether an output line is the demotion's exit marker, i.e. the moment the
+/// demotion finished and the tower read started
+pub fn is_demotion_exit_line(line: &str) -> bool {
+    line.starts_with(DEMOTION_EXIT_MARKER)
+}
+
+/// What the tower phase of the pipelined script reported
+#[derive(Debug, PartialEq)]
+pub enum PipelinedTower {
+    /// The demotion failed (or its output was cut short), so no read was attempted
+    NotRead,
+    Missing,
+    Read {
+        data: String,
+        exit_code: Option<i32>,
+    },
+}
+
+/// Output of the pipelined script, split into its two phases
+#[derive(Debug, PartialEq)]
+pub struct PipelinedDemotion {
+    pub demotion_output: String,
+    pub demotion_exit_code: Option<i32>,
+    pub tower: PipelinedTower,
+}
+
+impl PipelinedDemotion {
+    pub fn parse(output: &str) -> Self {
+        let mut demotion_lines = Vec::new();
+        let mut demotion_exit_code = None;
+        let mut tower = PipelinedTower::NotRead;
+        let mut lines = output.lines();
+
+        for line in lines.by_ref() {
+            if let Some(code) = line.strip_prefix(DEMOTION_EXIT_MARKER) {
+                demotion_exit_code = code.trim().parse().ok();
+                break;
+            }
+            demotion_lines.push(line);
+        }
+
+        for line in lines {
+            match (&mut tower, line.trim()) {
+                (PipelinedTower::NotRead, TOWER_MISSING_MARKER) => tower = PipelinedTower::Missing,
+                (PipelinedTower::NotRead, TOWER_BEGIN_MARKER) => {
+                    tower = PipelinedTower::Read {
+                        data: String::new(),
+                        exit_code: None,
+                    }
+                }
+                (PipelinedTower::Read { data, exit_code }, line) => {
+                    match line.strip_prefix(TOWER_EXIT_MARKER) {
+                        Some(code) => *exit_code = code.trim().parse().ok(),
+                        None if exit_code.is_none() => {
+                            data.push_str(line);
+                            data.push('\n');
+                        }
+                        None => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            demotion_output: demotion_lines.join("\n"),
+            demotion_exit_code,
+            tower,
+        }
+    }
+
+    /// The Step 1 result: the demotion's exit status, then the strategy's own checks
+    pub fn check_demotion(&self, demotion: &StrategyCommand) -> Result<()> {
+        let last_line = self.demotion_output.lines().last().unwrap_or("no output");
+        match self.demotion_exit_code {
+            Some(0) => demotion.check_output(&self.demotion_output),
+            Some(code) => Err(anyhow!(
+                "Command failed (exit status {}): {}",
+                code,
+                last_line
+            )),
+            None => Err(anyhow!("Demotion reported no exit status: {}", last_line)),
+        }
+    }
+
+    /// The Step 2 result: the tower as base64, or why it could not be read
+    pub fn into_tower_base64(self, tower_path: &str) -> Result<String> {
+        match self.tower {
+            PipelinedTower::Read {
+                data,
+                exit_code: Some(0),
+            } => Ok(data),
+            PipelinedTower::Read { data, exit_code } => Err(anyhow!(
+                "Failed to read tower file (exit status {}): {}",
+                exit_code.map_or_else(|| "unknown".to_string(), |code| code.to_string()),
+                data.lines().last().unwrap_or("no output")
+            )),
+            PipelinedTower::Missing => Err(SwitchError::TowerFileNotFound {
+                path: tower_path.to_string(),
+            }
+            .into()),
+            PipelinedTower::NotRead => Err(anyhow!("Tower file was not read after the demotion")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_identity() -> StrategyCommand {
+        StrategyCommand {
+            subtitle: "",
+            display: String::new(),
+            program: "/opt/agave-validator".to_string(),
+            args: vec![
+                "-l".to_string(),
+                "/mnt/ledger".to_string(),
+                "set-identity".to_string(),
+                "/keys/unfunded.json".to_string(),
+            ],
+            reports_exit_code: false,
+        }
+    }
+
+    #[test]
+    fn script_reads_the_tower_only_after_a_successful_demotion() {
+        let script = pipelined_demotion_script(&set_identity(), "/mnt/ledger/tower-1_9-X.bin");
+        assert!(script.starts_with(
+            "'/opt/agave-validator' '-l' '/mnt/ledger' 'set-identity' '/keys/unfunded.json' 2>&1;"
+        ));
+        let guard = script.find("[ $rc -eq 0 ] || exit 0").unwrap();
+        let read = script.find("base64 '/mnt/ledger/tower-1_9-X.bin'").unwrap();
+        assert!(guard < read);
+    }
+
+    #[test]
+    fn parses_each_phase_of_the_output() {
+        let output = "identity set\n__SVS_DEMOTION_EXIT=0\n__SVS_TOWER_BEGIN\nAAAA\nBBBB\n__SVS_TOWER_EXIT=0\n";
+        let parsed = PipelinedDemotion::parse(output);
+        assert!(parsed.check_demotion(&set_identity()).is_ok());
+        let exit_lines: Vec<_> = output
+            .lines()
+            .filter(|l| is_demotion_exit_line(l))
+            .collect();
+        assert_eq!(exit_lines, vec!["__SVS_DEMOTION_EXIT=0"]);
+        assert_eq!(
+            parsed.into_tower_base64("/t").unwrap(),
+            "AAAA\nBBBB\n".to_string()
+        );
+
+        let missing = PipelinedDemotion::parse("__SVS_DEMOTION_EXIT=0\n__SVS_TOWER_MISSING\n");
+        let err = missing.into_tower_base64("/t").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SwitchError>(),
+            Some(SwitchError::TowerFileNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn failed_demotion_is_reported_without_a_tower_read() {
+        let parsed = PipelinedDemotion::parse("error: ledger locked\n__SVS_DEMOTION_EXIT=1\n");
+        let err = parsed.check_demotion(&set_identity()).unwrap_err();
+        assert!(err.to_string().contains("exit status 1"));
+        assert!(err.to_string().contains("ledger locked"));
+        assert_eq!(parsed.tower, PipelinedTower::NotRead);
+    }
+}
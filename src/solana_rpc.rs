@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +40,24 @@ pub struct ValidatorVoteData {
     pub recent_votes: Vec<RecentVote>,
     pub is_voting: bool,
     pub tvc_metrics: Option<TvcPerformanceMetrics>,
+    /// Share of the cluster's activated stake that getVoteAccounts reports delinquent
+    pub cluster_delinquent_stake_fraction: f64,
+}
+
+/// Share of activated stake in the delinquent vote accounts (0.0 when no stake is reported)
+pub fn delinquent_stake_fraction(
+    vote_accounts: &solana_client::rpc_response::RpcVoteAccountStatus,
+) -> f64 {
+    let stake = |accounts: &[solana_client::rpc_response::RpcVoteAccountInfo]| -> u128 {
+        accounts.iter().map(|a| a.activated_stake as u128).sum()
+    };
+    let delinquent = stake(&vote_accounts.delinquent);
+    let total = delinquent + stake(&vote_accounts.current);
+    if total == 0 {
+        0.0
+    } else {
+        delinquent as f64 / total as f64
+    }
 }
 
 fn compute_tvc_rank(
@@ -56,7 +75,7 @@ fn compute_tvc_rank(
         })
         .collect();
 
-    epoch_credits.sort_by(|a, b| b.1.cmp(&a.1));
+    epoch_credits.sort_by_key(|entry| std::cmp::Reverse(entry.1));
     let total = epoch_credits.len() as u32;
     let rank = epoch_credits
         .iter()
@@ -100,6 +119,38 @@ fn compute_missed_votes(
     (missed, effective_window)
 }
 
+/// The vote account's `last_vote` as seen at `commitment`. The default
+/// (finalized) view trails the live vote by ~32 slots, too late to tell which
+/// node cast a vote in the seconds after a switch.
+pub async fn fetch_last_vote(
+    rpc_url: &str,
+    vote_pubkey_str: &str,
+    commitment: CommitmentConfig,
+) -> Result<u64> {
+    use std::time::Duration;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = RpcClient::new_with_timeout_and_commitment(
+        rpc_url.to_string(),
+        Duration::from_secs(3),
+        commitment,
+    );
+    let vote_accounts = rpc_client
+        .get_vote_accounts()
+        .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+
+    vote_accounts
+        .current
+        .iter()
+        .chain(vote_accounts.delinquent.iter())
+        .find(|account| account.vote_pubkey == vote_pubkey_str)
+        .map(|account| account.last_vote)
+        .ok_or_else(|| anyhow!("Vote account {} not found", vote_pubkey_str))
+}
+
 pub async fn fetch_vote_account_data(
     rpc_url: &str,
     vote_pubkey_str: &str,
@@ -272,5 +323,110 @@ pub async fn fetch_vote_account_data(
         recent_votes,
         is_voting,
         tvc_metrics,
+        cluster_delinquent_stake_fraction: delinquent_stake_fraction(&vote_account),
     })
 }
+
+/// Convert epoch-relative leader schedule indices into sorted absolute slots.
+fn absolute_leader_slots(epoch_start_slot: u64, slot_indices: &[usize]) -> Vec<u64> {
+    let mut slots: Vec<u64> = slot_indices
+        .iter()
+        .map(|idx| epoch_start_slot + *idx as u64)
+        .collect();
+    slots.sort_unstable();
+    slots.dedup();
+    slots
+}
+
+/// Number of slots between `current_slot` and our next leader slot.
+/// Returns 0 while we are inside a leader window and `None` when no
+/// upcoming leader slot is known.
+pub fn slots_until_next_leader(leader_slots: &[u64], current_slot: u64) -> Option<u64> {
+    leader_slots
+        .iter()
+        .find(|slot| **slot >= current_slot)
+        .map(|slot| slot - current_slot)
+}
+
+/// Fetch the current slot and the identity's leader slots for the current
+/// epoch (plus the next epoch when the cluster already publishes it).
+pub async fn fetch_upcoming_leader_slots(
+    rpc_url: &str,
+    identity_pubkey: &str,
+) -> Result<(u64, Vec<u64>)> {
+    use solana_client::rpc_config::RpcLeaderScheduleConfig;
+    use std::time::Duration;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = RpcClient::new_with_timeout(rpc_url.to_string(), Duration::from_secs(3));
+    let epoch_info = rpc_client
+        .get_epoch_info()
+        .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+
+    let current_slot = epoch_info.absolute_slot;
+    let epoch_start = current_slot.saturating_sub(epoch_info.slot_index);
+    let next_epoch_start = epoch_start + epoch_info.slots_in_epoch;
+
+    let mut leader_slots = Vec::new();
+    for start in [epoch_start, next_epoch_start] {
+        let schedule = rpc_client
+            .get_leader_schedule_with_config(
+                Some(start),
+                RpcLeaderScheduleConfig {
+                    identity: Some(identity_pubkey.to_string()),
+                    commitment: None,
+                },
+            )
+            .map_err(|e| anyhow!("Failed to get leader schedule: {}", e))?;
+
+        // The next epoch's schedule is not always published yet
+        if let Some(indices) = schedule.and_then(|s| s.get(identity_pubkey).cloned()) {
+            leader_slots.extend(absolute_leader_slots(start, &indices));
+        }
+    }
+
+    Ok((current_slot, leader_slots))
+}
+
+/// Fetch only the current slot, used while waiting out a leader window.
+pub async fn fetch_current_slot(rpc_url: &str) -> Result<u64> {
+    use std::time::Duration;
+
+    let rpc_client = RpcClient::new_with_timeout(rpc_url.to_string(), Duration::from_secs(3));
+    rpc_client
+        .get_slot()
+        .map_err(|e| anyhow!("Failed to get current slot: {}", e))
+}
+
+#[cfg(test)]
+mod leader_schedule_tests {
+    use super::{absolute_leader_slots, slots_until_next_leader};
+
+    #[test]
+    fn absolute_leader_slots_offsets_by_epoch_start_and_sorts() {
+        let slots = absolute_leader_slots(1_000, &[7, 4, 5, 6, 4]);
+        assert_eq!(slots, vec![1_004, 1_005, 1_006, 1_007]);
+    }
+
+    #[test]
+    fn gap_is_distance_to_next_leader_slot() {
+        let slots = vec![100, 101, 102, 103, 500, 501, 502, 503];
+        assert_eq!(slots_until_next_leader(&slots, 50), Some(50));
+        assert_eq!(slots_until_next_leader(&slots, 104), Some(396));
+    }
+
+    #[test]
+    fn gap_is_zero_inside_our_leader_window() {
+        let slots = vec![100, 101, 102, 103];
+        assert_eq!(slots_until_next_leader(&slots, 102), Some(0));
+    }
+
+    #[test]
+    fn gap_is_unknown_without_upcoming_leader_slots() {
+        assert_eq!(slots_until_next_leader(&[], 10), None);
+        assert_eq!(slots_until_next_leader(&[1, 2, 3], 10), None);
+    }
+}
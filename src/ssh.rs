@@ -700,6 +700,94 @@ impl AsyncSshPool {
         Ok(())
     }
 
+    /// Copy a small file from one node into a file on another node: read it
+    /// once (at most `max_bytes`), let `check` refuse the contents, then write
+    /// it with a single `dd`. Nothing is written on the destination when the
+    /// read or `check` fails. Returns the bytes copied and their SHA256 (hex).
+    #[allow(clippy::too_many_arguments)]
+    pub async fn copy_checked_file_between_nodes(
+        &self,
+        source_node: &NodeConfig,
+        source_key_path: &str,
+        source_path: &str,
+        dest_node: &NodeConfig,
+        dest_key_path: &str,
+        dest_path: &str,
+        max_bytes: usize,
+        check: impl FnOnce(&[u8]) -> Result<()>,
+    ) -> Result<(usize, String)> {
+        use sha2::{Digest, Sha256};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Open both sessions up front so the copy is one read and one write
+        let (source_session, dest_session) = tokio::try_join!(
+            self.get_session(source_node, source_key_path),
+            self.get_session(dest_node, dest_key_path)
+        )?;
+
+        timeout(Duration::from_secs(120), async {
+            let mut cat_child = source_session
+                .command("cat")
+                .arg(source_path)
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .await
+                .map_err(|e| anyhow!("Failed to spawn cat on source: {}", e))?;
+
+            let source = cat_child
+                .stdout()
+                .take()
+                .ok_or_else(|| anyhow!("Failed to get source stdout"))?;
+            let mut contents = Vec::new();
+            source
+                .take(max_bytes as u64 + 1)
+                .read_to_end(&mut contents)
+                .await?;
+            if contents.len() > max_bytes {
+                return Err(anyhow!(
+                    "{} on source is larger than {} bytes",
+                    source_path,
+                    max_bytes
+                ));
+            }
+            let cat_status = cat_child.wait().await?;
+            if !cat_status.success() {
+                return Err(anyhow!("Reading {} on source failed", source_path));
+            }
+
+            check(&contents)?;
+
+            let mut dd_child = dest_session
+                .command("dd")
+                .arg(format!("of={}", dest_path))
+                .arg("status=none")
+                .stdin(Stdio::piped())
+                .stdout(Stdio::null())
+                .stderr(Stdio::piped())
+                .spawn()
+                .await
+                .map_err(|e| anyhow!("Failed to spawn dd on destination: {}", e))?;
+            let mut dest = dd_child
+                .stdin()
+                .take()
+                .ok_or_else(|| anyhow!("Failed to get destination stdin"))?;
+            dest.write_all(&contents).await?;
+            dest.flush().await?;
+            drop(dest); // Close pipe so dd finishes
+
+            let dd_status = dd_child.wait().await?;
+            if !dd_status.success() {
+                return Err(anyhow!("Writing {} on destination failed", dest_path));
+            }
+
+            Ok((contents.len(), format!("{:x}", Sha256::digest(&contents))))
+        })
+        .await
+        .map_err(|_| anyhow!("Tower file copy timed out after 120s"))?
+    }
+
     /// Copy a file to remote host
     pub async fn copy_file_to_remote(
         &self,
@@ -259,6 +259,15 @@ pub async fn run_startup_checklist_with_config(
         }
     }
 
+    // `auto` only resolves to a strategy once the validator type is detected
+    if let Some(ref statuses) = validator_statuses {
+        let strategy_issues = resolved_strategy_issues(statuses);
+        if !strategy_issues.is_empty() {
+            validation.config_valid = false;
+            validation.issues.extend(strategy_issues);
+        }
+    }
+
     // Phase 5: Final validation and summary
     progress_bar.set_message("Finalizing startup...");
     validation.success = validation.config_valid
@@ -287,6 +296,7 @@ pub async fn run_startup_checklist_with_config(
                 metadata_cache,
                 detected_ssh_keys,
                 selected_validator_index: 0, // Default to first validator
+                switch_target_label: None,
             };
 
             // Auto-failover safety checks are now done per-validator during status detection
@@ -383,9 +393,11 @@ pub async fn run_startup_checklist_with_config(
             )
             .yellow()
         );
-        println!("{}", "Press Enter to exit...".dimmed());
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input).unwrap();
+        if crate::terminal::is_interactive() {
+            println!("{}", "Press Enter to exit...".dimmed());
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input).unwrap();
+        }
 
         Ok(None)
     }
@@ -563,10 +575,13 @@ async fn validate_configuration_with_config(
             if needs_migration {
                 println!("  🔄 Configuration needs migration to include public key identifiers");
 
-                let migrate_now =
-                    Confirm::new("Would you like to add the missing public key identifiers now?")
-                        .with_default(true)
-                        .prompt()?;
+                // Without an operator the migration waits for the next interactive run
+                let migrate_now = crate::terminal::is_interactive()
+                    && Confirm::new(
+                        "Would you like to add the missing public key identifiers now?",
+                    )
+                    .with_default(true)
+                    .prompt()?;
 
                 if migrate_now {
                     config = migrate_configuration(&config_manager, config).await?;
@@ -590,9 +605,10 @@ async fn validate_configuration_with_config(
                     println!("    • {}", issue.yellow());
                 }
 
-                let fix_now = Confirm::new("Would you like to fix these issues now?")
-                    .with_default(true)
-                    .prompt()?;
+                let fix_now = crate::terminal::is_interactive()
+                    && Confirm::new("Would you like to fix these issues now?")
+                        .with_default(true)
+                        .prompt()?;
 
                 if fix_now {
                     fix_configuration_issues(&config, &config_issues).await?;
@@ -923,25 +939,76 @@ fn validate_config_completeness(config: &Config) -> Vec<String> {
             issues.push(format!("{} RPC endpoint is empty", validator_name));
         }
 
-        // Check nodes - allow 1 or 2 nodes
+        if validator_pair.additional_rpcs.iter().any(|rpc| rpc.is_empty()) {
+            issues.push(format!("{} has an empty additionalRpcs entry", validator_name));
+        }
+
+        if let Some(quorum) = validator_pair.delinquency_quorum {
+            let endpoint_count = crate::vote_quorum::rpc_endpoints(validator_pair).len();
+            if quorum == 0 || quorum > endpoint_count {
+                issues.push(format!(
+                    "{} delinquencyQuorum must be between 1 and its {} RPC endpoint(s)",
+                    validator_name, endpoint_count
+                ));
+            }
+        }
+
+        validate_hooks(&validator_pair.hooks, &validator_name, false, &mut issues);
+
+        // Check nodes - a single node, or an active node plus any number of standbys
         if validator_pair.nodes.is_empty() {
             issues.push(format!(
                 "{} must have at least 1 node configured",
                 validator_name
             ));
-        } else if validator_pair.nodes.len() > 2 {
-            issues.push(format!("{} cannot have more than 2 nodes", validator_name));
+        }
+
+        let mut labels = std::collections::HashSet::new();
+        for node in &validator_pair.nodes {
+            if !labels.insert(node.label.as_str()) {
+                issues.push(format!(
+                    "{} has more than one node labelled '{}'",
+                    validator_name, node.label
+                ));
+            }
         }
 
         for (node_index, node) in validator_pair.nodes.iter().enumerate() {
             let node_name = format!("{} Node {}", validator_name, node_index + 1);
             validate_node_config(node, &node_name, &mut issues);
+            validate_hooks(&node.hooks, &node_name, true, &mut issues);
         }
     }
 
     issues
 }
 
+fn validate_hooks(
+    hooks: &[crate::types::SwitchHook],
+    owner_name: &str,
+    node_hooks: bool,
+    issues: &mut Vec<String>,
+) {
+    for hook in hooks {
+        let hook_name = format!("{} hook '{}'", owner_name, hook.name);
+        if hook.name.is_empty() {
+            issues.push(format!("{} has a hook without a name", owner_name));
+        }
+        if hook.command.trim().is_empty() {
+            issues.push(format!("{} command is empty", hook_name));
+        }
+        if hook.timeout_seconds == 0 {
+            issues.push(format!("{} timeoutSeconds must be at least 1", hook_name));
+        }
+        if !node_hooks && hook.run_on == crate::types::HookRunOn::Node {
+            issues.push(format!(
+                "{} uses runOn: node, which is only valid for node hooks (use source or target)",
+                hook_name
+            ));
+        }
+    }
+}
+
 fn validate_node_config(
     node: &crate::types::NodeConfig,
     node_name: &str,
@@ -991,6 +1058,46 @@ fn validate_node_config(
     if !has_agave && !has_fdctl {
         issues.push(format!("{} must have at least one validator executable configured: either agaveValidatorPath (for Agave/Jito) or fdctlPath (for Firedancer)", node_name));
     }
+
+    if node.switch_strategy == crate::types::SwitchStrategy::AuthorizedVoter && !has_agave {
+        issues.push(format!(
+            "{} uses switchStrategy: authorized_voter, which needs agaveValidatorPath",
+            node_name
+        ));
+    }
+
+    match node.restart_command.as_deref() {
+        Some(command) if command.trim().is_empty() => {
+            issues.push(format!("{} restartCommand is empty", node_name));
+        }
+        None if node.switch_strategy == crate::types::SwitchStrategy::Restart => {
+            issues.push(format!(
+                "{} uses switchStrategy: restart, which needs restartCommand",
+                node_name
+            ));
+        }
+        _ => {}
+    }
+}
+
+/// Nodes whose resolved switch strategy is `restart` but have no restartCommand
+pub(crate) fn resolved_strategy_issues(statuses: &[crate::ValidatorStatus]) -> Vec<String> {
+    let mut issues = Vec::new();
+    for status in statuses {
+        for node in &status.nodes_with_status {
+            let strategy = crate::switch_strategy::resolve_strategy(node);
+            if strategy == crate::types::SwitchStrategy::Restart
+                && node.node.restart_command.is_none()
+                && node.node.switch_strategy != crate::types::SwitchStrategy::Restart
+            {
+                issues.push(format!(
+                    "{} resolves switchStrategy: auto to restart (validator type not detected), which needs restartCommand",
+                    node.node.label
+                ));
+            }
+        }
+    }
+    issues
 }
 
 #[allow(dead_code)]
@@ -1173,21 +1280,13 @@ async fn show_ready_prompt() {
     // Flush stdout to ensure the prompt appears immediately
     io::stdout().flush().unwrap();
 
-    // Skip wait for status command
-    if std::env::args().any(|arg| arg == "status") {
+    // Skip wait for status command and when nobody is at the terminal
+    if std::env::args().any(|arg| arg == "status") || !crate::terminal::is_interactive() {
         // For status command, just clear everything
         print!("\x1B[2J\x1B[1;1H"); // Clear entire screen and move to top
         io::stdout().flush().unwrap();
     } else {
-        // Actually wait for ANY key press, not just Enter
-        use crossterm::event::{self, Event};
-        crossterm::terminal::enable_raw_mode().ok();
-        loop {
-            if let Ok(Event::Key(_)) = event::read() {
-                break;
-            }
-        }
-        crossterm::terminal::disable_raw_mode().ok();
+        crate::terminal::wait_for_any_key();
 
         // Clear the ready prompt
         print!("\x1B[8A\x1B[2K"); // Move up 8 lines and clear
@@ -1226,10 +1325,7 @@ pub async fn detect_node_statuses(
             ) = detect_node_status_and_executable(node, validator_pair, ssh_pool).await?;
             // Derive tower path from ledger path and validator pair identity
             let tower_path = ledger_path.as_ref().map(|ledger| {
-                format!(
-                    "{}/tower-1_9-{}.bin",
-                    ledger, validator_pair.identity_pubkey
-                )
+                crate::tower_file::tower_path_in_ledger(ledger, &validator_pair.identity_pubkey)
             });
 
             nodes_with_status.push(crate::types::NodeWithStatus {
@@ -1326,10 +1422,7 @@ async fn detect_node_statuses_with_progress(
 
             // Derive tower path from ledger path and validator pair identity
             let tower_path = ledger_path.as_ref().map(|ledger| {
-                format!(
-                    "{}/tower-1_9-{}.bin",
-                    ledger, validator_pair.identity_pubkey
-                )
+                crate::tower_file::tower_path_in_ledger(ledger, &validator_pair.identity_pubkey)
             });
 
             // Get the detected SSH key for this node
@@ -1654,6 +1747,22 @@ async fn detect_node_status_and_executable(
         }
     }
 
+    // Firedancer takes its ledger path from the config file, not the command line
+    if validator_type == crate::types::ValidatorType::Firedancer && ledger_path.is_none() {
+        if let Some(config_path) = firedancer_config_path.as_deref() {
+            if let Ok(path) = crate::startup_checks::detect_firedancer_ledger_path(
+                ssh_pool,
+                node,
+                ssh_key,
+                config_path,
+            )
+            .await
+            {
+                ledger_path = path;
+            }
+        }
+    }
+
     // If no running validator found, search for executables on disk as fallback
     if _main_validator_executable.is_none() {
         // logger.log("No running validator process found, searching for executables on disk...")?;
@@ -2032,12 +2141,10 @@ pub async fn check_node_swap_readiness(
                         issues.push("Vote keypair missing or not readable".to_string());
                         all_ready = false;
                     }
-                    "tower_fail" => {
-                        // Only report tower issues for non-standby nodes
-                        if is_standby != Some(true) {
-                            issues.push("Tower file missing".to_string());
-                            all_ready = false;
-                        }
+                    // Only report tower issues for non-standby nodes
+                    "tower_fail" if is_standby != Some(true) => {
+                        issues.push("Tower file missing".to_string());
+                        all_ready = false;
                     }
                     "ledger_fail" => {
                         issues.push("Ledger directory missing or not writable".to_string());
@@ -2194,32 +2301,36 @@ async fn detect_node_status_and_executable_with_progress(
                         .log(&format!("Found Firedancer config at: {}", config_path))
                         .ok();
 
-                    // Read config file and extract ledger path
-                    let cat_cmd = format!(
-                        "cat {} 2>/dev/null | grep -A 5 '\\[ledger\\]' | grep 'path' | head -1",
-                        config_path
-                    );
-                    if let Ok(config_output) =
-                        ssh_pool.execute_command(node, &ssh_key, &cat_cmd).await
+                    // Read config file and extract the [ledger] path
+                    match crate::startup_checks::detect_firedancer_ledger_path(
+                        ssh_pool,
+                        node,
+                        &ssh_key,
+                        config_path,
+                    )
+                    .await
                     {
-                        for line in config_output.lines() {
-                            if line.contains("path") && line.contains("=") {
-                                let path_parts: Vec<&str> = line.split('=').collect();
-                                if path_parts.len() >= 2 {
-                                    let path =
-                                        path_parts[1].trim().trim_matches('"').trim_matches('\'');
-                                    if !path.is_empty() {
-                                        ledger_path = Some(path.to_string());
-                                        logger
-                                            .log(&format!(
-                                                "Extracted ledger path from config: {}",
-                                                path
-                                            ))
-                                            .ok();
-                                        break;
-                                    }
-                                }
-                            }
+                        Ok(Some(path)) => {
+                            logger
+                                .log(&format!("Extracted ledger path from config: {}", path))
+                                .ok();
+                            ledger_path = Some(path);
+                        }
+                        Ok(None) => {
+                            logger
+                                .log_warning(&format!(
+                                    "No [ledger] path found in Firedancer config: {}",
+                                    config_path
+                                ))
+                                .ok();
+                        }
+                        Err(e) => {
+                            logger
+                                .log_warning(&format!(
+                                    "Failed to read Firedancer config {}: {}",
+                                    config_path, e
+                                ))
+                                .ok();
                         }
                     }
                     break;
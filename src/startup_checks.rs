@@ -135,6 +135,79 @@ fn parse_firedancer_consensus_paths(content: &str) -> Result<(String, String)> {
     Ok((identity_path, authorized_voter_path))
 }
 
+/// Walk a Firedancer TOML config and return the `path` key of the `[ledger]`
+/// section, which is where fdctl keeps the ledger and the tower file.
+///
+/// fdctl expands `{user}` and `{name}` in paths from the top-level `user` and
+/// `name` keys, so we do the same. Returns `None` when the section or key is
+/// missing, or when a placeholder cannot be resolved from the file (fdctl
+/// would then fall back to defaults we do not want to guess).
+pub(crate) fn parse_firedancer_ledger_path(content: &str) -> Option<String> {
+    let mut section: Option<String> = None;
+    let mut user: Option<String> = None;
+    let mut name: Option<String> = None;
+    let mut ledger_path: Option<String> = None;
+
+    for raw_line in content.lines() {
+        // Same comment handling as parse_firedancer_consensus_paths
+        let trimmed_full = raw_line.trim();
+        let trimmed = trimmed_full
+            .split('#')
+            .next()
+            .unwrap_or(trimmed_full)
+            .trim_end();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            section = Some(trimmed.to_string());
+            continue;
+        }
+
+        // Compare whole keys so `accounts_path` and friends don't match `path`
+        let Some((key, _)) = trimmed.split_once('=') else {
+            continue;
+        };
+        match (section.as_deref(), key.trim()) {
+            (None, "user") if user.is_none() => user = extract_quoted_value(trimmed),
+            (None, "name") if name.is_none() => name = extract_quoted_value(trimmed),
+            (Some("[ledger]"), "path") if ledger_path.is_none() => {
+                ledger_path = extract_quoted_value(trimmed)
+            }
+            _ => {}
+        }
+    }
+
+    let mut ledger_path = ledger_path.filter(|path| !path.is_empty())?;
+    if let Some(user) = user.filter(|user| !user.is_empty()) {
+        ledger_path = ledger_path.replace("{user}", &user);
+    }
+    if let Some(name) = name.filter(|name| !name.is_empty()) {
+        ledger_path = ledger_path.replace("{name}", &name);
+    }
+
+    if ledger_path.contains('{') {
+        None
+    } else {
+        Some(ledger_path.trim_end_matches('/').to_string())
+    }
+}
+
+/// Read a Firedancer config over SSH and return its `[ledger] path`, so tower
+/// transfer and readiness checks work the same as for Agave's `--ledger`
+pub(crate) async fn detect_firedancer_ledger_path(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    config_path: &str,
+) -> Result<Option<String>> {
+    let config_content =
+        fetch_firedancer_config_via_ssh(ssh_pool, node, ssh_key, config_path).await?;
+    Ok(parse_firedancer_ledger_path(&config_content))
+}
+
 /// Pull the value of `--config <path>` out of a `ps` listing for fdctl.
 ///
 /// The output may contain ANSI colour escapes from `grep --color=auto` (which
@@ -663,7 +736,7 @@ mod parser_tests {
     //! check that prevents booting with identity_path == authorized voter.
 
     use super::{extract_firedancer_config_path_from_ps_output, extract_quoted_value,
-                parse_firedancer_consensus_paths};
+                parse_firedancer_consensus_paths, parse_firedancer_ledger_path};
 
     fn assert_parsed(content: &str, expected_identity: &str, expected_voter: &str) {
         let (identity, voter) = parse_firedancer_consensus_paths(content)
@@ -781,6 +854,52 @@ identity_path = "/keys/unfunded.json"
         );
     }
 
+    #[test]
+    fn parses_ledger_path_from_the_ledger_section_only() {
+        // `path` keys in other sections and `accounts_path` inside [ledger]
+        // must not be mistaken for the ledger directory.
+        let content = r#"
+[snapshots]
+path = "/mnt/snapshots"
+
+[ledger]
+accounts_path = "/mnt/accounts"
+path = "/mnt/ledger/" # trailing slash is dropped
+"#;
+        assert_eq!(
+            parse_firedancer_ledger_path(content),
+            Some("/mnt/ledger".to_string())
+        );
+    }
+
+    #[test]
+    fn expands_user_and_name_placeholders_in_ledger_path() {
+        let content = r#"
+name = "mainnet"
+user = "sol"
+
+[ledger]
+path = "/home/{user}/.firedancer/{name}/ledger"
+"#;
+        assert_eq!(
+            parse_firedancer_ledger_path(content),
+            Some("/home/sol/.firedancer/mainnet/ledger".to_string())
+        );
+    }
+
+    #[test]
+    fn ledger_path_is_none_when_missing_or_unresolvable() {
+        assert_eq!(
+            parse_firedancer_ledger_path("[consensus]\nidentity_path = \"/k.json\"\n"),
+            None
+        );
+        // fdctl would fill {user} from its defaults; we refuse to guess.
+        assert_eq!(
+            parse_firedancer_ledger_path("[ledger]\npath = \"/home/{user}/ledger\"\n"),
+            None
+        );
+    }
+
     #[test]
     fn extract_quoted_value_handles_double_quotes() {
         assert_eq!(
@@ -1,7 +1,8 @@
 #[cfg(test)]
-mod startup_validation_tests {
+mod tests {
     use crate::types::{
-        NodeConfig, NodePaths, NodeStatus, NodeWithStatus, ValidatorPair, ValidatorType,
+        NodeConfig, NodePaths, NodeStatus, NodeWithStatus, SwitchStrategy, ValidatorPair,
+        ValidatorType,
     };
 
     // Helper function to create a test node
@@ -19,8 +20,13 @@ mod startup_validation_tests {
                     solana_cli: "/home/solana/bin/solana".to_string(),
                     agave_validator: Some("/home/solana/bin/agave-validator".to_string()),
                     fdctl: None,
+                    authorized_voter: None,
                 },
                 ssh_key_path: Some("/home/user/.ssh/id_rsa".to_string()),
+                priority: None,
+                hooks: vec![],
+                switch_strategy: SwitchStrategy::default(),
+                restart_command: None,
             },
             status: if available {
                 NodeStatus::Standby
@@ -53,6 +59,9 @@ mod startup_validation_tests {
             vote_pubkey: format!("vote_pubkey_{}", index),
             identity_pubkey: format!("identity_pubkey_{}", index),
             rpc: format!("http://rpc{}.example.com:8899", index),
+            additional_rpcs: vec![],
+            delinquency_quorum: None,
+            hooks: vec![],
             nodes: vec![
                 NodeConfig {
                     label: format!("node-{}-1", index),
@@ -66,8 +75,13 @@ mod startup_validation_tests {
                         solana_cli: "/home/solana/bin/solana".to_string(),
                         agave_validator: Some("/home/solana/bin/agave-validator".to_string()),
                         fdctl: None,
+                        authorized_voter: None,
                     },
                     ssh_key_path: Some("/home/user/.ssh/id_rsa".to_string()),
+                    priority: None,
+                    hooks: vec![],
+                    switch_strategy: SwitchStrategy::default(),
+                    restart_command: None,
                 },
                 NodeConfig {
                     label: format!("node-{}-2", index),
@@ -81,8 +95,13 @@ mod startup_validation_tests {
                         solana_cli: "/home/solana/bin/solana".to_string(),
                         agave_validator: Some("/home/solana/bin/agave-validator".to_string()),
                         fdctl: None,
+                        authorized_voter: None,
                     },
                     ssh_key_path: Some("/home/user/.ssh/id_rsa".to_string()),
+                    priority: None,
+                    hooks: vec![],
+                    switch_strategy: SwitchStrategy::default(),
+                    restart_command: None,
                 },
             ],
         }
@@ -93,7 +112,7 @@ mod startup_validation_tests {
         // Scenario 1: Validator 2 Node 2 is down, but we should still be able to start
         // and switch Validator 1 nodes
 
-        let validator_statuses = vec![
+        let validator_statuses = [
             crate::ValidatorStatus {
                 validator_pair: create_test_validator_pair(1),
                 nodes_with_status: vec![
@@ -163,7 +182,7 @@ mod startup_validation_tests {
         // Scenario 2: Validator 1 Node 1 (source) is down, but we should still be able to start
         // and potentially switch to Node 2 in emergency
 
-        let validator_statuses = vec![crate::ValidatorStatus {
+        let validator_statuses = [crate::ValidatorStatus {
             validator_pair: create_test_validator_pair(1),
             nodes_with_status: vec![
                 create_test_node("node-1-1", "validator1-1.example.com", false), // DOWN (source)
@@ -227,7 +246,7 @@ mod startup_validation_tests {
         // This test documents what the OLD behavior would have done
         // to ensure we understand the change
 
-        let validator_statuses = vec![
+        let validator_statuses = [
             crate::ValidatorStatus {
                 validator_pair: create_test_validator_pair(1),
                 nodes_with_status: vec![
@@ -274,4 +293,30 @@ mod startup_validation_tests {
         // OLD behavior would have blocked startup
         // assert!(!critical_failures_old.is_empty()); // This would have caused "CRITICAL STARTUP FAILURES DETECTED"
     }
+
+    #[test]
+    fn test_auto_strategy_on_unknown_validator_needs_restart_command() {
+        use crate::startup::resolved_strategy_issues;
+
+        let mut unknown = create_test_node("node-unknown", "validator1-2.example.com", true);
+        unknown.validator_type = ValidatorType::Unknown;
+        let mut statuses = vec![crate::ValidatorStatus {
+            validator_pair: create_test_validator_pair(1),
+            nodes_with_status: vec![
+                create_test_node("node-agave", "validator1-1.example.com", true),
+                unknown,
+            ],
+            metadata: None,
+        }];
+
+        // `auto` on an undetected validator resolves to restart
+        let issues = resolved_strategy_issues(&statuses);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("node-unknown"));
+        assert!(issues[0].contains("restartCommand"));
+
+        statuses[0].nodes_with_status[1].node.restart_command =
+            Some("sudo systemctl restart sol".to_string());
+        assert!(resolved_strategy_issues(&statuses).is_empty());
+    }
 }
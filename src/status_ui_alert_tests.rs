@@ -1,5 +1,5 @@
 #[cfg(test)]
-mod status_ui_alert_tests {
+mod tests {
     use crate::commands::status_ui_v2::{build_verbose_log_message, LogLevel};
     use crate::alert::AlertTracker;
     use crate::commands::status_ui_v2::{
@@ -139,6 +139,11 @@ validators: []
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: true,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
 
         // The CORRECT logic for auto-failover:
@@ -426,6 +431,11 @@ validators: []
             telegram: None,
             telegram_low_priority: None,
             auto_failover_enabled: false,
+            max_auto_failovers: 2,
+            auto_failover_window_seconds: 86400,
+            auto_failover_min_dwell_seconds: 900,
+            double_sign_auto_demote: false,
+            telegram_commands: false,
         };
         let mut ssh_tracker = FailureTracker::new();
         let mut rpc_tracker = FailureTracker::new();
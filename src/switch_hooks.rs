@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::ssh::AsyncSshPool;
+use crate::types::{
+    HookFailurePolicy, HookPoint, HookRunOn, NodeConfig, SwitchHook, ValidatorPair,
+};
+
+/// The SSH pool gives up on remote commands after 60s, so remote hooks are
+/// cut off by `timeout` on the node before that
+pub const MAX_REMOTE_HOOK_TIMEOUT_SECONDS: u64 = 55;
+
+/// Printed after a remote script so its exit code survives the SSH round trip
+pub(crate) const REMOTE_EXIT_MARKER: &str = "__SVS_EXIT=";
+
+impl std::fmt::Display for HookPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HookPoint::PreSwitch => "pre_switch",
+            HookPoint::PostTower => "post_tower",
+            HookPoint::PostPromotion => "post_promotion",
+            HookPoint::OnFailure => "on_failure",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A configured hook together with the node it runs on (None = locally)
+#[derive(Debug, Clone, Copy)]
+pub struct ScheduledHook<'a> {
+    pub hook: &'a SwitchHook,
+    pub node: Option<&'a NodeConfig>,
+}
+
+impl ScheduledHook<'_> {
+    pub fn location(&self) -> String {
+        match self.node {
+            Some(node) => format!("on {}", node.label),
+            None => "locally".to_string(),
+        }
+    }
+}
+
+/// Hooks due at `point`: the pair's, then the source node's, then the target
+/// node's, each in config order
+pub fn scheduled_hooks<'a>(
+    validator_pair: &'a ValidatorPair,
+    source: &'a NodeConfig,
+    target: &'a NodeConfig,
+    point: HookPoint,
+) -> Vec<ScheduledHook<'a>> {
+    let pair_hooks = validator_pair.hooks.iter().map(|hook| (hook, None));
+    let source_hooks = source.hooks.iter().map(|hook| (hook, Some(source)));
+    let target_hooks = target.hooks.iter().map(|hook| (hook, Some(target)));
+
+    pair_hooks
+        .chain(source_hooks)
+        .chain(target_hooks)
+        .filter(|(hook, _)| hook.at == point)
+        .map(|(hook, owner)| ScheduledHook {
+            hook,
+            node: match hook.run_on {
+                HookRunOn::Local => None,
+                HookRunOn::Source => Some(source),
+                HookRunOn::Target => Some(target),
+                // Startup validation rejects `node` on pair hooks
+                HookRunOn::Node => owner,
+            },
+        })
+        .collect()
+}
+
+/// Switch details passed to every hook as SVS_* environment variables
+pub struct HookContext<'a> {
+    pub validator_pair: &'a ValidatorPair,
+    pub source: &'a NodeConfig,
+    pub target: &'a NodeConfig,
+    /// Why the switch failed, for `on_failure` hooks
+    pub error: Option<String>,
+}
+
+pub fn hook_env(
+    point: HookPoint,
+    hook: &SwitchHook,
+    ctx: &HookContext,
+) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        ("SVS_HOOK_POINT", point.to_string()),
+        ("SVS_HOOK_NAME", hook.name.clone()),
+        (
+            "SVS_VALIDATOR_IDENTITY",
+            ctx.validator_pair.identity_pubkey.clone(),
+        ),
+        ("SVS_VOTE_PUBKEY", ctx.validator_pair.vote_pubkey.clone()),
+        ("SVS_SOURCE_LABEL", ctx.source.label.clone()),
+        ("SVS_SOURCE_HOST", ctx.source.host.clone()),
+        ("SVS_TARGET_LABEL", ctx.target.label.clone()),
+        ("SVS_TARGET_HOST", ctx.target.host.clone()),
+    ];
+    if let Some(error) = &ctx.error {
+        env.push(("SVS_SWITCH_ERROR", error.clone()));
+    }
+    env
+}
+
+pub(crate) fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Script run through `bash -c` on the remote node: exports the hook
+/// environment, enforces the timeout there and reports the exit code
+pub fn remote_hook_script(hook: &SwitchHook, env: &[(&'static str, String)]) -> String {
+    let exports = env
+        .iter()
+        .map(|(key, value)| format!("export {}={};", key, shell_quote(value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "{} timeout {} bash -c {} 2>&1; echo \"{}$?\"",
+        exports,
+        hook.timeout_seconds.min(MAX_REMOTE_HOOK_TIMEOUT_SECONDS),
+        shell_quote(&hook.command),
+        REMOTE_EXIT_MARKER
+    )
+}
+
+/// Split a remote hook's output into what the command printed and its exit
+/// code; None when the marker is missing (the SSH command itself failed)
+pub fn parse_remote_output(output: &str) -> (String, Option<i32>) {
+    let mut lines: Vec<&str> = output.trim_end().lines().collect();
+    let exit_code = lines
+        .last()
+        .and_then(|line| line.strip_prefix(REMOTE_EXIT_MARKER))
+        .and_then(|code| code.trim().parse().ok());
+    if exit_code.is_some() {
+        lines.pop();
+    }
+    (lines.join("\n").trim().to_string(), exit_code)
+}
+
+/// Result of one hook run
+#[derive(Debug, Clone)]
+pub struct HookOutcome {
+    pub name: String,
+    pub location: String,
+    pub policy: HookFailurePolicy,
+    pub elapsed: Duration,
+    pub error: Option<String>,
+}
+
+impl HookOutcome {
+    /// The hook failed and its policy stops the switch
+    pub fn aborts(&self) -> bool {
+        self.error.is_some() && self.policy == HookFailurePolicy::Abort
+    }
+}
+
+/// Last line of a hook's output, to keep error messages short
+fn last_line(output: &str) -> &str {
+    output.trim().lines().last().unwrap_or("")
+}
+
+fn failure_message(status: &str, output: &str) -> String {
+    match last_line(output) {
+        "" => status.to_string(),
+        line => format!("{}: {}", status, line),
+    }
+}
+
+async fn run_local_hook(hook: &SwitchHook, env: &[(&'static str, String)]) -> Result<(), String> {
+    let output = tokio::time::timeout(
+        Duration::from_secs(hook.timeout_seconds),
+        tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg(&hook.command)
+            .envs(env.iter().map(|(key, value)| (*key, value)))
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| format!("timed out after {}s", hook.timeout_seconds))?
+    .map_err(|e| format!("failed to start: {}", e))?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+    let mut printed = String::from_utf8_lossy(&output.stdout).to_string();
+    printed.push_str(&String::from_utf8_lossy(&output.stderr));
+    Err(failure_message(&output.status.to_string(), &printed))
+}
+
+async fn run_remote_hook(
+    hook: &SwitchHook,
+    node: &NodeConfig,
+    env: &[(&'static str, String)],
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+) -> Result<(), String> {
+    let ssh_key = detected_ssh_keys
+        .get(&node.host)
+        .ok_or_else(|| format!("no SSH key detected for {}", node.host))?;
+    let script = remote_hook_script(hook, env);
+    let output = ssh_pool
+        .execute_command_with_args(node, ssh_key, "bash", &["-c", &script])
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match parse_remote_output(&output) {
+        (_, Some(0)) => Ok(()),
+        // GNU timeout exits with 124 when it had to kill the command
+        (_, Some(124)) => Err(format!(
+            "timed out after {}s",
+            hook.timeout_seconds.min(MAX_REMOTE_HOOK_TIMEOUT_SECONDS)
+        )),
+        (printed, Some(code)) => Err(failure_message(&format!("exit status: {}", code), &printed)),
+        (printed, None) => Err(failure_message("no exit status reported", &printed)),
+    }
+}
+
+/// Run the hooks due at `point` in order. A failing `abort` hook stops the
+/// remaining ones unless `warn_only` is set (emergency takeovers never abort).
+pub async fn run_hooks(
+    point: HookPoint,
+    ctx: &HookContext<'_>,
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+    warn_only: bool,
+) -> Vec<HookOutcome> {
+    let mut outcomes = Vec::new();
+
+    for scheduled in scheduled_hooks(ctx.validator_pair, ctx.source, ctx.target, point) {
+        let hook = scheduled.hook;
+        let env = hook_env(point, hook, ctx);
+        let start = Instant::now();
+        let result = match scheduled.node {
+            Some(node) => run_remote_hook(hook, node, &env, ssh_pool, detected_ssh_keys).await,
+            None => run_local_hook(hook, &env).await,
+        };
+
+        let outcome = HookOutcome {
+            name: hook.name.clone(),
+            location: scheduled.location(),
+            policy: if warn_only {
+                HookFailurePolicy::Warn
+            } else {
+                hook.on_failure
+            },
+            elapsed: start.elapsed(),
+            error: result.err(),
+        };
+        let aborts = outcome.aborts();
+        outcomes.push(outcome);
+        if aborts {
+            break;
+        }
+    }
+
+    outcomes
+}
+
+#[cfg(test)]
+mod switch_hooks_tests {
+    use super::{parse_remote_output, remote_hook_script, scheduled_hooks};
+    use crate::types::{
+        HookFailurePolicy, HookPoint, HookRunOn, NodeConfig, NodePaths, SwitchHook, SwitchStrategy,
+        ValidatorPair,
+    };
+
+    fn hook(name: &str, at: HookPoint, run_on: HookRunOn) -> SwitchHook {
+        SwitchHook {
+            name: name.to_string(),
+            at,
+            command: "true".to_string(),
+            run_on,
+            timeout_seconds: 30,
+            on_failure: HookFailurePolicy::Warn,
+        }
+    }
+
+    fn node(label: &str, hooks: Vec<SwitchHook>) -> NodeConfig {
+        NodeConfig {
+            label: label.to_string(),
+            host: format!("{}.example", label),
+            port: 22,
+            user: "solana".to_string(),
+            paths: NodePaths {
+                funded_identity: "/home/solana/funded.json".to_string(),
+                unfunded_identity: "/home/solana/unfunded.json".to_string(),
+                vote_keypair: "/home/solana/vote.json".to_string(),
+                solana_cli: "/usr/bin/solana".to_string(),
+                agave_validator: None,
+                fdctl: None,
+                authorized_voter: None,
+            },
+            ssh_key_path: None,
+            priority: None,
+            hooks,
+            switch_strategy: SwitchStrategy::default(),
+            restart_command: None,
+        }
+    }
+
+    #[test]
+    fn hooks_are_selected_by_point_and_resolved_to_nodes() {
+        let source = node(
+            "node-a",
+            vec![hook("drain", HookPoint::PreSwitch, HookRunOn::Node)],
+        );
+        let target = node(
+            "node-b",
+            vec![
+                hook("relayer", HookPoint::PostPromotion, HookRunOn::Node),
+                hook("firewall", HookPoint::PreSwitch, HookRunOn::Source),
+            ],
+        );
+        let pair: ValidatorPair = serde_yaml::from_str(
+            r#"
+votePubkey: vote123
+identityPubkey: funded123
+rpc: https://rpc
+nodes: []
+hooks:
+  - name: dns
+    at: pre_switch
+    command: update-dns "$SVS_TARGET_HOST"
+    onFailure: abort
+  - name: page
+    at: on_failure
+    command: page-oncall
+    timeoutSeconds: 5
+"#,
+        )
+        .unwrap();
+        assert_eq!(pair.hooks[0].run_on, HookRunOn::Local);
+        assert_eq!(pair.hooks[0].on_failure, HookFailurePolicy::Abort);
+        assert_eq!(pair.hooks[1].timeout_seconds, 5);
+
+        let pre_switch = scheduled_hooks(&pair, &source, &target, HookPoint::PreSwitch);
+        let resolved: Vec<(&str, Option<&str>)> = pre_switch
+            .iter()
+            .map(|s| (s.hook.name.as_str(), s.node.map(|n| n.label.as_str())))
+            .collect();
+        assert_eq!(
+            resolved,
+            vec![
+                ("dns", None),
+                ("drain", Some("node-a")),
+                ("firewall", Some("node-a")),
+            ]
+        );
+
+        // Node hooks follow the node, whichever side of the switch it is on
+        let post_promotion = scheduled_hooks(&pair, &target, &source, HookPoint::PostPromotion);
+        assert_eq!(post_promotion.len(), 1);
+        assert_eq!(post_promotion[0].node.unwrap().label, "node-b");
+        assert!(scheduled_hooks(&pair, &source, &target, HookPoint::PostTower).is_empty());
+    }
+
+    #[test]
+    fn remote_script_quotes_and_caps_timeout() {
+        let mut slow = hook("relayer", HookPoint::PostPromotion, HookRunOn::Target);
+        slow.command = "systemctl restart 'jito relayer'".to_string();
+        slow.timeout_seconds = 300;
+        let script = remote_hook_script(&slow, &[("SVS_SOURCE_LABEL", "it's-a".to_string())]);
+        assert_eq!(
+            script,
+            "export SVS_SOURCE_LABEL='it'\\''s-a'; timeout 55 bash -c 'systemctl restart '\\''jito relayer'\\''' 2>&1; echo \"__SVS_EXIT=$?\""
+        );
+    }
+
+    #[test]
+    fn remote_exit_code_is_parsed_from_marker() {
+        assert_eq!(
+            parse_remote_output("updated record\n__SVS_EXIT=0\n"),
+            ("updated record".to_string(), Some(0))
+        );
+        assert_eq!(
+            parse_remote_output("permission denied\n__SVS_EXIT=3"),
+            ("permission denied".to_string(), Some(3))
+        );
+        assert_eq!(
+            parse_remote_output("bash: line 1: killed"),
+            ("bash: line 1: killed".to_string(), None)
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Last recorded position of a switch. Written before and after each step so an
+/// interrupted switch can be resumed or rolled back on the next startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwitchStep {
+    DemotingPrimary,
+    PrimaryDemoted,
+    TransferringTower,
+    TowerTransferred,
+    PromotingStandby,
+    RollingBack,
+}
+
+impl SwitchStep {
+    pub fn description(&self) -> &'static str {
+        match self {
+            SwitchStep::DemotingPrimary => "Step 1 started (active → unfunded), result unknown",
+            SwitchStep::PrimaryDemoted => "Step 1 completed (active is on unfunded identity)",
+            SwitchStep::TransferringTower => "Step 2 started (tower transfer), result unknown",
+            SwitchStep::TowerTransferred => "Step 2 completed (tower copied to standby)",
+            SwitchStep::PromotingStandby => "Step 3 started (standby → funded), result unknown",
+            SwitchStep::RollingBack => "Rollback started after a failed step, result unknown",
+        }
+    }
+
+    /// Whether the original active node still has to be demoted when resuming forward
+    pub fn needs_demotion_on_resume(&self) -> bool {
+        matches!(self, SwitchStep::DemotingPrimary | SwitchStep::RollingBack)
+    }
+
+    /// Whether the tower still has to be copied when resuming forward
+    pub fn needs_tower_on_resume(&self) -> bool {
+        matches!(
+            self,
+            SwitchStep::DemotingPrimary
+                | SwitchStep::PrimaryDemoted
+                | SwitchStep::TransferringTower
+                | SwitchStep::RollingBack
+        )
+    }
+
+    /// Whether the standby may hold the funded identity and must be demoted on rollback
+    pub fn standby_may_be_funded(&self) -> bool {
+        matches!(self, SwitchStep::PromotingStandby | SwitchStep::RollingBack)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchJournal {
+    pub identity_pubkey: String,
+    pub vote_pubkey: String,
+    pub from_node: String,
+    pub from_host: String,
+    pub to_node: String,
+    pub to_host: String,
+    pub step: SwitchStep,
+    pub started_at: String,
+    pub updated_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl SwitchJournal {
+    pub fn new(
+        validator_pair: &crate::types::ValidatorPair,
+        from: &crate::types::NodeConfig,
+        to: &crate::types::NodeConfig,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            identity_pubkey: validator_pair.identity_pubkey.clone(),
+            vote_pubkey: validator_pair.vote_pubkey.clone(),
+            from_node: from.label.clone(),
+            from_host: from.host.clone(),
+            to_node: to.label.clone(),
+            to_host: to.host.clone(),
+            step: SwitchStep::DemotingPrimary,
+            started_at: now.clone(),
+            updated_at: now,
+            last_error: None,
+        }
+    }
+
+    /// Journal file for a validator pair, keyed by its funded identity
+    pub fn path_for(identity_pubkey: &str) -> Result<PathBuf> {
+        let journal_dir = dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find home directory"))?
+            .join(".solana-validator-switch")
+            .join("journal");
+
+        Ok(journal_dir.join(format!("switch-{}.json", identity_pubkey)))
+    }
+
+    /// Load the unfinished journal for a pair, if any
+    pub fn load(identity_pubkey: &str) -> Result<Option<Self>> {
+        let path = Self::path_for(identity_pubkey)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let journal = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Corrupt switch journal {}: {}", path.display(), e))?;
+        Ok(Some(journal))
+    }
+
+    /// Record the current step and flush it to disk
+    pub fn record(&mut self, step: SwitchStep, error: Option<String>) -> Result<()> {
+        self.step = step;
+        self.updated_at = chrono::Utc::now().to_rfc3339();
+        if error.is_some() {
+            self.last_error = error;
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = Self::path_for(&self.identity_pubkey)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temp file and rename so a crash never leaves a half-written journal
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    /// Remove the journal once the switch (or rollback) has finished
+    pub fn clear(identity_pubkey: &str) -> Result<()> {
+        let path = Self::path_for(identity_pubkey)?;
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod journal_tests {
+    use super::SwitchStep;
+
+    #[test]
+    fn step_serializes_as_snake_case() {
+        let json = serde_json::to_string(&SwitchStep::TowerTransferred).unwrap();
+        assert_eq!(json, "\"tower_transferred\"");
+        let step: SwitchStep = serde_json::from_str("\"promoting_standby\"").unwrap();
+        assert_eq!(step, SwitchStep::PromotingStandby);
+    }
+
+    #[test]
+    fn resume_forward_skips_completed_steps() {
+        assert!(SwitchStep::DemotingPrimary.needs_demotion_on_resume());
+        assert!(!SwitchStep::PrimaryDemoted.needs_demotion_on_resume());
+        assert!(SwitchStep::TransferringTower.needs_tower_on_resume());
+        assert!(!SwitchStep::TowerTransferred.needs_tower_on_resume());
+        assert!(!SwitchStep::PromotingStandby.needs_tower_on_resume());
+    }
+
+    #[test]
+    fn rollback_demotes_standby_only_once_promotion_started() {
+        assert!(!SwitchStep::PrimaryDemoted.standby_may_be_funded());
+        assert!(!SwitchStep::TowerTransferred.standby_may_be_funded());
+        assert!(SwitchStep::PromotingStandby.standby_may_be_funded());
+        assert!(SwitchStep::RollingBack.standby_may_be_funded());
+    }
+}
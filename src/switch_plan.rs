@@ -0,0 +1,288 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::switch_strategy::{identity_command, IdentityRole};
+use crate::types::{NodeStatus, NodeWithStatus, SwitchConfig, SwitchStrategy, ValidatorPair};
+
+/// Bumped when the plan layout changes so `svs apply` refuses plans it cannot read
+pub const PLAN_FORMAT_VERSION: u32 = 1;
+
+/// Everything a switch resolves about one node, captured for review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedNode {
+    pub label: String,
+    pub host: String,
+    pub user: String,
+    pub validator_type: String,
+    /// `active` or `standby` when the plan was made
+    pub expected_status: String,
+    pub expected_identity: Option<String>,
+    pub version: Option<String>,
+    pub agave_validator_executable: Option<String>,
+    pub fdctl_executable: Option<String>,
+    pub solana_cli_executable: Option<String>,
+    pub firedancer_config_path: Option<String>,
+    pub ledger_path: Option<String>,
+    pub tower_path: Option<String>,
+    pub funded_identity_keypair: String,
+    pub unfunded_identity_keypair: String,
+    /// Configured switch strategy (plans made before strategies existed used set-identity)
+    #[serde(default)]
+    pub switch_strategy: SwitchStrategy,
+}
+
+impl PlannedNode {
+    pub fn from_node(node: &NodeWithStatus) -> Self {
+        Self {
+            label: node.node.label.clone(),
+            host: node.node.host.clone(),
+            user: node.node.user.clone(),
+            validator_type: format!("{:?}", node.validator_type),
+            expected_status: status_name(&node.status).to_string(),
+            expected_identity: node.current_identity.clone(),
+            version: node.version.clone(),
+            agave_validator_executable: node.agave_validator_executable.clone(),
+            fdctl_executable: node.fdctl_executable.clone(),
+            solana_cli_executable: node.solana_cli_executable.clone(),
+            firedancer_config_path: node.firedancer_config_path.clone(),
+            ledger_path: node.ledger_path.clone(),
+            tower_path: node.tower_path.clone(),
+            funded_identity_keypair: node.node.paths.funded_identity.clone(),
+            unfunded_identity_keypair: node.node.paths.unfunded_identity.clone(),
+            switch_strategy: node.node.switch_strategy,
+        }
+    }
+}
+
+/// The command `node` will run over SSH to move into `role`, for review
+fn planned_identity_command(
+    node: &NodeWithStatus,
+    role: IdentityRole,
+    require_tower: bool,
+) -> Result<String> {
+    let firedancer_config_path = node.firedancer_config_path.as_deref().unwrap_or("<config>");
+    let command = identity_command(node, Some(firedancer_config_path), role, require_tower)?;
+    Ok(format!(
+        "ssh {}@{} '{}'",
+        node.node.user, node.node.host, command.display
+    ))
+}
+
+pub(crate) fn status_name(status: &NodeStatus) -> &'static str {
+    match status {
+        NodeStatus::Active => "active",
+        NodeStatus::Standby => "standby",
+        NodeStatus::Unknown => "unknown",
+    }
+}
+
+/// Reviewable switch plan written by `svs switch --plan` and executed by `svs apply`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchPlan {
+    pub format_version: u32,
+    pub created_at: String,
+    pub identity_pubkey: String,
+    pub vote_pubkey: String,
+    /// Currently active node, demoted to its unfunded identity
+    pub source: PlannedNode,
+    /// Standby node that receives the tower and the funded identity
+    pub target: PlannedNode,
+    pub target_tower_path: String,
+    pub switch_config: SwitchConfig,
+    /// Commands in execution order, for review only
+    pub commands: Vec<String>,
+}
+
+impl SwitchPlan {
+    pub fn new(
+        validator_pair: &ValidatorPair,
+        source: &NodeWithStatus,
+        target: &NodeWithStatus,
+        switch_config: SwitchConfig,
+    ) -> Result<Self> {
+        if source.status != NodeStatus::Active || target.status != NodeStatus::Standby {
+            return Err(anyhow!(
+                "Plans need an active source and a standby target ({} is {}, {} is {}); use `svs switch` for recovery",
+                source.node.label,
+                status_name(&source.status),
+                target.node.label,
+                status_name(&target.status)
+            ));
+        }
+
+        let demote_command = planned_identity_command(source, IdentityRole::Unfunded, false)?;
+        let promote_command =
+            planned_identity_command(target, IdentityRole::Funded, switch_config.require_tower)?;
+        let source = PlannedNode::from_node(source);
+        let target = PlannedNode::from_node(target);
+
+        let tower_path = source
+            .tower_path
+            .clone()
+            .ok_or_else(|| anyhow!("Tower path not available for active node {}", source.label))?;
+        let target_ledger = target
+            .ledger_path
+            .as_ref()
+            .ok_or_else(|| anyhow!("Ledger path not detected for target node {}", target.label))?;
+        let tower_file_name = tower_path.split('/').last().unwrap_or("tower.bin");
+        let target_tower_path = format!("{}/{}", target_ledger, tower_file_name);
+
+        let demote_command =
+            if switch_config.tower_transfer_mode == crate::types::TowerTransferMode::Pipelined {
+                format!("{} && base64 {}", demote_command, tower_path)
+            } else {
+                demote_command
+            };
+        let commands = vec![
+            demote_command,
+            format!(
+                "copy {}:{} → {}:{}",
+                source.host, tower_path, target.host, target_tower_path
+            ),
+            promote_command,
+        ];
+
+        Ok(Self {
+            format_version: PLAN_FORMAT_VERSION,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            identity_pubkey: validator_pair.identity_pubkey.clone(),
+            vote_pubkey: validator_pair.vote_pubkey.clone(),
+            source,
+            target,
+            target_tower_path,
+            switch_config,
+            commands,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read plan {}: {}", path.display(), e))?;
+        let plan: Self = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Invalid plan {}: {}", path.display(), e))?;
+        if plan.format_version != PLAN_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Plan {} has format version {}, this svs reads version {}",
+                path.display(),
+                plan.format_version,
+                PLAN_FORMAT_VERSION
+            ));
+        }
+        Ok(plan)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(|e| anyhow!("Failed to write plan {}: {}", path.display(), e))
+    }
+
+    /// Compare the plan with the nodes as detected now. Returns one line per
+    /// difference; an empty list means the plan's preconditions still hold.
+    pub fn precondition_mismatches(&self, nodes: &[NodeWithStatus]) -> Vec<String> {
+        let mut mismatches = Vec::new();
+
+        for (role, planned) in [("source", &self.source), ("target", &self.target)] {
+            let Some(node) = nodes.iter().find(|n| n.node.label == planned.label) else {
+                mismatches.push(format!(
+                    "{} node {} is no longer configured",
+                    role, planned.label
+                ));
+                continue;
+            };
+            let mut current = PlannedNode::from_node(node);
+            // Startup may not cache the Firedancer config path; the plan's resolved one is used
+            if current.firedancer_config_path.is_none() {
+                current.firedancer_config_path = planned.firedancer_config_path.clone();
+            }
+
+            let fields = [
+                ("host", Some(&planned.host), Some(&current.host)),
+                ("user", Some(&planned.user), Some(&current.user)),
+                (
+                    "validator type",
+                    Some(&planned.validator_type),
+                    Some(&current.validator_type),
+                ),
+                (
+                    "status",
+                    Some(&planned.expected_status),
+                    Some(&current.expected_status),
+                ),
+                (
+                    "identity",
+                    planned.expected_identity.as_ref(),
+                    current.expected_identity.as_ref(),
+                ),
+                (
+                    "version",
+                    planned.version.as_ref(),
+                    current.version.as_ref(),
+                ),
+                (
+                    "agave-validator",
+                    planned.agave_validator_executable.as_ref(),
+                    current.agave_validator_executable.as_ref(),
+                ),
+                (
+                    "fdctl",
+                    planned.fdctl_executable.as_ref(),
+                    current.fdctl_executable.as_ref(),
+                ),
+                (
+                    "solana cli",
+                    planned.solana_cli_executable.as_ref(),
+                    current.solana_cli_executable.as_ref(),
+                ),
+                (
+                    "firedancer config",
+                    planned.firedancer_config_path.as_ref(),
+                    current.firedancer_config_path.as_ref(),
+                ),
+                (
+                    "ledger path",
+                    planned.ledger_path.as_ref(),
+                    current.ledger_path.as_ref(),
+                ),
+                (
+                    "tower path",
+                    planned.tower_path.as_ref(),
+                    current.tower_path.as_ref(),
+                ),
+                (
+                    "funded identity keypair",
+                    Some(&planned.funded_identity_keypair),
+                    Some(&current.funded_identity_keypair),
+                ),
+                (
+                    "unfunded identity keypair",
+                    Some(&planned.unfunded_identity_keypair),
+                    Some(&current.unfunded_identity_keypair),
+                ),
+            ];
+
+            if planned.switch_strategy != current.switch_strategy {
+                mismatches.push(format!(
+                    "{} node {}: switch strategy was {:?} in the plan, now {:?}",
+                    role, planned.label, planned.switch_strategy, current.switch_strategy
+                ));
+            }
+
+            for (field, expected, actual) in fields {
+                if expected != actual {
+                    mismatches.push(format!(
+                        "{} node {}: {} was {} in the plan, now {}",
+                        role,
+                        planned.label,
+                        field,
+                        expected.map(String::as_str).unwrap_or("none"),
+                        actual.map(String::as_str).unwrap_or("none")
+                    ));
+                }
+            }
+        }
+
+        mismatches
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::ssh::AsyncSshPool;
+use crate::switch_hooks::shell_quote;
+use crate::switch_strategy::resolve_strategy;
+use crate::types::{NodeWithStatus, SwitchStrategy, ValidatorPair, ValidatorType};
+
+/// One read-only probe run by `svs switch --dry-run`
+#[derive(Debug, Clone, Serialize)]
+pub struct PreflightCheck {
+    pub check: String,
+    pub node: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl PreflightCheck {
+    fn new(check: &str, node: &NodeWithStatus, passed: bool, detail: String) -> Self {
+        Self {
+            check: check.to_string(),
+            node: node.node.label.clone(),
+            passed,
+            detail,
+        }
+    }
+
+    /// Checklist line, e.g. `✅ Tower readable (node-a): /mnt/ledger/tower-1_9-X.bin`
+    pub fn line(&self) -> String {
+        format!(
+            "{} {} ({}): {}",
+            if self.passed { "✅" } else { "❌" },
+            self.check,
+            self.node,
+            self.detail
+        )
+    }
+}
+
+/// Probe both nodes without changing anything: SSH round trip, Firedancer
+/// config resolution, tower readability on the active node, write access to
+/// the standby ledger and the pubkeys behind each node's identity keypairs
+pub async fn run_preflight(
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+    validator_pair: &ValidatorPair,
+    active: &NodeWithStatus,
+    standby: &NodeWithStatus,
+) -> Vec<PreflightCheck> {
+    let mut checks = Vec::new();
+
+    for (node, is_active) in [(active, true), (standby, false)] {
+        let Some(ssh_key) = detected_ssh_keys.get(&node.node.host) else {
+            checks.push(PreflightCheck::new(
+                "SSH",
+                node,
+                false,
+                format!("no SSH key detected for {}", node.node.host),
+            ));
+            continue;
+        };
+
+        checks.push(ssh_round_trip(ssh_pool, node, ssh_key).await);
+
+        if node.validator_type == ValidatorType::Firedancer
+            && resolve_strategy(node) == SwitchStrategy::SetIdentity
+        {
+            let check = match crate::commands::switch::firedancer_config_path(
+                ssh_pool,
+                detected_ssh_keys,
+                node,
+            )
+            .await
+            {
+                Ok(path) => PreflightCheck::new("Firedancer config", node, true, path),
+                Err(e) => PreflightCheck::new("Firedancer config", node, false, e.to_string()),
+            };
+            checks.push(check);
+        }
+
+        let solana_cli = node
+            .solana_cli_executable
+            .as_deref()
+            .unwrap_or(&node.node.paths.solana_cli);
+        for (check, keypair, funded) in [
+            ("Funded keypair", &node.node.paths.funded_identity, true),
+            (
+                "Unfunded keypair",
+                &node.node.paths.unfunded_identity,
+                false,
+            ),
+        ] {
+            let command = format!("{} address -k {}", solana_cli, shell_quote(keypair));
+            let (passed, detail) = match ssh_pool
+                .execute_command(&node.node, ssh_key, &command)
+                .await
+            {
+                Ok(output) => keypair_check(&output, &validator_pair.identity_pubkey, funded),
+                Err(e) => (false, format!("{}: {}", keypair, e)),
+            };
+            checks.push(PreflightCheck::new(check, node, passed, detail));
+        }
+
+        if is_active {
+            checks.push(match &node.tower_path {
+                Some(tower_path) => {
+                    let probe = format!(
+                        "test -r {0} && test -s {0} && echo ok",
+                        shell_quote(tower_path)
+                    );
+                    probe_check(
+                        ssh_pool,
+                        node,
+                        ssh_key,
+                        "Tower readable",
+                        &probe,
+                        tower_path,
+                    )
+                    .await
+                }
+                None => PreflightCheck::new(
+                    "Tower readable",
+                    node,
+                    false,
+                    "tower path not detected".to_string(),
+                ),
+            });
+        } else {
+            checks.push(match &node.ledger_path {
+                Some(ledger_path) => {
+                    let probe = format!(
+                        "test -d {0} && test -w {0} && echo ok",
+                        shell_quote(ledger_path)
+                    );
+                    probe_check(
+                        ssh_pool,
+                        node,
+                        ssh_key,
+                        "Ledger writable",
+                        &probe,
+                        ledger_path,
+                    )
+                    .await
+                }
+                None => PreflightCheck::new(
+                    "Ledger writable",
+                    node,
+                    false,
+                    "ledger path not detected".to_string(),
+                ),
+            });
+        }
+    }
+
+    checks
+}
+
+/// Time a no-op command over an established session
+async fn ssh_round_trip(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeWithStatus,
+    ssh_key: &str,
+) -> PreflightCheck {
+    if let Err(e) = ssh_pool.get_session(&node.node, ssh_key).await {
+        return PreflightCheck::new("SSH", node, false, e.to_string());
+    }
+    let start = Instant::now();
+    match ssh_pool
+        .execute_command(&node.node, ssh_key, "echo ok")
+        .await
+    {
+        Ok(output) if output.trim() == "ok" => PreflightCheck::new(
+            "SSH",
+            node,
+            true,
+            format!("{}ms round trip", start.elapsed().as_millis()),
+        ),
+        Ok(output) => PreflightCheck::new(
+            "SSH",
+            node,
+            false,
+            format!("unexpected output: {}", output.trim()),
+        ),
+        Err(e) => PreflightCheck::new("SSH", node, false, e.to_string()),
+    }
+}
+
+/// Run a shell test that prints `ok` on success
+async fn probe_check(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeWithStatus,
+    ssh_key: &str,
+    check: &str,
+    probe: &str,
+    path: &str,
+) -> PreflightCheck {
+    match ssh_pool.execute_command(&node.node, ssh_key, probe).await {
+        Ok(output) if output.trim() == "ok" => {
+            PreflightCheck::new(check, node, true, path.to_string())
+        }
+        Ok(_) => PreflightCheck::new(check, node, false, format!("{} failed the check", path)),
+        Err(e) => PreflightCheck::new(check, node, false, format!("{}: {}", path, e)),
+    }
+}
+
+/// Judge `solana address -k` output: the funded keypair must be the
+/// validator identity, the unfunded one any other valid pubkey
+fn keypair_check(output: &str, identity_pubkey: &str, funded: bool) -> (bool, String) {
+    let pubkey = output.trim();
+    if pubkey.parse::<solana_sdk::pubkey::Pubkey>().is_err() {
+        return (false, format!("not a keypair: {}", pubkey));
+    }
+    match (funded, pubkey == identity_pubkey) {
+        (true, true) | (false, false) => (true, pubkey.to_string()),
+        (true, false) => (
+            false,
+            format!(
+                "{} is not the validator identity {}",
+                pubkey, identity_pubkey
+            ),
+        ),
+        (false, true) => (
+            false,
+            format!("{} is the funded validator identity", pubkey),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::keypair_check;
+
+    const IDENTITY: &str = "Vote111111111111111111111111111111111111111";
+    const OTHER: &str = "11111111111111111111111111111111";
+
+    #[test]
+    fn funded_keypair_must_match_the_identity() {
+        assert_eq!(
+            keypair_check(&format!("{}\n", IDENTITY), IDENTITY, true),
+            (true, IDENTITY.to_string())
+        );
+        let (passed, detail) = keypair_check(OTHER, IDENTITY, true);
+        assert!(!passed);
+        assert!(detail.contains("is not the validator identity"));
+    }
+
+    #[test]
+    fn unfunded_keypair_must_differ_from_the_identity() {
+        assert!(keypair_check(OTHER, IDENTITY, false).0);
+        assert!(!keypair_check(IDENTITY, IDENTITY, false).0);
+        assert!(!keypair_check("Error: No such file or directory", IDENTITY, false).0);
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A scheduled switch more than this late (e.g. svs was not running) is skipped
+/// rather than executed at a surprising time
+pub const MISSED_SWITCH_GRACE_SECONDS: i64 = 900;
+
+/// A planned maintenance switch, executed by the long-running status process
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledSwitch {
+    pub id: u64,
+    pub identity_pubkey: String,
+    pub at: DateTime<Utc>,
+    /// Node to make active; None promotes the best standby
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_node: Option<String>,
+    /// Switch back to the previously active node this long after the switch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub switch_back_after_seconds: Option<u64>,
+    /// Set on the entry created for an automatic switch back
+    #[serde(default)]
+    pub is_switch_back: bool,
+}
+
+impl ScheduledSwitch {
+    pub fn describe(&self) -> String {
+        let target = self.to_node.as_deref().unwrap_or("best standby");
+        let mut text = format!(
+            "#{} {} → {}{}",
+            self.id,
+            self.at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S %Z"),
+            target,
+            if self.is_switch_back {
+                " (switch back)"
+            } else {
+                ""
+            }
+        );
+        if let Some(seconds) = self.switch_back_after_seconds {
+            text.push_str(&format!(", switch back after {}", format_duration(seconds)));
+        }
+        text
+    }
+}
+
+/// Planned switches for all validators, persisted in
+/// `~/.solana-validator-switch/schedule.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SwitchSchedule {
+    pub switches: Vec<ScheduledSwitch>,
+}
+
+impl SwitchSchedule {
+    pub fn path() -> Result<PathBuf> {
+        Ok(dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find home directory"))?
+            .join(".solana-validator-switch")
+            .join("schedule.json"))
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Corrupt switch schedule {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temp file and rename so a crash never leaves a half-written schedule
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    /// Add a planned switch and return its id
+    pub fn add(
+        &mut self,
+        identity_pubkey: &str,
+        at: DateTime<Utc>,
+        to_node: Option<String>,
+        switch_back_after_seconds: Option<u64>,
+        is_switch_back: bool,
+    ) -> u64 {
+        let id = self.switches.iter().map(|s| s.id).max().unwrap_or(0) + 1;
+        self.switches.push(ScheduledSwitch {
+            id,
+            identity_pubkey: identity_pubkey.to_string(),
+            at,
+            to_node,
+            switch_back_after_seconds,
+            is_switch_back,
+        });
+        self.switches.sort_by_key(|s| s.at);
+        id
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<ScheduledSwitch> {
+        let idx = self.switches.iter().position(|s| s.id == id)?;
+        Some(self.switches.remove(idx))
+    }
+
+    /// Remove and return the earliest switch that is due at `now`
+    pub fn take_due(&mut self, now: DateTime<Utc>) -> Option<ScheduledSwitch> {
+        let idx = self
+            .switches
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.at <= now)
+            .min_by_key(|(_, s)| s.at)
+            .map(|(idx, _)| idx)?;
+        Some(self.switches.remove(idx))
+    }
+}
+
+/// Parse `--at`: RFC 3339 (`2026-10-20T03:00:00Z`) or local time (`2026-10-20 03:00`)
+pub fn parse_schedule_time(input: &str) -> Result<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(input, format) {
+            return Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|time| time.with_timezone(&Utc))
+                .ok_or_else(|| anyhow!("'{}' does not exist in the local time zone", input));
+        }
+    }
+
+    Err(anyhow!(
+        "Invalid time '{}': use RFC 3339 (2026-10-20T03:00:00Z) or local 'YYYY-MM-DD HH:MM'",
+        input
+    ))
+}
+
+/// Parse a duration such as `90s`, `30m`, `2h`, `1d` or `1h30m`
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    let mut total = 0u64;
+    let mut digits = String::new();
+
+    for c in input.trim().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => {
+                return Err(anyhow!(
+                    "Invalid duration '{}': unknown unit '{}'",
+                    input,
+                    c
+                ))
+            }
+        };
+        let value: u64 = digits.parse().map_err(|_| {
+            anyhow!(
+                "Invalid duration '{}': expected a number before '{}'",
+                input,
+                c
+            )
+        })?;
+        total += value * unit;
+        digits.clear();
+    }
+
+    if !digits.is_empty() || total == 0 {
+        return Err(anyhow!(
+            "Invalid duration '{}': use e.g. 90s, 30m, 2h or 1h30m",
+            input
+        ));
+    }
+    Ok(Duration::from_secs(total))
+}
+
+pub fn format_duration(seconds: u64) -> String {
+    let (hours, minutes, secs) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    let mut text = String::new();
+    if hours > 0 {
+        text.push_str(&format!("{}h", hours));
+    }
+    if minutes > 0 {
+        text.push_str(&format!("{}m", minutes));
+    }
+    if secs > 0 || text.is_empty() {
+        text.push_str(&format!("{}s", secs));
+    }
+    text
+}
+
+#[cfg(test)]
+mod schedule_tests {
+    use super::{format_duration, parse_duration, parse_schedule_time, SwitchSchedule};
+    use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+    use std::time::Duration;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::from_secs(7200));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("5w").is_err());
+        assert_eq!(format_duration(5400), "1h30m");
+        assert_eq!(format_duration(7200), "2h");
+    }
+
+    #[test]
+    fn parses_rfc3339_time() {
+        let time = parse_schedule_time("2026-10-20T03:00:00+02:00").unwrap();
+        assert_eq!(time, Utc.with_ymd_and_hms(2026, 10, 20, 1, 0, 0).unwrap());
+        assert!(parse_schedule_time("2026-10-20 03:00").is_ok());
+        assert!(parse_schedule_time("tomorrow").is_err());
+    }
+
+    #[test]
+    fn take_due_returns_earliest_due_switch_once() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 20, 3, 0, 0).unwrap();
+        let mut schedule = SwitchSchedule::default();
+        let later = schedule.add("id", now + ChronoDuration::hours(1), None, None, false);
+        let due = schedule.add(
+            "id",
+            now - ChronoDuration::minutes(1),
+            None,
+            Some(3600),
+            false,
+        );
+
+        let taken = schedule.take_due(now).unwrap();
+        assert_eq!(taken.id, due);
+        assert_eq!(taken.switch_back_after_seconds, Some(3600));
+        assert!(schedule.take_due(now).is_none());
+        assert_eq!(schedule.switches.len(), 1);
+        assert_eq!(schedule.switches[0].id, later);
+    }
+}
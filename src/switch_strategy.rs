@@ -0,0 +1,433 @@
+use anyhow::{anyhow, Result};
+
+use crate::switch_hooks::{parse_remote_output, shell_quote, REMOTE_EXIT_MARKER};
+use crate::types::{NodeWithStatus, SwitchStrategy, ValidatorType};
+
+/// The role a switch moves a node into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityRole {
+    /// Votes for the validator (funded identity or authorized voter)
+    Funded,
+    /// Follows the cluster without voting
+    Unfunded,
+}
+
+/// The strategy `node` switches with; `auto` becomes set-identity for known
+/// validator clients and a restart for anything else
+pub fn resolve_strategy(node: &NodeWithStatus) -> SwitchStrategy {
+    match node.node.switch_strategy {
+        SwitchStrategy::Auto => match node.validator_type {
+            ValidatorType::Unknown => SwitchStrategy::Restart,
+            _ => SwitchStrategy::SetIdentity,
+        },
+        strategy => strategy,
+    }
+}
+
+/// Remote command that moves a node into a role
+#[derive(Debug, Clone)]
+pub struct StrategyCommand {
+    pub subtitle: &'static str,
+    /// What gets run, for dry runs and switch plans
+    pub display: String,
+    pub program: String,
+    pub args: Vec<String>,
+    /// The command ends with the exit-code marker; its output must be checked
+    pub reports_exit_code: bool,
+}
+
+impl StrategyCommand {
+    /// Run the command through bash and end its output with the exit-code
+    /// marker, so a refusal is seen even when the command printed something
+    fn reporting_exit_code(mut self) -> Self {
+        let command = std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|arg| shell_quote(arg))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.program = "bash".to_string();
+        self.args = vec![
+            "-c".to_string(),
+            format!("{} 2>&1; echo \"{}$?\"", command, REMOTE_EXIT_MARKER),
+        ];
+        self.reports_exit_code = true;
+        self
+    }
+
+    /// Turn the output of a `reports_exit_code` command into an error if it failed
+    pub fn check_output(&self, output: &str) -> Result<()> {
+        if !self.reports_exit_code {
+            return Ok(());
+        }
+        match parse_remote_output(output) {
+            (_, Some(0)) => Ok(()),
+            (printed, Some(code)) => Err(anyhow!(
+                "{} failed (exit status {}): {}",
+                self.display,
+                code,
+                printed.lines().last().unwrap_or("no output")
+            )),
+            (printed, None) => Err(anyhow!(
+                "{} reported no exit status: {}",
+                self.display,
+                printed.lines().last().unwrap_or("no output")
+            )),
+        }
+    }
+}
+
+fn role_keypair(node: &NodeWithStatus, role: IdentityRole) -> &str {
+    match role {
+        IdentityRole::Funded => &node.node.paths.funded_identity,
+        IdentityRole::Unfunded => &node.node.paths.unfunded_identity,
+    }
+}
+
+fn agave_executable(node: &NodeWithStatus) -> Result<&str> {
+    node.agave_validator_executable
+        .as_deref()
+        .ok_or_else(|| anyhow!("Agave validator executable path not found"))
+}
+
+fn ledger_path(node: &NodeWithStatus) -> Result<&str> {
+    node.ledger_path
+        .as_deref()
+        .ok_or_else(|| anyhow!("Ledger path not detected for {}", node.node.label))
+}
+
+/// Command that moves `node` into `role` with its switch strategy.
+/// `firedancer_config_path` is only needed for Firedancer set-identity;
+/// `require_tower` makes an Agave/Jito promotion refuse to run without a tower.
+pub fn identity_command(
+    node: &NodeWithStatus,
+    firedancer_config_path: Option<&str>,
+    role: IdentityRole,
+    require_tower: bool,
+) -> Result<StrategyCommand> {
+    match resolve_strategy(node) {
+        SwitchStrategy::AuthorizedVoter => authorized_voter_command(node, role),
+        SwitchStrategy::Restart => restart_command(node, role),
+        _ => set_identity_command(
+            node,
+            firedancer_config_path,
+            role,
+            require_tower && role == IdentityRole::Funded,
+        ),
+    }
+}
+
+fn set_identity_command(
+    node: &NodeWithStatus,
+    firedancer_config_path: Option<&str>,
+    role: IdentityRole,
+    require_tower: bool,
+) -> Result<StrategyCommand> {
+    let keypair = role_keypair(node, role);
+    match node.validator_type {
+        ValidatorType::Firedancer => {
+            let fdctl_path = crate::executable_utils::get_fdctl_path(node)?;
+            let config_path = firedancer_config_path.ok_or_else(|| {
+                anyhow!(
+                    "Firedancer config path not resolved for {}",
+                    node.node.label
+                )
+            })?;
+            Ok(StrategyCommand {
+                subtitle: "Using Firedancer fdctl set-identity",
+                display: format!(
+                    "{} set-identity --config \"{}\" \"{}\"",
+                    fdctl_path, config_path, keypair
+                ),
+                args: vec![
+                    "set-identity".to_string(),
+                    "--config".to_string(),
+                    config_path.to_string(),
+                    keypair.to_string(),
+                ],
+                program: fdctl_path,
+                reports_exit_code: false,
+            })
+        }
+        ValidatorType::Agave | ValidatorType::Jito => {
+            let agave_path = agave_executable(node)?;
+            let ledger_path = ledger_path(node)?;
+            let mut args = vec![
+                "-l".to_string(),
+                ledger_path.to_string(),
+                "set-identity".to_string(),
+            ];
+            if require_tower {
+                args.push("--require-tower".to_string());
+            }
+            args.push(keypair.to_string());
+            let command = StrategyCommand {
+                subtitle: "Using Agave validator set-identity",
+                display: format!(
+                    "{} -l \"{}\" set-identity {}\"{}\"",
+                    agave_path,
+                    ledger_path,
+                    if require_tower {
+                        "--require-tower "
+                    } else {
+                        ""
+                    },
+                    keypair
+                ),
+                program: agave_path.to_string(),
+                args,
+                reports_exit_code: false,
+            };
+            // A --require-tower refusal has to be told apart by its exit status
+            Ok(if require_tower {
+                command.reporting_exit_code()
+            } else {
+                command
+            })
+        }
+        ValidatorType::Unknown => Err(anyhow!(
+            "Unsupported validator type for set-identity on {} (use switchStrategy: restart)",
+            node.node.label
+        )),
+    }
+}
+
+/// The node keeps its own identity; only the vote account's authorized voter
+/// is added to (funded) or removed from (unfunded) the running validator
+fn authorized_voter_command(node: &NodeWithStatus, role: IdentityRole) -> Result<StrategyCommand> {
+    if !matches!(
+        node.validator_type,
+        ValidatorType::Agave | ValidatorType::Jito
+    ) {
+        return Err(anyhow!(
+            "The authorized_voter strategy needs agave-validator, but {} runs {:?}",
+            node.node.label,
+            node.validator_type
+        ));
+    }
+    let agave_path = agave_executable(node)?;
+    let ledger_path = ledger_path(node)?;
+
+    let (display, action_args) = match role {
+        IdentityRole::Funded => {
+            let keypair = node
+                .node
+                .paths
+                .authorized_voter
+                .as_deref()
+                .unwrap_or(&node.node.paths.funded_identity);
+            (
+                format!(
+                    "{} -l \"{}\" authorized-voter add \"{}\"",
+                    agave_path, ledger_path, keypair
+                ),
+                vec!["add".to_string(), keypair.to_string()],
+            )
+        }
+        IdentityRole::Unfunded => (
+            format!(
+                "{} -l \"{}\" authorized-voter remove-all",
+                agave_path, ledger_path
+            ),
+            vec!["remove-all".to_string()],
+        ),
+    };
+
+    let mut args = vec![
+        "-l".to_string(),
+        ledger_path.to_string(),
+        "authorized-voter".to_string(),
+    ];
+    args.extend(action_args);
+    Ok(StrategyCommand {
+        subtitle: "Using Agave authorized-voter hot-swap",
+        display,
+        program: agave_path.to_string(),
+        args,
+        reports_exit_code: false,
+    })
+}
+
+/// Restart the validator process with the role's identity keypair through the
+/// node's `restartCommand`. svs does not know how the validator is launched, so
+/// it never builds a validator command line itself.
+fn restart_command(node: &NodeWithStatus, role: IdentityRole) -> Result<StrategyCommand> {
+    let command = node.node.restart_command.as_deref().ok_or_else(|| {
+        anyhow!(
+            "{} switches by restarting the validator but has no restartCommand configured",
+            node.node.label
+        )
+    })?;
+    let keypair = role_keypair(node, role);
+    let mut env = vec![
+        ("SVS_IDENTITY_KEYPAIR", keypair.to_string()),
+        (
+            "SVS_IDENTITY_ROLE",
+            match role {
+                IdentityRole::Funded => "funded",
+                IdentityRole::Unfunded => "unfunded",
+            }
+            .to_string(),
+        ),
+        ("SVS_VOTE_KEYPAIR", node.node.paths.vote_keypair.clone()),
+    ];
+    if let Some(ledger_path) = &node.ledger_path {
+        env.push(("SVS_LEDGER_PATH", ledger_path.clone()));
+    }
+
+    let exports = env
+        .iter()
+        .map(|(key, value)| format!("export {}={};", key, shell_quote(value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let script = format!(
+        "{} {{ {}\n}} 2>&1; echo \"{}$?\"",
+        exports, command, REMOTE_EXIT_MARKER
+    );
+
+    Ok(StrategyCommand {
+        subtitle: "Using validator restart",
+        display: format!("{} (SVS_IDENTITY_KEYPAIR={})", command, keypair),
+        program: "bash".to_string(),
+        args: vec!["-c".to_string(), script],
+        reports_exit_code: true,
+    })
+}
+
+#[cfg(test)]
+mod switch_strategy_tests {
+    use super::{identity_command, resolve_strategy, IdentityRole};
+    use crate::types::{
+        NodeConfig, NodePaths, NodeStatus, NodeWithStatus, SwitchStrategy, ValidatorType,
+    };
+
+    fn node(validator_type: ValidatorType, switch_strategy: SwitchStrategy) -> NodeWithStatus {
+        NodeWithStatus {
+            node: NodeConfig {
+                label: "node-a".to_string(),
+                host: "10.0.0.1".to_string(),
+                port: 22,
+                user: "solana".to_string(),
+                paths: NodePaths {
+                    funded_identity: "/home/solana/funded.json".to_string(),
+                    unfunded_identity: "/home/solana/unfunded.json".to_string(),
+                    vote_keypair: "/home/solana/vote.json".to_string(),
+                    solana_cli: "/usr/bin/solana".to_string(),
+                    agave_validator: None,
+                    fdctl: None,
+                    authorized_voter: None,
+                },
+                ssh_key_path: None,
+                priority: None,
+                hooks: vec![],
+                switch_strategy,
+                restart_command: None,
+            },
+            status: NodeStatus::Active,
+            validator_type,
+            agave_validator_executable: Some("/usr/bin/agave-validator".to_string()),
+            fdctl_executable: None,
+            firedancer_config_path: None,
+            solana_cli_executable: None,
+            version: None,
+            sync_status: None,
+            current_identity: None,
+            ledger_path: Some("/mnt/ledger".to_string()),
+            tower_path: None,
+            swap_ready: Some(true),
+            swap_issues: vec![],
+            ssh_key_path: None,
+        }
+    }
+
+    #[test]
+    fn auto_strategy_follows_validator_type() {
+        let agave = node(ValidatorType::Jito, SwitchStrategy::Auto);
+        assert_eq!(resolve_strategy(&agave), SwitchStrategy::SetIdentity);
+        let command = identity_command(&agave, None, IdentityRole::Unfunded, false).unwrap();
+        assert_eq!(
+            command.args,
+            vec![
+                "-l",
+                "/mnt/ledger",
+                "set-identity",
+                "/home/solana/unfunded.json"
+            ]
+        );
+
+        // Tower enforcement only applies to the promotion
+        let command = identity_command(&agave, None, IdentityRole::Funded, true).unwrap();
+        assert_eq!(command.program, "bash");
+        assert!(command.reports_exit_code);
+        assert_eq!(
+            command.args[1],
+            "'/usr/bin/agave-validator' '-l' '/mnt/ledger' 'set-identity' '--require-tower' \
+             '/home/solana/funded.json' 2>&1; echo \"__SVS_EXIT=$?\""
+        );
+        assert!(command.check_output("__SVS_EXIT=0").is_ok());
+        assert!(command
+            .check_output("Error: tower not found\n__SVS_EXIT=1")
+            .is_err());
+        let command = identity_command(&agave, None, IdentityRole::Unfunded, true).unwrap();
+        assert!(!command.args.contains(&"--require-tower".to_string()));
+
+        // A restart needs the operator's restartCommand; svs never builds a launch line
+        let mut unknown = node(ValidatorType::Unknown, SwitchStrategy::Auto);
+        assert_eq!(resolve_strategy(&unknown), SwitchStrategy::Restart);
+        let err = identity_command(&unknown, None, IdentityRole::Funded, false).unwrap_err();
+        assert!(err.to_string().contains("no restartCommand"));
+
+        unknown.node.restart_command = Some("sudo /usr/local/bin/restart-validator".to_string());
+        let command = identity_command(&unknown, None, IdentityRole::Funded, false).unwrap();
+        assert_eq!(command.program, "bash");
+        assert!(command.reports_exit_code);
+        let script = &command.args[1];
+        assert!(script.contains("export SVS_IDENTITY_KEYPAIR='/home/solana/funded.json';"));
+        assert!(script.contains("{ sudo /usr/local/bin/restart-validator\n} 2>&1;"));
+        assert!(script.ends_with("echo \"__SVS_EXIT=$?\""));
+    }
+
+    #[test]
+    fn authorized_voter_adds_and_removes_the_voter() {
+        let mut target = node(ValidatorType::Agave, SwitchStrategy::AuthorizedVoter);
+        target.node.paths.authorized_voter = Some("/home/solana/voter.json".to_string());
+        let promote = identity_command(&target, None, IdentityRole::Funded, false).unwrap();
+        assert_eq!(
+            promote.args,
+            vec![
+                "-l",
+                "/mnt/ledger",
+                "authorized-voter",
+                "add",
+                "/home/solana/voter.json"
+            ]
+        );
+        let demote = identity_command(&target, None, IdentityRole::Unfunded, false).unwrap();
+        assert_eq!(
+            demote.args,
+            vec!["-l", "/mnt/ledger", "authorized-voter", "remove-all"]
+        );
+
+        let firedancer = node(ValidatorType::Firedancer, SwitchStrategy::AuthorizedVoter);
+        assert!(identity_command(
+            &firedancer,
+            Some("/etc/fd.toml"),
+            IdentityRole::Funded,
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn restart_output_is_checked_for_the_exit_code() {
+        let mut source = node(ValidatorType::Agave, SwitchStrategy::Restart);
+        source.node.restart_command = Some("sudo systemctl restart sol".to_string());
+        let command = identity_command(&source, None, IdentityRole::Unfunded, false).unwrap();
+        assert!(command.args[1].contains("{ sudo systemctl restart sol\n} 2>&1;"));
+        assert!(command.check_output("done\n__SVS_EXIT=0\n").is_ok());
+        let err = command
+            .check_output("Failed to restart sol.service\n__SVS_EXIT=5")
+            .unwrap_err();
+        assert!(err.to_string().contains("exit status 5"));
+        assert!(command.check_output("").is_err());
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
-mod switch_validation_tests {
-    use crate::types::{NodeConfig, NodePaths, NodeStatus, NodeWithStatus, ValidatorType};
+mod tests {
+    use crate::types::{NodeConfig, NodePaths, NodeStatus, NodeWithStatus, SwitchStrategy, ValidatorType};
     use std::collections::HashMap;
 
     // Helper function to create a test node
@@ -23,8 +23,13 @@ mod switch_validation_tests {
                     solana_cli: "/home/solana/bin/solana".to_string(),
                     agave_validator: Some("/home/solana/bin/agave-validator".to_string()),
                     fdctl: None,
+                    authorized_voter: None,
                 },
                 ssh_key_path: Some("/home/user/.ssh/id_rsa".to_string()),
+                priority: None,
+                hooks: vec![],
+                switch_strategy: SwitchStrategy::default(),
+                restart_command: None,
             },
             status: if !available {
                 NodeStatus::Unknown
@@ -237,4 +242,221 @@ mod switch_validation_tests {
         assert!(validation_errors[0].contains("Funded identity keypair missing"));
         assert!(validation_errors[0].contains("Vote keypair missing"));
     }
+
+    #[test]
+    fn test_targeted_switch_picks_named_standby() {
+        use crate::commands::switch::targeted_switch_nodes;
+
+        let nodes = vec![
+            create_test_node("node-1-1", "validator1-1.example.com", true, true),
+            create_test_node("node-1-2", "validator1-2.example.com", true, false),
+            create_test_node("node-1-3", "validator1-3.example.com", true, false),
+        ];
+
+        let (source, target) = targeted_switch_nodes(&nodes, "node-1-3").unwrap();
+        assert_eq!(source.node.label, "node-1-1");
+        assert_eq!(target.node.label, "node-1-3");
+    }
+
+    #[test]
+    fn test_targeted_switch_rejects_active_unknown_or_unready_target() {
+        use crate::commands::switch::targeted_switch_nodes;
+
+        let mut nodes = vec![
+            create_test_node("node-1-1", "validator1-1.example.com", true, true),
+            create_test_node("node-1-2", "validator1-2.example.com", true, false),
+        ];
+
+        let err = targeted_switch_nodes(&nodes, "node-1-1").unwrap_err();
+        assert!(err.to_string().contains("already the active node"));
+
+        let err = targeted_switch_nodes(&nodes, "node-9-9").unwrap_err();
+        assert!(err.to_string().contains("No node labelled"));
+
+        nodes[1].swap_ready = Some(false);
+        nodes[1].swap_issues = vec!["Vote keypair missing or not readable".to_string()];
+        let err = targeted_switch_nodes(&nodes, "node-1-2").unwrap_err();
+        assert!(err.to_string().contains("not swap-ready"));
+        assert!(err.to_string().contains("Vote keypair missing"));
+    }
+
+    fn create_test_plan(nodes: &[NodeWithStatus]) -> crate::switch_plan::SwitchPlan {
+        let validator_pair = crate::types::ValidatorPair {
+            vote_pubkey: "vote123".to_string(),
+            identity_pubkey: "funded123".to_string(),
+            rpc: "https://api.mainnet-beta.solana.com".to_string(),
+            additional_rpcs: vec![],
+            delinquency_quorum: None,
+            hooks: vec![],
+            nodes: nodes.iter().map(|n| n.node.clone()).collect(),
+        };
+        crate::switch_plan::SwitchPlan::new(
+            &validator_pair,
+            &nodes[0],
+            &nodes[1],
+            crate::types::SwitchConfig::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_switch_plan_records_resolved_commands() {
+        let nodes = vec![
+            create_test_node("node-1-1", "validator1-1.example.com", true, true),
+            create_test_node("node-1-2", "validator1-2.example.com", true, false),
+        ];
+
+        let plan = create_test_plan(&nodes);
+        assert_eq!(plan.source.expected_status, "active");
+        assert_eq!(plan.target.expected_status, "standby");
+        assert_eq!(plan.target_tower_path, "/mnt/ledger/tower.bin");
+        assert_eq!(plan.commands.len(), 3);
+        assert!(plan.commands[0].contains("set-identity \"/home/solana/unfunded.json\""));
+        assert!(plan.commands[2].contains("set-identity \"/home/solana/funded.json\""));
+        assert!(plan.precondition_mismatches(&nodes).is_empty());
+
+        // Recovery-mode switches cannot be planned
+        let standby_only = [nodes[1].clone(), nodes[1].clone()];
+        let validator_pair = crate::types::ValidatorPair {
+            vote_pubkey: "vote123".to_string(),
+            identity_pubkey: "funded123".to_string(),
+            rpc: "https://api.mainnet-beta.solana.com".to_string(),
+            additional_rpcs: vec![],
+            delinquency_quorum: None,
+            hooks: vec![],
+            nodes: vec![],
+        };
+        assert!(crate::switch_plan::SwitchPlan::new(
+            &validator_pair,
+            &standby_only[0],
+            &standby_only[1],
+            crate::types::SwitchConfig::default(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_switch_plan_detects_changed_preconditions() {
+        let mut nodes = vec![
+            create_test_node("node-1-1", "validator1-1.example.com", true, true),
+            create_test_node("node-1-2", "validator1-2.example.com", true, false),
+        ];
+        let plan = create_test_plan(&nodes);
+
+        nodes[1].ledger_path = Some("/mnt/ledger2".to_string());
+        nodes[0].status = NodeStatus::Standby;
+        let mismatches = plan.precondition_mismatches(&nodes);
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches.iter().any(|m| m.contains("status was active")));
+        assert!(mismatches.iter().any(|m| m.contains("ledger path was /mnt/ledger")));
+
+        let mismatches = plan.precondition_mismatches(&nodes[..1]);
+        assert!(mismatches.iter().any(|m| m.contains("no longer configured")));
+    }
+
+    #[test]
+    fn test_fencing_classifies_reported_identity() {
+        use crate::commands::switch::{check_fenced_identity, FenceCheck};
+
+        assert_eq!(
+            check_fenced_identity("funded123", "funded123", Some("unfunded123")),
+            FenceCheck::StillFunded
+        );
+        assert_eq!(
+            check_fenced_identity("unfunded123", "funded123", Some("unfunded123")),
+            FenceCheck::Fenced
+        );
+        assert_eq!(
+            check_fenced_identity("other456", "funded123", Some("unfunded123")),
+            FenceCheck::UnexpectedIdentity
+        );
+        // Without a resolved unfunded pubkey, anything but the funded identity is fenced
+        assert_eq!(
+            check_fenced_identity("other456", "funded123", None),
+            FenceCheck::Fenced
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_recovery_mode_ranks_the_other_standbys() {
+        use crate::emergency_failover::{select_best_standby, standby_indices};
+
+        // Recovery mode: every node is a standby and node-1 (index 0) holds the tower
+        let mut nodes = vec![
+            create_test_node("node-1", "validator1-1.example.com", true, false),
+            create_test_node("node-2", "validator1-2.example.com", true, false),
+            create_test_node("node-3", "validator1-3.example.com", true, false),
+        ];
+        nodes[0].node.priority = Some(0);
+        nodes[1].node.priority = Some(5);
+        nodes[2].node.priority = Some(1);
+
+        assert_eq!(standby_indices(&nodes, Some(0)), vec![1, 2]);
+
+        // No probe succeeds here, so priority decides - and never picks the source
+        let pool = crate::ssh::AsyncSshPool::new();
+        let target = select_best_standby(
+            &nodes,
+            Some(0),
+            "http://127.0.0.1:1",
+            &pool,
+            &HashMap::new(),
+        )
+        .await;
+        assert_eq!(target, Some(2));
+    }
+
+    #[test]
+    fn test_promotion_check_demotes_on_wrong_identity() {
+        use crate::commands::switch::{check_promoted_identity, PromotionCheck};
+
+        assert_eq!(
+            check_promoted_identity(&Ok("funded123".to_string()), "funded123"),
+            PromotionCheck::Confirmed
+        );
+        // A standby still on (or switched to) another identity is not confirmed either
+        assert!(matches!(
+            check_promoted_identity(&Ok("unfunded123".to_string()), "funded123"),
+            PromotionCheck::Unconfirmed(reason) if reason.contains("unfunded123")
+        ));
+        assert!(matches!(
+            check_promoted_identity(&Err(anyhow::anyhow!("connection refused")), "funded123"),
+            PromotionCheck::Unconfirmed(reason) if reason.contains("connection refused")
+        ));
+    }
+
+    #[test]
+    fn test_vote_landing_floor_uses_old_primary_last_vote() {
+        use crate::commands::switch::vote_landing_floor;
+
+        // The tower's last vote outranks a lagging end-of-Step-3 snapshot
+        assert_eq!(
+            vote_landing_floor(&[Some(1_032), Some(1_020), Some(1_000)]),
+            Some(1_032)
+        );
+        assert_eq!(vote_landing_floor(&[None, Some(1_020), None]), Some(1_020));
+        assert_eq!(vote_landing_floor(&[None, None, None]), None);
+    }
+
+    #[test]
+    fn test_relay_tower_mode_accepts_old_stream_name() {
+        use crate::types::{SwitchConfig, TowerTransferMode};
+
+        for mode in ["relay", "stream"] {
+            let json = format!("{{\"tower_transfer_mode\": \"{}\"}}", mode);
+            let switch_config: SwitchConfig = serde_json::from_str(&json).unwrap();
+            assert_eq!(switch_config.tower_transfer_mode, TowerTransferMode::Relay);
+        }
+    }
+
+    #[test]
+    fn test_fencing_is_on_by_default_with_opt_out() {
+        let switch_config: crate::types::SwitchConfig = serde_json::from_str("{}").unwrap();
+        assert!(switch_config.fence_old_primary);
+        assert!(crate::types::SwitchConfig::default().fence_old_primary);
+
+        let switch_config: crate::types::SwitchConfig =
+            serde_json::from_str("{\"fence_old_primary\": false}").unwrap();
+        assert!(!switch_config.fence_old_primary);
+    }
 }
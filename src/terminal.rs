@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static NON_INTERACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Mark the process as running without an operator at the terminal
+/// (`svs monitor`): prompts fail and key waits return at once
+pub fn set_non_interactive() {
+    NON_INTERACTIVE.store(true, Ordering::SeqCst);
+}
+
+pub fn is_interactive() -> bool {
+    !NON_INTERACTIVE.load(Ordering::SeqCst)
+}
+
+/// Fail instead of showing `prompt` when nobody can answer it
+pub fn ensure_interactive(prompt: &str) -> Result<()> {
+    if is_interactive() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Cannot ask \"{}\" when running non-interactively",
+            prompt
+        ))
+    }
+}
+
+/// Wait for any key press. Returns at once when non-interactive, and when
+/// terminal events cannot be read rather than retrying in a tight loop.
+pub fn wait_for_any_key() {
+    use crossterm::event::{self, Event};
+
+    if !is_interactive() {
+        return;
+    }
+    crossterm::terminal::enable_raw_mode().ok();
+    loop {
+        match event::read() {
+            Ok(Event::Key(_)) | Err(_) => break,
+            Ok(_) => {}
+        }
+    }
+    crossterm::terminal::disable_raw_mode().ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_interactive_refuses_prompts_and_skips_key_waits() {
+        set_non_interactive();
+        assert!(!is_interactive());
+        assert!(ensure_interactive("Proceed?").is_err());
+        // Would block on the terminal if it did not return at once
+        wait_for_any_key();
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::vote::state::VoteState1_14_11;
+
+/// Upper bound for a tower file read into memory; real towers are a few KB
+pub const MAX_TOWER_FILE_BYTES: usize = 1024 * 1024;
+
+/// Signed envelope stored in `tower-1_9-<identity>.bin`
+#[derive(Deserialize)]
+struct SavedTower {
+    signature: Signature,
+    data: Vec<u8>,
+    node_pubkey: Pubkey,
+}
+
+/// On-disk tower versions written by Agave (and Frankendancer)
+#[derive(Deserialize)]
+enum SavedTowerVersions {
+    V1_17_14(SavedTower),
+    Current(SavedTower),
+}
+
+/// Leading fields of the serialized tower. Both saved versions persist the
+/// vote state in the 1.14.11 layout; the remaining fields are not needed.
+#[derive(Deserialize)]
+struct TowerPrefix {
+    node_pubkey: Pubkey,
+    _threshold_depth: u64,
+    _threshold_size: f64,
+    vote_state: VoteState1_14_11,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TowerSummary {
+    pub node_pubkey: String,
+    pub last_voted_slot: Option<u64>,
+    pub root_slot: Option<u64>,
+    pub signature_valid: bool,
+}
+
+/// Where Agave and Firedancer keep the tower for `identity_pubkey` inside a ledger directory
+pub fn tower_path_in_ledger(ledger_path: &str, identity_pubkey: &str) -> String {
+    format!(
+        "{}/tower-1_9-{}.bin",
+        ledger_path.trim_end_matches('/'),
+        identity_pubkey
+    )
+}
+
+/// Decode a tower file and summarize the fields we validate before a transfer
+pub fn parse_tower_file(bytes: &[u8]) -> Result<TowerSummary> {
+    let saved: SavedTowerVersions = bincode::deserialize(bytes)
+        .map_err(|e| anyhow!("Unrecognized tower file format: {}", e))?;
+    let saved = match saved {
+        SavedTowerVersions::V1_17_14(saved) | SavedTowerVersions::Current(saved) => saved,
+    };
+
+    let signature_valid = saved
+        .signature
+        .verify(saved.node_pubkey.as_ref(), &saved.data);
+
+    let tower: TowerPrefix = bincode::deserialize(&saved.data)
+        .map_err(|e| anyhow!("Unrecognized tower data format: {}", e))?;
+
+    if tower.node_pubkey != saved.node_pubkey {
+        return Err(anyhow!(
+            "Tower envelope pubkey {} does not match tower pubkey {}",
+            saved.node_pubkey,
+            tower.node_pubkey
+        ));
+    }
+
+    Ok(TowerSummary {
+        node_pubkey: tower.node_pubkey.to_string(),
+        last_voted_slot: tower.vote_state.votes.back().map(|lockout| lockout.slot()),
+        root_slot: tower.vote_state.root_slot,
+        signature_valid,
+    })
+}
+
+/// Check a parsed tower belongs to the funded identity and is not older than the
+/// vote account's on-chain `last_vote` by more than `max_staleness_slots`
+pub fn validate_tower(
+    summary: &TowerSummary,
+    funded_identity: &str,
+    onchain_last_vote: Option<u64>,
+    max_staleness_slots: u64,
+) -> Result<()> {
+    if summary.node_pubkey != funded_identity {
+        return Err(anyhow!(
+            "Tower belongs to {} but the funded identity is {}",
+            summary.node_pubkey,
+            funded_identity
+        ));
+    }
+
+    if !summary.signature_valid {
+        return Err(anyhow!(
+            "Tower signature does not verify against {}",
+            summary.node_pubkey
+        ));
+    }
+
+    if let Some(onchain_last_vote) = onchain_last_vote {
+        let tower_last_vote = summary.last_voted_slot.unwrap_or(0);
+        let behind = onchain_last_vote.saturating_sub(tower_last_vote);
+        if behind > max_staleness_slots {
+            return Err(anyhow!(
+                "Tower is stale: last voted slot {} is {} slots behind on-chain last vote {} (max {})",
+                tower_last_vote,
+                behind,
+                onchain_last_vote,
+                max_staleness_slots
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tower_file_tests {
+    use super::{parse_tower_file, validate_tower, TowerSummary};
+    use serde::Serialize;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signature, Signer};
+    use solana_sdk::vote::state::{Lockout, VoteState1_14_11};
+
+    #[derive(Serialize)]
+    struct TestTower {
+        node_pubkey: Pubkey,
+        threshold_depth: u64,
+        threshold_size: f64,
+        vote_state: VoteState1_14_11,
+    }
+
+    #[derive(Serialize)]
+    struct TestSavedTower {
+        signature: Signature,
+        data: Vec<u8>,
+        node_pubkey: Pubkey,
+    }
+
+    #[derive(Serialize)]
+    enum TestSavedTowerVersions {
+        #[allow(dead_code)]
+        V1_17_14(TestSavedTower),
+        Current(TestSavedTower),
+    }
+
+    fn tower_bytes(keypair: &Keypair, votes: &[u64], root: Option<u64>) -> Vec<u8> {
+        let mut vote_state = VoteState1_14_11 {
+            node_pubkey: keypair.pubkey(),
+            root_slot: root,
+            ..VoteState1_14_11::default()
+        };
+        for slot in votes {
+            vote_state.votes.push_back(Lockout::new(*slot));
+        }
+
+        let tower = TestTower {
+            node_pubkey: keypair.pubkey(),
+            threshold_depth: 8,
+            threshold_size: 0.67,
+            vote_state,
+        };
+        let mut data = bincode::serialize(&tower).unwrap();
+        // Real towers carry more fields after the vote state
+        data.extend_from_slice(&[0u8; 16]);
+
+        let saved = TestSavedTowerVersions::Current(TestSavedTower {
+            signature: keypair.sign_message(&data),
+            data,
+            node_pubkey: keypair.pubkey(),
+        });
+        bincode::serialize(&saved).unwrap()
+    }
+
+    fn summary(identity: &str, last_vote: Option<u64>) -> TowerSummary {
+        TowerSummary {
+            node_pubkey: identity.to_string(),
+            last_voted_slot: last_vote,
+            root_slot: None,
+            signature_valid: true,
+        }
+    }
+
+    #[test]
+    fn parses_last_vote_and_root_from_saved_tower() {
+        let keypair = Keypair::new();
+        let bytes = tower_bytes(&keypair, &[1_000, 1_001, 1_002], Some(990));
+
+        let summary = parse_tower_file(&bytes).expect("tower should parse");
+        assert_eq!(summary.node_pubkey, keypair.pubkey().to_string());
+        assert_eq!(summary.last_voted_slot, Some(1_002));
+        assert_eq!(summary.root_slot, Some(990));
+        assert!(summary.signature_valid);
+    }
+
+    #[test]
+    fn detects_tampered_tower_data() {
+        let keypair = Keypair::new();
+        let mut bytes = tower_bytes(&keypair, &[5], None);
+        let last = bytes.len() - 40; // inside the trailing tower data
+        bytes[last] ^= 0xff;
+
+        let summary = parse_tower_file(&bytes).expect("tower should still parse");
+        assert!(!summary.signature_valid);
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(parse_tower_file(b"not a tower").is_err());
+    }
+
+    #[test]
+    fn validate_rejects_foreign_identity() {
+        let err = validate_tower(&summary("other", Some(10)), "funded", None, 150).unwrap_err();
+        assert!(err.to_string().contains("funded identity"));
+    }
+
+    #[test]
+    fn validate_rejects_stale_tower() {
+        let err = validate_tower(&summary("funded", Some(1_000)), "funded", Some(1_200), 150)
+            .unwrap_err();
+        assert!(err.to_string().contains("stale"));
+    }
+
+    #[test]
+    fn validate_accepts_recent_or_newer_tower() {
+        assert!(
+            validate_tower(&summary("funded", Some(1_100)), "funded", Some(1_200), 150).is_ok()
+        );
+        assert!(
+            validate_tower(&summary("funded", Some(1_300)), "funded", Some(1_200), 150).is_ok()
+        );
+        assert!(validate_tower(&summary("funded", Some(1)), "funded", None, 150).is_ok());
+    }
+}
@@ -26,10 +26,50 @@ fn default_node_status_poll_interval() -> u64 {
     10 // Preserve previous direct validator status polling cadence by default
 }
 
+fn default_max_auto_failovers() -> u32 {
+    2
+}
+
+fn default_auto_failover_window() -> u64 {
+    86400 // Rolling 24 hour window for the auto-failover budget
+}
+
+fn default_auto_failover_min_dwell() -> u64 {
+    900 // Let a new active node settle before another automatic takeover
+}
+
 fn default_verbose_logging() -> bool {
     false
 }
 
+fn default_leader_gap_max_wait() -> u64 {
+    120 // Roughly 300 slots; long enough to ride out one of our leader windows
+}
+
+fn default_vote_landing_timeout() -> u64 {
+    60 // ~150 slots for the new active node to land its first vote
+}
+
+fn default_failback_deadline() -> u64 {
+    60 // Time for the new active node to report healthy before failing back
+}
+
+fn default_validate_tower() -> bool {
+    true
+}
+
+fn default_tower_max_staleness() -> u64 {
+    150 // ~1 minute of slots
+}
+
+fn default_fence_old_primary() -> bool {
+    true
+}
+
+fn default_fencing_timeout() -> u64 {
+    5
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub version: String,
@@ -38,6 +78,156 @@ pub struct Config {
     pub verbose_logging: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alert_config: Option<AlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_config: Option<SwitchConfig>,
+}
+
+/// Defaults applied to manual switches (CLI, interactive menu and TUI).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchConfig {
+    /// Minimum number of slots required before our next leader slot before
+    /// Step 1 may start. 0 disables the leader schedule check.
+    #[serde(default)]
+    pub min_leader_gap_slots: u64,
+    /// How long to wait for a large enough leader gap before refusing the switch.
+    #[serde(default = "default_leader_gap_max_wait")]
+    pub leader_gap_max_wait_seconds: u64,
+    /// After promotion, poll the cluster until the vote account's last_vote advances
+    #[serde(default)]
+    pub verify_vote_landing: bool,
+    /// Fail the switch if no new vote lands within this window
+    #[serde(default = "default_vote_landing_timeout")]
+    pub vote_landing_timeout_seconds: u64,
+    /// Switch back to the original node if the new active node fails getHealth
+    /// (or vote verification, when enabled) after promotion
+    #[serde(default)]
+    pub auto_failback: bool,
+    /// How long the new active node has to report healthy before failing back
+    #[serde(default = "default_failback_deadline")]
+    pub failback_deadline_seconds: u64,
+    /// How the tower file is moved from the active to the standby node
+    #[serde(default)]
+    pub tower_transfer_mode: TowerTransferMode,
+    /// Parse the tower before transfer and check its identity and freshness
+    #[serde(default = "default_validate_tower")]
+    pub validate_tower: bool,
+    /// Refuse a tower whose last vote is this many slots behind the on-chain last vote
+    #[serde(default = "default_tower_max_staleness")]
+    pub tower_max_staleness_slots: u64,
+    /// After Step 1, require the old primary's getIdentity to stop reporting the
+    /// funded identity before the standby is promoted. On by default; an
+    /// unreachable old primary then blocks the promotion unless
+    /// `fencing_command` can fence it another way. Set to false to opt out.
+    #[serde(default = "default_fence_old_primary")]
+    pub fence_old_primary: bool,
+    /// How long the old primary has to report its unfunded identity
+    #[serde(default = "default_fencing_timeout")]
+    pub fencing_timeout_seconds: u64,
+    /// Local command run when the old primary cannot be queried (e.g. stop its
+    /// systemd unit over an out-of-band path); the switch continues only if it exits 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fencing_command: Option<String>,
+    /// Promote with `set-identity --require-tower` on Agave/Jito, and check the
+    /// transferred tower exists on a Firedancer standby, so a missing tower
+    /// refuses the promotion and rolls the switch back
+    #[serde(default)]
+    pub require_tower: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TowerTransferMode {
+    /// Read the tower as base64 into svs, then decode and write it on the standby
+    #[default]
+    Base64,
+    /// Read the raw tower from the active node into svs once, check it, and
+    /// write it to the standby with one `dd`, without the base64 round trips.
+    /// The tower is held in svs memory, not piped node to node
+    #[serde(alias = "stream")]
+    Relay,
+    /// Read the tower back as base64 in the same SSH call as the Step 1
+    /// demotion, then decode and write it on the standby
+    Pipelined,
+}
+
+/// Point in a switch at which a hook runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookPoint {
+    /// Before Step 1, while both nodes still hold their original identities
+    PreSwitch,
+    /// After the tower file reached the standby, before it is promoted
+    PostTower,
+    /// After the standby took the funded identity
+    PostPromotion,
+    /// After a switch failed (rolled back or not)
+    OnFailure,
+}
+
+/// Where a hook command runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookRunOn {
+    /// On the machine running svs
+    #[default]
+    Local,
+    /// On the node being demoted, over SSH
+    Source,
+    /// On the node being promoted, over SSH
+    Target,
+    /// On the node the hook is defined on (node hooks only)
+    Node,
+}
+
+/// What a failing or timed-out hook does to the switch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookFailurePolicy {
+    /// Stop the switch (rolling back Step 1 if it already ran)
+    Abort,
+    /// Log the failure and carry on
+    #[default]
+    Warn,
+}
+
+/// Command run at a fixed point of a switch, e.g. to update DNS or firewall rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchHook {
+    pub name: String,
+    pub at: HookPoint,
+    /// Run through `sh -c` locally or `bash -c` on the remote node
+    pub command: String,
+    #[serde(default)]
+    pub run_on: HookRunOn,
+    #[serde(default = "default_hook_timeout")]
+    pub timeout_seconds: u64,
+    #[serde(default)]
+    pub on_failure: HookFailurePolicy,
+}
+
+fn default_hook_timeout() -> u64 {
+    30
+}
+
+impl Default for SwitchConfig {
+    fn default() -> Self {
+        Self {
+            min_leader_gap_slots: 0,
+            leader_gap_max_wait_seconds: default_leader_gap_max_wait(),
+            verify_vote_landing: false,
+            vote_landing_timeout_seconds: default_vote_landing_timeout(),
+            auto_failback: false,
+            failback_deadline_seconds: default_failback_deadline(),
+            tower_transfer_mode: TowerTransferMode::default(),
+            validate_tower: default_validate_tower(),
+            tower_max_staleness_slots: default_tower_max_staleness(),
+            fence_old_primary: default_fence_old_primary(),
+            fencing_timeout_seconds: default_fencing_timeout(),
+            fencing_command: None,
+            require_tower: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +250,22 @@ pub struct AlertConfig {
     pub telegram_low_priority: Option<TelegramConfig>,
     #[serde(default)]
     pub auto_failover_enabled: bool,
+    /// Automatic takeovers allowed per rolling window before auto-failover locks out
+    #[serde(default = "default_max_auto_failovers")]
+    pub max_auto_failovers: u32,
+    #[serde(default = "default_auto_failover_window")]
+    pub auto_failover_window_seconds: u64,
+    /// No automatic takeover within this long after any switch
+    #[serde(default = "default_auto_failover_min_dwell")]
+    pub auto_failover_min_dwell_seconds: u64,
+    /// When two nodes report the funded identity and the vote account is still
+    /// advancing, demote every funded node but the only healthy one instead of
+    /// only alerting
+    #[serde(default)]
+    pub double_sign_auto_demote: bool,
+    /// Accept `/demote <validator>` from the configured Telegram chat
+    #[serde(default)]
+    pub telegram_commands: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,7 +281,25 @@ pub struct ValidatorPair {
     #[serde(rename = "identityPubkey")]
     pub identity_pubkey: String,
     pub rpc: String,
+    /// Further cluster RPC endpoints polled alongside `rpc` for delinquency detection
+    #[serde(
+        rename = "additionalRpcs",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub additional_rpcs: Vec<String>,
+    /// Endpoints that must agree the vote account stopped advancing before the
+    /// validator is declared delinquent (unset = a majority of all endpoints)
+    #[serde(
+        rename = "delinquencyQuorum",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub delinquency_quorum: Option<usize>,
     pub nodes: Vec<NodeConfig>,
+    /// Hooks run on every switch of this pair
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<SwitchHook>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +311,36 @@ pub struct NodeConfig {
     pub paths: NodePaths,
     #[serde(rename = "sshKeyPath", skip_serializing_if = "Option::is_none")]
     pub ssh_key_path: Option<String>,
+    /// Failover preference among standbys; lower is preferred (unset = lowest preference)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+    /// Hooks run on switches where this node is the source or the target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<SwitchHook>,
+    /// How this node takes or gives up the funded role during a switch
+    #[serde(rename = "switchStrategy", default)]
+    pub switch_strategy: SwitchStrategy,
+    /// Restart strategy: command run on the node (through bash) to restart the
+    /// validator with $SVS_IDENTITY_KEYPAIR; required whenever the node resolves
+    /// to that strategy, including `auto` on an undetected validator type
+    #[serde(rename = "restartCommand", skip_serializing_if = "Option::is_none")]
+    pub restart_command: Option<String>,
+}
+
+/// How a node changes between its funded and unfunded role
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwitchStrategy {
+    /// `set_identity` for Agave, Jito and Firedancer, `restart` for anything else
+    #[default]
+    Auto,
+    /// Hot-swap the node identity with `agave-validator set-identity` / `fdctl set-identity`
+    SetIdentity,
+    /// Keep the node identity and add or remove the vote account's authorized
+    /// voter with `agave-validator authorized-voter` (Agave and Jito only)
+    AuthorizedVoter,
+    /// Restart the validator process with the other identity keypair
+    Restart,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,6 +357,9 @@ pub struct NodePaths {
     pub agave_validator: Option<String>,
     #[serde(rename = "fdctlPath", skip_serializing_if = "Option::is_none")]
     pub fdctl: Option<String>,
+    /// Authorized voter keypair for the authorized-voter strategy (defaults to fundedIdentity)
+    #[serde(rename = "authorizedVoterKeypair", skip_serializing_if = "Option::is_none")]
+    pub authorized_voter: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
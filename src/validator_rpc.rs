@@ -111,6 +111,33 @@ pub async fn get_health(
     }
 }
 
+/// Get the validator's current processed slot using getSlot RPC call
+pub async fn get_slot(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    rpc_port: u16,
+) -> Result<u64> {
+    let response = execute_rpc_call(
+        ssh_pool,
+        node,
+        ssh_key,
+        "getSlot",
+        Some(json!([{ "commitment": "processed" }])),
+        rpc_port,
+    )
+    .await?;
+
+    if let Some(error) = response.error {
+        return Err(anyhow!("RPC error: {:?}", error));
+    }
+
+    response
+        .result
+        .as_u64()
+        .ok_or_else(|| anyhow!("Failed to extract slot from RPC response"))
+}
+
 /// Check if a validator is caught up using getHealth RPC
 #[allow(dead_code)]
 pub async fn is_validator_caught_up(
@@ -0,0 +1,195 @@
+use std::time::Instant;
+
+use crate::types::ValidatorPair;
+
+/// Cluster RPC endpoints polled for a pair's vote account: `rpc` first, then
+/// `additionalRpcs`, without duplicates
+pub fn rpc_endpoints(validator_pair: &ValidatorPair) -> Vec<String> {
+    let mut endpoints: Vec<String> = Vec::new();
+    for rpc in std::iter::once(&validator_pair.rpc).chain(&validator_pair.additional_rpcs) {
+        if !rpc.is_empty() && !endpoints.contains(rpc) {
+            endpoints.push(rpc.clone());
+        }
+    }
+    endpoints
+}
+
+/// Endpoints that must agree before delinquency is declared: the configured
+/// `delinquencyQuorum`, or a majority of the endpoints when unset
+pub fn delinquency_quorum(validator_pair: &ValidatorPair) -> usize {
+    let endpoint_count = rpc_endpoints(validator_pair).len().max(1);
+    validator_pair
+        .delinquency_quorum
+        .unwrap_or(endpoint_count / 2 + 1)
+        .clamp(1, endpoint_count)
+}
+
+/// What one RPC endpoint has told us about a vote account
+#[derive(Debug, Clone, Default)]
+pub struct EndpointVoteView {
+    /// Last vote slot reported and when this endpoint first reported it
+    pub last_vote: Option<(u64, Instant)>,
+    /// A fetch failed since `last_vote` changed. Like the single-RPC taint, only
+    /// a new vote slot from this endpoint makes its view trustworthy again.
+    pub stale: bool,
+    pub last_error: Option<String>,
+}
+
+impl EndpointVoteView {
+    pub fn record_vote(&mut self, slot: u64, now: Instant) {
+        if self.last_vote.map(|(last_slot, _)| last_slot) != Some(slot) {
+            self.last_vote = Some((slot, now));
+            self.stale = false;
+        }
+        self.last_error = None;
+    }
+
+    pub fn record_failure(&mut self, error: String) {
+        self.stale = true;
+        self.last_error = Some(error);
+    }
+
+    /// Seconds this endpoint has seen no new vote, if its view is fresh
+    pub fn seconds_without_vote(&self, now: Instant) -> Option<u64> {
+        if self.stale {
+            return None;
+        }
+        self.last_vote
+            .map(|(_, since)| now.saturating_duration_since(since).as_secs())
+    }
+}
+
+/// Per-endpoint vote views for one validator pair
+#[derive(Debug, Clone)]
+pub struct VoteQuorum {
+    pub endpoints: Vec<String>,
+    pub views: Vec<EndpointVoteView>,
+    pub quorum: usize,
+}
+
+impl VoteQuorum {
+    pub fn new(validator_pair: &ValidatorPair) -> Self {
+        let endpoints = rpc_endpoints(validator_pair);
+        Self {
+            views: vec![EndpointVoteView::default(); endpoints.len()],
+            quorum: delinquency_quorum(validator_pair),
+            endpoints,
+        }
+    }
+
+    /// Endpoints with a fresh view that have seen no new vote for `threshold` seconds
+    pub fn stalled_endpoints(&self, threshold: u64, now: Instant) -> usize {
+        self.views
+            .iter()
+            .filter(|view| {
+                view.seconds_without_vote(now)
+                    .is_some_and(|seconds| seconds >= threshold)
+            })
+            .count()
+    }
+
+    /// Enough endpoints with a fresh view saw a new vote within `threshold` seconds
+    pub fn agrees_voting(&self, threshold: u64, now: Instant) -> bool {
+        let voting = self
+            .views
+            .iter()
+            .filter(|view| {
+                view.seconds_without_vote(now)
+                    .is_some_and(|seconds| seconds < threshold)
+            })
+            .count();
+        voting >= self.quorum
+    }
+
+    pub fn agrees_delinquent(&self, threshold: u64, now: Instant) -> bool {
+        self.stalled_endpoints(threshold, now) >= self.quorum
+    }
+
+    pub fn describe(&self, threshold: u64, now: Instant) -> String {
+        format!(
+            "{}/{} RPC endpoints see no vote for {}s (quorum {})",
+            self.stalled_endpoints(threshold, now),
+            self.endpoints.len(),
+            threshold,
+            self.quorum
+        )
+    }
+}
+
+#[cfg(test)]
+mod vote_quorum_tests {
+    use super::{delinquency_quorum, rpc_endpoints, VoteQuorum};
+    use crate::types::ValidatorPair;
+    use std::time::{Duration, Instant};
+
+    fn pair(additional_rpcs: &[&str], delinquency_quorum: Option<usize>) -> ValidatorPair {
+        ValidatorPair {
+            vote_pubkey: "vote123".to_string(),
+            identity_pubkey: "funded123".to_string(),
+            rpc: "https://rpc-a".to_string(),
+            additional_rpcs: additional_rpcs.iter().map(|s| s.to_string()).collect(),
+            delinquency_quorum,
+            hooks: vec![],
+            nodes: vec![],
+        }
+    }
+
+    #[test]
+    fn endpoints_and_default_majority_quorum() {
+        assert_eq!(delinquency_quorum(&pair(&[], None)), 1);
+        assert_eq!(
+            rpc_endpoints(&pair(&["https://rpc-b", "https://rpc-a"], None)),
+            vec!["https://rpc-a", "https://rpc-b"]
+        );
+        assert_eq!(
+            delinquency_quorum(&pair(&["https://rpc-b", "https://rpc-c"], None)),
+            2
+        );
+        assert_eq!(delinquency_quorum(&pair(&["https://rpc-b"], Some(5))), 2);
+        assert_eq!(delinquency_quorum(&pair(&["https://rpc-b"], Some(0))), 1);
+    }
+
+    #[test]
+    fn one_lagging_endpoint_does_not_reach_quorum() {
+        let mut quorum = VoteQuorum::new(&pair(&["https://rpc-b", "https://rpc-c"], None));
+        let start = Instant::now();
+        for view in quorum.views.iter_mut() {
+            view.record_vote(1000, start);
+        }
+
+        // rpc-a is stuck on slot 1000, the others keep seeing new votes
+        let now = start + Duration::from_secs(40);
+        quorum.views[1].record_vote(1080, now);
+        quorum.views[2].record_vote(1080, now);
+        assert_eq!(quorum.stalled_endpoints(30, now), 1);
+        assert!(!quorum.agrees_delinquent(30, now));
+        assert!(quorum.agrees_voting(30, now));
+
+        // rpc-b and rpc-c stop seeing new votes as well
+        let later = now + Duration::from_secs(30);
+        quorum.views[1].record_vote(1080, later);
+        assert_eq!(quorum.stalled_endpoints(30, later), 3);
+        assert!(quorum.agrees_delinquent(30, later));
+        assert!(!quorum.agrees_voting(30, later));
+    }
+
+    #[test]
+    fn failed_endpoints_do_not_count_towards_quorum() {
+        let mut quorum = VoteQuorum::new(&pair(&["https://rpc-b", "https://rpc-c"], None));
+        let start = Instant::now();
+        for view in quorum.views.iter_mut() {
+            view.record_vote(1000, start);
+        }
+        quorum.views[0].record_failure("timeout".to_string());
+        quorum.views[1].record_failure("429 Too Many Requests".to_string());
+
+        let now = start + Duration::from_secs(60);
+        quorum.views[0].record_vote(1000, now);
+        assert_eq!(quorum.stalled_endpoints(30, now), 1);
+        assert!(!quorum.agrees_delinquent(30, now));
+
+        // A new vote slot clears the failure taint
+        quorum.views[1].record_vote(1001, now);
+        assert!(!quorum.views[1].stale);
+    }
+}